@@ -1,46 +1,200 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::time::Duration;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
 use ratatui::widgets::TableState;
 
+use crate::config::{LogAnsi, DEFAULT_LOAD_TIMEOUT_SECS, DEFAULT_SEARCH_CONCURRENCY};
 use crate::types::{
-    fuzzy_match, ConfirmAction, Focus, ResourceItem, ResourceType, SearchResult, ViewMode,
+    compare_column, fuzzy_match, initial_namespace, node_pods_selector, parse_port_mapping,
+    parse_tail_lines, tail_label, unix_now, Bookmark, ColumnLayout, ConfirmAction, ContextHealth,
+    DetailTarget, DryRun, Focus, InFlight, LogOptions, Palette, PaletteCommand, PendingEdit,
+    PodMetrics, PortForward, Prompt, PromptKind, ResourceItem, ResourceType, SearchQuery,
+    SearchResult, SearchScope, Selector, Severity, ViewMode, ALL_NAMESPACES, DEFAULT_LOG_TAIL,
+    LOG_SINCE_WINDOWS, PALETTE_ENTRIES,
 };
+use crate::ui::ansi;
+use crate::ui::theme::Theme;
+
+/// Lists longer than this apply typed filter text on the next tick rather
+/// than on every keystroke.
+const FILTER_DEBOUNCE_ROWS: usize = 1000;
+
+/// Columns hidden, least important first, once the list is narrower than
+/// the given width.
+const NARROW_DROPS: &[(u16, &str)] = &[(90, "NODE"), (70, "RESTARTS")];
+
+/// Ticks between re-describes of a live detail view (~2s at 250ms).
+const DETAIL_REFRESH_TICKS: u32 = 8;
+
+/// Ticks before a context's reachability is probed again while the
+/// context dropdown is open (~60s at 250ms).
+const CONTEXT_PROBE_TICKS: u64 = 240;
+
+/// Frames of the loading spinner, advanced one per tick.
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Ticks per second, for turning tick counts into elapsed time.
+const TICKS_PER_SECOND: u32 = 4;
+
+/// Columns moved per `h`/`l` press in the unwrapped Logs view.
+const LOG_HSCROLL_STEP: u16 = 8;
+
+/// Recently picked namespaces pinned to the top of the dropdown, per context.
+const MAX_RECENT_NAMESPACES: usize = 5;
+
+/// Messages kept in the message history; older ones are dropped.
+const MESSAGE_LOG_CAPACITY: usize = 200;
 
 pub struct App {
     // Navigation
     pub contexts: Vec<String>,
     pub selected_context: usize,
+    pub context_health: HashMap<String, (ContextHealth, u64)>, // last probe result, tick it was sent
     pub namespaces: Vec<String>,
     pub selected_namespace: usize,
     pub preferred_namespace: Option<String>, // from kubeconfig, used to pre-select on load
+    pub namespaces_unlisted: bool, // listing failed, the dropdown asks for a typed namespace
+    pub server_url: Option<String>, // API server of the current context, under its selector
+    pub recent_namespaces: BTreeMap<String, Vec<String>>, // per context, most recent first
+    pub favorite_contexts: BTreeSet<String>, // starred with Ctrl+S, saved with the state
+    pub favorites_only: bool,      // context dropdown lists only favorites, toggled with Ctrl+O
+    pub state_unreadable: bool,    // saved state failed to load, so it isn't overwritten
     pub resource_type: ResourceType,
+    pub pod_node: Option<String>, // pods listed only from this node (`P`), until Esc
+    pub server_selector: Selector, // `label:`/`field:` filter terms the watch was started with
     pub focus: Focus,
 
     // Resource list
     pub resources: Vec<ResourceItem>,
     pub table_state: TableState,
+    pub list_offset: Option<usize>, // list scroll offset saved on opening detail or logs
+    pub marked: BTreeSet<String>,   // "namespace/name" of rows marked with Space
+    pub wrap_navigation: bool,      // j/k past either end wraps around (config `wrap_navigation`)
+    pub load_timeout: Duration, // how long main waits on the API server (config `load_timeout_secs`)
+    pub search_concurrency: usize, // contexts searched or probed at once (config)
+    pub danger_contexts: Vec<String>, // name fragments of danger-mode contexts (config)
+    pub log_ansi: LogAnsi,      // ANSI codes in log lines: rendered, stripped or kept (config)
+    pub confirm_armed: bool,    // danger mode: the first `y` of a confirmation was pressed
+    pub pending_count: Option<usize>, // digits typed before a list motion, as in `5j`
+    pub pending_g: bool,        // first `g` of `gg` was pressed
     pub loading: bool,
+    pub loading_ticks: u32, // ticks since `loading` became true, drives the spinner
+    pub watch_reconnecting: bool, // watch lost its connection and is retrying
 
     // Detail view
     pub detail_text: String,
     pub detail_scroll: u16,
+    pub detail_yaml: bool, // show the raw YAML instead of the describe text
+    pub detail_raw_yaml: String, // YAML of the described resource, fetched on first view
+    pub detail_events: bool, // detail text holds only the resource's events
+    pub secret_revealed: bool, // describe Secrets with decoded values
+    pub detail_target: Option<DetailTarget>, // resource being described
+    pub detail_live: bool, // re-describe periodically; `f` freezes
+    pub detail_refresh_ticks: u32,
+    pub detail_refresh_in_flight: bool, // a live re-describe hasn't answered yet
 
     // Logs view
     pub log_lines: Vec<String>,
     pub log_scroll: u16,
     pub log_follow: bool,
+    pub log_container: Option<String>, // container being streamed, if chosen
+    pub log_aggregate: bool,           // lines come from every pod of a workload
+    pub log_selector: Option<String>,  // lines come from every pod matching these labels
+    pub log_tail: Option<i64>,         // lines of history to fetch (None = all)
+    pub log_previous: bool,            // stream the previous container instance
+    pub log_timestamps: bool,          // ask the API to prefix lines with timestamps
+    pub log_since: Option<i64>,        // only fetch lines newer than this many seconds
+    pub log_wrap: bool,                // wrap long lines instead of scrolling sideways
+    pub log_hscroll: u16,              // horizontal offset while not wrapping
+    pub container_choices: Vec<String>, // non-empty while the container picker is open
+    pub container_selected: usize,
+    pub log_search: String,      // active search pattern (empty = none)
+    pub log_search_active: bool, // typing the pattern
+    pub log_matches: Vec<usize>, // indices into log_lines containing the pattern
+    pub log_match_idx: usize,    // current match, index into log_matches
+    pub log_grep: bool,          // hide lines that don't match the pattern
+    pub log_ignore_case: bool,   // case-insensitive search/grep
 
     // Mode
     pub view_mode: ViewMode,
 
+    // Sort: index into the resource type's column headers (None keeps
+    // the watcher's namespace/name order)
+    pub sort_column: Option<usize>,
+    pub sort_ascending: bool,
+
     // Filter
     pub filter: String,
     pub filter_active: bool,
+    pub problems_only: bool, // hide healthy rows, toggled with `!`
+    filtered: Vec<usize>,    // indices into resources of the visible rows; see update_filter
+    pub filter_dirty: bool,  // filter typed on a large list, applied on the next tick
+
+    // Alternate column layout showing container images, toggled with `i`
+    pub show_images: bool,
+    // Compact/regular/wide columns, cycled with `w`
+    pub column_layout: ColumnLayout,
+
+    // Delete confirmation: dependents a cascading delete would remove
+    // (None while still being computed)
+    pub delete_preview: Option<Vec<String>>,
+
+    // Edit confirmation: the edited YAML and its diff, shown before applying
+    pub pending_edit: Option<PendingEdit>,
+    pub edit_diff_scroll: u16,
+
+    // Help overlay
+    pub show_help: bool,
+    pub theme: Theme,
+    pub help_scroll: u16,
+
+    // Single-line text prompt (e.g. port-forward ports)
+    pub prompt: Option<Prompt>,
+
+    // Ctrl+P command palette
+    pub palette: Option<Palette>,
+
+    // Where the last frame drew the clickable parts, recorded by the
+    // renderers so mouse events can be mapped back to them
+    pub areas: ScreenAreas,
+
+    // Port-forwards
+    pub port_forwards: Vec<PortForward>, // active forwards, for the indicator
+    pub pending_port_forward: Option<PortForward>, // forward to start/stop in main
+
+    // Replica count entered in the scale prompt, applied by main
+    pub pending_replicas: Option<i32>,
+
+    // Text queued for main to copy to the system clipboard
+    pub pending_clipboard: Option<String>,
+    // What is copied once `detail_raw_yaml` is loaded ("the YAML of web-0"),
+    // for the status line
+    pub yaml_to_clipboard: Option<String>,
+
+    // "namespace/name" to select once the next resource list arrives
+    pub pending_select: Option<String>,
+
+    // File the save-logs prompt asked main to write the log buffer to
+    pub pending_log_path: Option<String>,
 
     // Error
-    pub error_message: Option<String>,
-    pub error_ticks: u8,
+    pub status_message: Option<(Severity, String)>,
+    pub status_ticks: u8,
+    // Every message shown in the footer, oldest first, with its unix time
+    pub message_log: VecDeque<(i64, Severity, String)>,
+    pub show_message_log: bool,
+    pub message_log_scroll: u16,
+
+    // Bookmarked resources (`b`), browsed in the `B` overlay
+    pub bookmarks: Vec<Bookmark>,
+    pub show_bookmarks: bool,
+    pub bookmark_selected: usize,
+    // Namespace to open once a context switch to a bookmark lists namespaces
+    pub pending_namespace: Option<String>,
+    pub ticks: u64, // Tick events seen, the clock for throttling probes
 
     // Dropdown selector
     pub dropdown_query: String,
@@ -56,13 +210,21 @@ pub struct App {
     pub search_loading: bool,
     pub search_contexts_total: usize,
     pub search_contexts_done: usize,
+    pub search_scope: Option<SearchScope>, // None = every context and namespace
+    pub search_failures: Vec<(String, String)>, // (context, error) of unreachable clusters
+    pub search_failures_expanded: bool,    // list them above the results
+    pub search_type_filter: Option<ResourceType>, // show only results of this type
     pub entered_from_search: bool,
 
+    // Latest pod usage from metrics-server, merged into pod rows
+    pub pod_metrics: PodMetrics,
+
     // Resource counts per type (for dropdown display)
     pub resource_counts: HashMap<ResourceType, usize>,
 
     // Quit
     pub should_quit: bool,
+    pub in_flight: InFlight, // actions still running, `q` asks before abandoning them
 }
 
 impl App {
@@ -73,30 +235,113 @@ impl App {
         let mut app = Self {
             contexts: vec!["default-context".to_string()],
             selected_context: 0,
+            context_health: HashMap::new(),
             namespaces: vec!["default".to_string()],
             selected_namespace: 0,
+            recent_namespaces: BTreeMap::new(),
+            favorite_contexts: BTreeSet::new(),
+            favorites_only: false,
+            state_unreadable: false,
             preferred_namespace: None,
+            namespaces_unlisted: false,
+            server_url: None,
             resource_type: ResourceType::Pods,
+            pod_node: None,
+            server_selector: Selector::default(),
             focus: Focus::ContextSelector,
 
             resources: Vec::new(),
             table_state,
+            list_offset: None,
+            marked: BTreeSet::new(),
+            wrap_navigation: true,
+            load_timeout: Duration::from_secs(DEFAULT_LOAD_TIMEOUT_SECS),
+            search_concurrency: DEFAULT_SEARCH_CONCURRENCY,
+            danger_contexts: Vec::new(),
+            log_ansi: LogAnsi::default(),
+            confirm_armed: false,
+            pending_count: None,
+            pending_g: false,
             loading: false,
+            loading_ticks: 0,
+            watch_reconnecting: false,
 
             detail_text: String::new(),
             detail_scroll: 0,
+            detail_yaml: false,
+            detail_raw_yaml: String::new(),
+            detail_events: false,
+            secret_revealed: false,
+            detail_target: None,
+            detail_live: true,
+            detail_refresh_ticks: 0,
+            detail_refresh_in_flight: false,
 
             log_lines: Vec::new(),
             log_scroll: 0,
             log_follow: true,
+            log_container: None,
+            log_aggregate: false,
+            log_selector: None,
+            log_tail: Some(DEFAULT_LOG_TAIL),
+            log_previous: false,
+            log_timestamps: false,
+            log_since: None,
+            log_wrap: true,
+            log_hscroll: 0,
+            container_choices: Vec::new(),
+            container_selected: 0,
+            log_search: String::new(),
+            log_search_active: false,
+            log_matches: Vec::new(),
+            log_match_idx: 0,
+            log_grep: false,
+            log_ignore_case: false,
 
             view_mode: ViewMode::List,
 
+            sort_column: None,
+            sort_ascending: true,
+
             filter: String::new(),
             filter_active: false,
+            problems_only: false,
+            filtered: Vec::new(),
+            filter_dirty: false,
+            show_images: false,
+            column_layout: ColumnLayout::default(),
+
+            delete_preview: None,
+            pending_edit: None,
+            edit_diff_scroll: 0,
+
+            show_help: false,
+            theme: Theme::default(),
+            help_scroll: 0,
+
+            prompt: None,
+            palette: None,
+            areas: ScreenAreas::default(),
 
-            error_message: None,
-            error_ticks: 0,
+            port_forwards: Vec::new(),
+            pending_port_forward: None,
+
+            pending_replicas: None,
+            pending_clipboard: None,
+            yaml_to_clipboard: None,
+            pending_select: None,
+            pending_log_path: None,
+
+            status_message: None,
+            status_ticks: 0,
+            message_log: VecDeque::new(),
+            show_message_log: false,
+            message_log_scroll: 0,
+            bookmarks: Vec::new(),
+            show_bookmarks: false,
+            bookmark_selected: 0,
+            pending_namespace: None,
+            ticks: 0,
 
             dropdown_query: String::new(),
             dropdown_filtered: Vec::new(),
@@ -109,12 +354,19 @@ impl App {
             search_table_state: TableState::default(),
             search_loading: false,
             search_contexts_total: 0,
+            search_scope: None,
             search_contexts_done: 0,
+            search_failures: Vec::new(),
+            search_failures_expanded: false,
+            search_type_filter: None,
             entered_from_search: false,
 
+            pod_metrics: PodMetrics::new(),
+
             resource_counts: HashMap::new(),
 
             should_quit: false,
+            in_flight: InFlight::default(),
         };
         app.dropdown_open();
         app
@@ -136,23 +388,242 @@ impl App {
 
     pub fn selected_resource(&self) -> Option<&ResourceItem> {
         let idx = self.table_state.selected()?;
-        self.filtered_resources().into_iter().nth(idx)
+        self.filtered.get(idx).and_then(|&i| self.resources.get(i))
+    }
+
+    /// Text shown in the detail pane: the describe output, or the raw YAML
+    /// of the resource being described when the YAML view is toggled on.
+    pub fn detail_content(&self) -> &str {
+        if self.detail_yaml {
+            &self.detail_raw_yaml
+        } else {
+            &self.detail_text
+        }
+    }
+
+    /// Flip between the description and the raw YAML. The YAML is fetched
+    /// the first time it is shown for the described resource.
+    fn toggle_detail_yaml(&mut self) -> InputAction {
+        self.detail_yaml = !self.detail_yaml;
+        self.detail_scroll = 0;
+        if self.detail_yaml && self.detail_raw_yaml.is_empty() {
+            self.loading = true;
+            InputAction::LoadYaml
+        } else {
+            InputAction::None
+        }
+    }
+
+    /// Replace the namespace list, selecting `preferred_namespace` when the
+    /// cluster has it and the first real namespace after "<all>" otherwise.
+    pub fn set_namespaces(&mut self, namespaces: Vec<String>) {
+        let preferred = self.preferred_namespace.as_deref().unwrap_or_default();
+        let initial = initial_namespace(&namespaces, preferred).map(str::to_string);
+        self.namespaces = std::iter::once(ALL_NAMESPACES.to_string())
+            .chain(namespaces)
+            .collect();
+        self.selected_namespace = initial
+            .and_then(|ns| self.namespaces.iter().position(|n| *n == ns))
+            .unwrap_or(0);
+        self.loading = false;
+        if self.focus == Focus::NamespaceSelector {
+            self.update_dropdown_filter();
+        }
+    }
+
+    /// Replace the listed resources, merging in pod metrics and refreshing
+    /// the filtered rows.
+    pub fn set_resources(&mut self, items: Vec<ResourceItem>) {
+        self.resources = items;
+        self.apply_pod_metrics();
+    }
+
+    /// Copy the latest pod usage into the `cpu`/`mem` extras of each pod row.
+    /// Pods without metrics keep no entry so their columns show `<none>`.
+    pub fn apply_pod_metrics(&mut self) {
+        if self.resource_type == ResourceType::Pods {
+            for item in &mut self.resources {
+                item.extra.retain(|(k, _)| k != "cpu" && k != "mem");
+                let key = format!("{}/{}", item.namespace, item.name);
+                if let Some((cpu, mem)) = self.pod_metrics.get(&key) {
+                    item.extra.push(("cpu".to_string(), cpu.clone()));
+                    item.extra.push(("mem".to_string(), mem.clone()));
+                }
+            }
+        }
+        // Usage can change the order when sorting by CPU or MEM
+        self.update_filter();
+    }
+
+    /// True when the synthetic "<all>" namespace is selected.
+    pub fn all_namespaces(&self) -> bool {
+        self.current_namespace() == ALL_NAMESPACES
+    }
+
+    /// Namespace to act on for the selected row. With "<all>" selected this is
+    /// the row's own namespace rather than the selector value.
+    pub fn selected_resource_namespace(&self) -> String {
+        match self.selected_resource() {
+            Some(r) if self.all_namespaces() => r.namespace.clone(),
+            _ => self.current_namespace().to_string(),
+        }
     }
 
     pub fn selected_resource_name(&self) -> Option<String> {
         self.selected_resource().map(|r| r.name.clone())
     }
 
+    /// Visible row count and, for types with a readiness notion, how many of
+    /// those rows are not ready.
+    pub fn list_summary(&self) -> (usize, Option<usize>) {
+        let filtered = self.filtered_resources();
+        let not_ready = filtered
+            .iter()
+            .map(|r| r.is_ready(self.resource_type))
+            .try_fold(0, |n, ready| ready.map(|r| n + usize::from(!r)));
+        (filtered.len(), not_ready)
+    }
+
+    /// Number of rows shown in the list.
+    pub fn filtered_len(&self) -> usize {
+        self.filtered.len()
+    }
+
+    /// Rows shown in the list, in display order. Reads the indices cached by
+    /// `update_filter`, so this is cheap enough to call on every frame.
     pub fn filtered_resources(&self) -> Vec<&ResourceItem> {
-        if self.filter.is_empty() {
-            self.resources.iter().collect()
-        } else {
-            let filter_lower = self.filter.to_lowercase();
-            self.resources
+        self.filtered
+            .iter()
+            .filter_map(|&i| self.resources.get(i))
+            .collect()
+    }
+
+    /// Recompute the rows passing the name filter and the problems toggle,
+    /// sorted by the active sort column. A `status:<text>` term in the filter
+    /// matches the STATUS column instead of the name, and `label:`/`field:`
+    /// terms are left to the server (see `confirm_filter`). Call after
+    /// changing `resources`, the filter or the sort.
+    pub fn update_filter(&mut self) {
+        let filter_lower = self.filter.to_lowercase();
+        let (status_terms, name_terms): (Vec<&str>, Vec<&str>) = filter_lower
+            .split_whitespace()
+            .filter(|t| !Selector::is_term(t))
+            .partition(|t| t.starts_with("status:"));
+        let name_filter = name_terms.join(" ");
+        let status_filters: Vec<&str> =
+            status_terms.iter().map(|t| &t["status:".len()..]).collect();
+
+        let mut indices: Vec<usize> = self
+            .resources
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| !self.problems_only || !r.is_healthy())
+            .filter(|(_, r)| name_filter.is_empty() || r.name.to_lowercase().contains(&name_filter))
+            .filter(|(_, r)| {
+                let status = r.status.to_lowercase();
+                status_filters.iter().all(|s| status.contains(s))
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if let Some((col, header)) = self.sort_header() {
+            // Render each row's sort value once rather than per comparison
+            let headers = self.column_headers();
+            let mut keyed: Vec<(String, usize)> = indices
+                .into_iter()
+                .map(|i| (self.resources[i].columns(&headers).swap_remove(col), i))
+                .collect();
+            keyed.sort_by(|(a_val, _), (b_val, _)| {
+                let ord = compare_column(header, a_val, b_val);
+                if self.sort_ascending {
+                    ord
+                } else {
+                    ord.reverse()
+                }
+            });
+            indices = keyed.into_iter().map(|(_, i)| i).collect();
+        } else if self.resource_type == ResourceType::Events {
+            // Newest first, like a `kubectl get events -w` that has been running
+            indices.sort_by_key(|&i| std::cmp::Reverse(self.resources[i].created));
+        }
+        self.filtered = indices;
+        self.filter_dirty = false;
+
+        // A batch delete acts on what is marked, so marks must stay visible
+        if !self.marked.is_empty() {
+            let visible: HashSet<String> = self
+                .filtered_resources()
                 .iter()
-                .filter(|r| r.name.to_lowercase().contains(&filter_lower))
-                .collect()
+                .map(|r| format!("{}/{}", r.namespace, r.name))
+                .collect();
+            self.marked.retain(|key| visible.contains(key));
+        }
+    }
+
+    /// Headers of the list's current layout: the image layout when it is
+    /// toggled on and the type has one, the chosen column layout otherwise.
+    pub fn column_headers(&self) -> Vec<&'static str> {
+        self.show_images
+            .then(|| self.resource_type.image_column_headers())
+            .flatten()
+            .unwrap_or_else(|| self.resource_type.column_headers(self.column_layout))
+    }
+
+    /// The column headers a list `width` wide has room for. Before the
+    /// list is first drawn (width 0) that is all of them.
+    pub fn visible_headers(&self, width: u16) -> Vec<&'static str> {
+        let mut headers = self.column_headers();
+        if width > 0 {
+            headers.retain(|h| {
+                !NARROW_DROPS
+                    .iter()
+                    .any(|(min, dropped)| width < *min && h == dropped)
+            });
+        }
+        headers
+    }
+
+    /// The active sort column index and its header, if sorting is enabled.
+    pub fn sort_header(&self) -> Option<(usize, &'static str)> {
+        let col = self.sort_column?;
+        self.column_headers().get(col).map(|h| (col, *h))
+    }
+
+    /// Cycle sort: each column ascending then descending, then back to the
+    /// default order. Columns a narrow list leaves out are skipped.
+    pub fn cycle_sort(&mut self) {
+        let headers = self.column_headers();
+        let visible = self.visible_headers(self.areas.list.width);
+        match self.sort_column {
+            Some(_) if self.sort_ascending => {
+                self.sort_ascending = false;
+            }
+            current => {
+                let from = current.map_or(0, |col| col + 1);
+                self.sort_column = (from..headers.len()).find(|&i| visible.contains(&headers[i]));
+                self.sort_ascending = true;
+            }
         }
+        self.update_filter();
+        self.table_state.select(Some(0));
+    }
+
+    /// Ctrl+T in search: narrow the results to the next resource type that
+    /// has any, in `ResourceType::ALL` order, then back to every type.
+    fn cycle_search_type_filter(&mut self) {
+        let present: Vec<ResourceType> = ResourceType::ALL
+            .into_iter()
+            .filter(|rt| self.search_results.iter().any(|r| r.resource_type == *rt))
+            .collect();
+        let next = match self.search_type_filter {
+            None => 0,
+            Some(current) => present
+                .iter()
+                .position(|rt| *rt == current)
+                .map_or(0, |i| i + 1),
+        };
+        self.search_type_filter = present.get(next).copied();
+        self.update_search_filter();
     }
 
     pub fn selected_search_result(&self) -> Option<&SearchResult> {
@@ -162,20 +633,20 @@ impl App {
     }
 
     pub fn update_search_filter(&mut self) {
-        if self.search_query.is_empty() {
-            self.search_filtered = (0..self.search_results.len()).collect();
-        } else {
-            let mut scored: Vec<(usize, i64)> = self
-                .search_results
-                .iter()
-                .enumerate()
-                .filter_map(|(i, r)| {
-                    fuzzy_match(&self.search_query, &r.resource.name).map(|score| (i, score))
-                })
-                .collect();
-            scored.sort_by(|a, b| b.1.cmp(&a.1));
-            self.search_filtered = scored.into_iter().map(|(i, _)| i).collect();
+        let type_filter = self.search_type_filter;
+        let query = SearchQuery::parse(&self.search_query);
+        let mut scored: Vec<(usize, i64)> = self
+            .search_results
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| type_filter.is_none_or(|rt| r.resource_type == rt))
+            .filter_map(|(i, r)| query.score(r).map(|score| (i, score)))
+            .collect();
+        // Without a query results keep their arrival order
+        if !self.search_query.is_empty() {
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
         }
+        self.search_filtered = scored.into_iter().map(|(i, _)| i).collect();
         // Reset selection to top
         if self.search_filtered.is_empty() {
             self.search_table_state.select(None);
@@ -258,20 +729,43 @@ impl App {
             .unwrap_or(0);
     }
 
-    /// Re-filter the dropdown items using fuzzy match on the query.
+    /// Re-filter the dropdown items using fuzzy match on the query. Without
+    /// a query, recently picked namespaces and favorite contexts come first.
+    /// With `favorites_only`, the context dropdown leaves out the rest.
     pub fn update_dropdown_filter(&mut self) {
         let items = self.dropdown_items();
+        let only_favorites = self.focus == Focus::ContextSelector && self.favorites_only;
+        let shown = |i: &usize| !only_favorites || self.favorite_contexts.contains(&items[*i]);
         if self.dropdown_query.is_empty() {
-            self.dropdown_filtered = (0..items.len()).collect();
+            let pinned: Vec<usize> = match self.focus {
+                Focus::NamespaceSelector => self
+                    .recent_namespaces
+                    .get(self.current_context())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|ns| items.iter().position(|item| item == ns))
+                    .collect(),
+                Focus::ContextSelector => (0..items.len())
+                    .filter(|&i| self.favorite_contexts.contains(&items[i]))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            self.dropdown_filtered = pinned
+                .iter()
+                .copied()
+                .chain((0..items.len()).filter(|i| !pinned.contains(i)))
+                .filter(shown)
+                .collect();
         } else {
             let mut scored: Vec<(usize, i64)> = items
                 .iter()
                 .enumerate()
+                .filter(|(i, _)| shown(i))
                 .filter_map(|(i, item)| {
                     fuzzy_match(&self.dropdown_query, item).map(|score| (i, score))
                 })
                 .collect();
-            scored.sort_by(|a, b| b.1.cmp(&a.1));
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
             self.dropdown_filtered = scored.into_iter().map(|(i, _)| i).collect();
         }
         // Reset selection to top or clamp
@@ -282,6 +776,86 @@ impl App {
         }
     }
 
+    /// Star or unstar the highlighted context in the context dropdown.
+    fn toggle_favorite_context(&mut self) -> InputAction {
+        let Some(&idx) = self.dropdown_filtered.get(self.dropdown_selected) else {
+            return InputAction::None;
+        };
+        let Some(context) = self.contexts.get(idx).cloned() else {
+            return InputAction::None;
+        };
+        if !self.favorite_contexts.remove(&context) {
+            self.favorite_contexts.insert(context);
+        }
+        self.update_dropdown_filter();
+        // Keep the highlight on the same context as it moves
+        if let Some(pos) = self.dropdown_filtered.iter().position(|&i| i == idx) {
+            self.dropdown_selected = pos;
+        }
+        InputAction::SaveState
+    }
+
+    /// Switch the context dropdown between every context and favorites only.
+    fn toggle_favorites_only(&mut self) {
+        if !self.favorites_only && self.favorite_contexts.is_empty() {
+            self.set_warning("No favorite contexts yet; star one with Ctrl+S".to_string());
+            return;
+        }
+        self.favorites_only = !self.favorites_only;
+        self.dropdown_visible = true;
+        self.dropdown_selected = 0;
+        self.update_dropdown_filter();
+    }
+
+    /// Contexts a search of every cluster scans: the favorites when any are
+    /// set and still in the kubeconfig, otherwise all of them.
+    pub fn search_contexts(&self) -> Vec<String> {
+        let favorites: Vec<String> = self
+            .contexts
+            .iter()
+            .filter(|c| self.favorite_contexts.contains(*c))
+            .cloned()
+            .collect();
+        if favorites.is_empty() {
+            self.contexts.clone()
+        } else {
+            favorites
+        }
+    }
+
+    /// Move the current namespace to the front of this context's recent
+    /// list, which main then saves.
+    fn remember_namespace(&mut self) {
+        let namespace = self.current_namespace().to_string();
+        let recent = self
+            .recent_namespaces
+            .entry(self.current_context().to_string())
+            .or_default();
+        recent.retain(|ns| *ns != namespace);
+        recent.insert(0, namespace);
+        recent.truncate(MAX_RECENT_NAMESPACES);
+    }
+
+    /// Switch to the namespace typed into the dropdown when it matches none
+    /// listed, adding it to the list: the cluster may hide namespaces the
+    /// user can read. A mistyped name shows up as the list's error.
+    fn select_typed_namespace(&mut self) -> InputAction {
+        let namespace = self.dropdown_query.trim();
+        if namespace.is_empty() {
+            return InputAction::None;
+        }
+        let namespace = namespace.to_string();
+        self.selected_namespace = match self.namespaces.iter().position(|n| *n == namespace) {
+            Some(idx) => idx,
+            None => {
+                self.namespaces.push(namespace);
+                self.namespaces.len() - 1
+            }
+        };
+        self.remember_namespace();
+        InputAction::NamespaceChanged
+    }
+
     /// Confirm the currently selected dropdown item and advance focus.
     fn dropdown_confirm(&mut self) -> InputAction {
         let action = if self.dropdown_visible {
@@ -298,6 +872,7 @@ impl App {
                     Focus::NamespaceSelector => {
                         if item_idx != self.selected_namespace {
                             self.selected_namespace = item_idx;
+                            self.remember_namespace();
                             InputAction::NamespaceChanged
                         } else {
                             InputAction::None
@@ -308,6 +883,10 @@ impl App {
                         let new_type = ResourceType::ALL[all_idx];
                         if new_type != self.resource_type {
                             self.resource_type = new_type;
+                            // Column indices differ between types
+                            self.sort_column = None;
+                            self.sort_ascending = true;
+                            self.update_filter();
                             InputAction::ResourceTypeChanged
                         } else {
                             InputAction::None
@@ -315,139 +894,1517 @@ impl App {
                     }
                     Focus::ResourceList => InputAction::None,
                 }
+            } else if self.focus == Focus::NamespaceSelector {
+                self.select_typed_namespace()
             } else {
                 InputAction::None
             }
         } else {
             InputAction::None
-        };
-        // Advance focus to next selector
-        self.focus = self.focus.next();
-        if matches!(
-            self.focus,
-            Focus::ContextSelector | Focus::NamespaceSelector | Focus::ResourceTypeSelector
-        ) {
-            self.dropdown_open();
+        };
+        // Marks refer to rows of the list being replaced, which lists more
+        // than one node's pods
+        if action != InputAction::None {
+            self.marked.clear();
+            self.pod_node = None;
+        }
+        // Advance focus to next selector
+        self.focus = self.focus.next();
+        if matches!(
+            self.focus,
+            Focus::ContextSelector | Focus::NamespaceSelector | Focus::ResourceTypeSelector
+        ) {
+            self.dropdown_open();
+        } else {
+            self.dropdown_visible = false;
+        }
+        action
+    }
+
+    fn start_search(&mut self, scope: Option<SearchScope>) -> InputAction {
+        self.view_mode = ViewMode::Search;
+        self.marked.clear();
+        self.search_query.clear();
+        self.search_results.clear();
+        self.search_filtered.clear();
+        self.search_table_state.select(None);
+        self.search_loading = true;
+        self.search_contexts_done = 0;
+        self.search_failures.clear();
+        self.search_failures_expanded = false;
+        self.search_type_filter = None;
+        self.search_scope = scope;
+        self.entered_from_search = false;
+        InputAction::StartSearch
+    }
+
+    /// Show a description unless the detail view has moved on to another
+    /// resource since it was asked for.
+    pub fn detail_loaded(&mut self, target: DetailTarget, text: String) {
+        self.detail_refresh_in_flight = false;
+        if self.detail_target.as_ref() != Some(&target) {
+            return;
+        }
+        self.detail_text = text;
+        self.loading = false;
+    }
+
+    /// Report a failed describe of the open resource and freeze a live view,
+    /// so a deleted resource doesn't repeat the error on every refresh.
+    pub fn detail_failed(&mut self, target: DetailTarget, message: String) {
+        self.detail_refresh_in_flight = false;
+        if self.detail_target.as_ref() != Some(&target) {
+            return;
+        }
+        self.loading = false;
+        if self.detail_live {
+            self.detail_live = false;
+            self.set_error(format!("{} (live refresh stopped, f to resume)", message));
+        } else {
+            self.set_error(message);
+        }
+    }
+
+    /// Whether a live detail view is due for a re-describe. Counts ticks
+    /// only while the plain describe or events text of a listed resource
+    /// is on screen and neither loading nor still waiting on the last
+    /// refresh, so slow describes don't stack up.
+    pub fn detail_refresh_due(&mut self) -> bool {
+        let showing = self.view_mode == ViewMode::Detail
+            && !self.entered_from_search
+            && self.detail_live
+            && !self.detail_yaml
+            && !self.loading
+            && !self.detail_refresh_in_flight
+            && self.detail_target.is_some();
+        if !showing {
+            self.detail_refresh_ticks = 0;
+            return false;
+        }
+        self.detail_refresh_ticks += 1;
+        if self.detail_refresh_ticks < DETAIL_REFRESH_TICKS {
+            return false;
+        }
+        self.detail_refresh_ticks = 0;
+        true
+    }
+
+    pub fn handle_tick(&mut self) {
+        self.ticks += 1;
+        if self.filter_dirty {
+            self.update_filter();
+        }
+        if self.loading {
+            self.loading_ticks = self.loading_ticks.saturating_add(1);
+        } else {
+            self.loading_ticks = 0;
+        }
+        if let Some((severity, _)) = self.status_message {
+            self.status_ticks += 1;
+            if self.status_ticks > severity.dismiss_ticks() {
+                self.status_message = None;
+                self.status_ticks = 0;
+            }
+        }
+    }
+
+    /// Contexts due a reachability probe: all of them when `all` is set
+    /// (after contexts load), otherwise only while the context dropdown is
+    /// open. Contexts probed within `CONTEXT_PROBE_TICKS` are skipped, and
+    /// the returned ones are stamped with the current tick so they are not
+    /// sent again while in flight.
+    pub fn contexts_to_probe(&mut self, all: bool) -> Vec<String> {
+        let dropdown_open = self.focus == Focus::ContextSelector && self.dropdown_visible;
+        if !all && !dropdown_open {
+            return Vec::new();
+        }
+        let now = self.ticks;
+        let due: Vec<String> = self
+            .contexts
+            .iter()
+            .filter(|c| {
+                self.context_health
+                    .get(*c)
+                    .is_none_or(|&(_, at)| now.saturating_sub(at) >= CONTEXT_PROBE_TICKS)
+            })
+            .cloned()
+            .collect();
+        for context in &due {
+            let health = self.context_health(context);
+            self.context_health.insert(context.clone(), (health, now));
+        }
+        due
+    }
+
+    /// Record a probe result, keeping the tick the probe was sent.
+    pub fn set_context_health(&mut self, context: String, health: ContextHealth) {
+        let at = self
+            .context_health
+            .get(&context)
+            .map_or(self.ticks, |&(_, at)| at);
+        self.context_health.insert(context, (health, at));
+    }
+
+    pub fn context_health(&self, context: &str) -> ContextHealth {
+        self.context_health
+            .get(context)
+            .map_or(ContextHealth::Unknown, |&(health, _)| health)
+    }
+
+    /// Spinner frame and elapsed seconds while loading, e.g. "⠹ 3s".
+    pub fn loading_indicator(&self) -> Option<String> {
+        if !self.loading {
+            return None;
+        }
+        let frame = SPINNER_FRAMES[self.loading_ticks as usize % SPINNER_FRAMES.len()];
+        Some(format!(
+            "{} {}s",
+            frame,
+            self.loading_ticks / TICKS_PER_SECOND
+        ))
+    }
+
+    pub fn set_info(&mut self, msg: String) {
+        self.set_status(Severity::Info, msg);
+    }
+
+    pub fn set_warning(&mut self, msg: String) {
+        self.set_status(Severity::Warning, msg);
+    }
+
+    pub fn set_error(&mut self, msg: String) {
+        self.set_status(Severity::Error, msg);
+    }
+
+    /// Show `msg` in the footer until it times out, and keep it in the
+    /// message history (`M`).
+    fn set_status(&mut self, severity: Severity, msg: String) {
+        if self.message_log.len() == MESSAGE_LOG_CAPACITY {
+            self.message_log.pop_front();
+        }
+        self.message_log
+            .push_back((unix_now(), severity, msg.clone()));
+        self.status_message = Some((severity, msg));
+        self.status_ticks = 0;
+    }
+
+    /// Text of the footer message, if one is showing.
+    pub fn status_text(&self) -> Option<&str> {
+        self.status_message.as_ref().map(|(_, msg)| msg.as_str())
+    }
+
+    /// Handle key input. Returns true if an action requiring K8s interaction was triggered.
+    pub fn handle_input(&mut self, key: KeyEvent) -> InputAction {
+        // Global quit
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.should_quit = true;
+            return InputAction::None;
+        }
+
+        // The palette takes every key while open, Ctrl ones included
+        if self.palette.is_some() {
+            return self.handle_palette_input(key);
+        }
+
+        // Global Ctrl+F to search every cluster, Ctrl+G to search only the
+        // current context and namespace (from List or selector views)
+        if key.modifiers.contains(KeyModifiers::CONTROL) && self.view_mode == ViewMode::List {
+            match key.code {
+                KeyCode::Char('f') => return self.start_search(None),
+                KeyCode::Char('p') => return self.open_palette(),
+                KeyCode::Char('g') => {
+                    let scope = SearchScope {
+                        context: self.current_context().to_string(),
+                        namespace: self.current_namespace().to_string(),
+                    };
+                    return self.start_search(Some(scope));
+                }
+                _ => {}
+            }
+        }
+
+        // Filter mode input
+        if self.filter_active {
+            return self.handle_filter_input(key);
+        }
+
+        if self.show_help {
+            return self.handle_help_input(key);
+        }
+
+        if self.show_message_log {
+            return self.handle_message_log_input(key);
+        }
+
+        if self.show_bookmarks {
+            return self.handle_bookmarks_input(key);
+        }
+
+        if self.prompt.is_some() {
+            return self.handle_prompt_input(key);
+        }
+
+        // Confirmation dialog
+        if let ViewMode::Confirm(action) = self.view_mode {
+            return self.handle_confirm_input(key, action);
+        }
+
+        if self.view_mode == ViewMode::Logs && !self.container_choices.is_empty() {
+            return self.handle_container_picker_input(key);
+        }
+
+        if self.view_mode == ViewMode::Logs {
+            if self.log_search_active {
+                return self.handle_log_search_input(key);
+            }
+            if let Some(action) = self.handle_log_search_keys(key) {
+                return action;
+            }
+        }
+
+        match self.view_mode {
+            ViewMode::List => self.handle_list_input(key),
+            ViewMode::Detail if self.entered_from_search => self.handle_search_detail_input(key),
+            ViewMode::Detail => self.handle_detail_input(key),
+            ViewMode::Logs if self.entered_from_search => self.handle_search_logs_input(key),
+            ViewMode::Logs => self.handle_logs_input(key),
+            ViewMode::Confirm(_) => unreachable!(),
+            ViewMode::Search => self.handle_search_input(key),
+        }
+    }
+
+    /// Clicks select list and search rows or focus a header selector; the
+    /// wheel scrolls like `j`/`k` in whatever view is shown. Ignored while
+    /// an overlay or text input has the keyboard.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) {
+        let overlay = self.show_help
+            || self.show_message_log
+            || self.show_bookmarks
+            || self.prompt.is_some()
+            || self.palette.is_some()
+            || self.filter_active
+            || self.log_search_active
+            || !self.container_choices.is_empty()
+            || matches!(self.view_mode, ViewMode::Confirm(_));
+        if overlay {
+            return;
+        }
+        let (column, row) = (mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+                let panes = [
+                    self.areas.list,
+                    self.areas.search_results,
+                    self.areas.content,
+                ];
+                let over_pane = panes.iter().any(|a| a.contains(Position::new(column, row)));
+                if !over_pane {
+                    return;
+                }
+                let down = mouse.kind == MouseEventKind::ScrollDown;
+                match self.view_mode {
+                    // Letters go to the query there
+                    ViewMode::Search if down => self.search_select_next(),
+                    ViewMode::Search => self.search_select_prev(),
+                    _ => {
+                        if self.view_mode == ViewMode::List {
+                            self.focus = Focus::ResourceList;
+                            self.dropdown_visible = false;
+                        }
+                        let c = if down { 'j' } else { 'k' };
+                        // Scrolling never starts a K8s action
+                        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+                        let _ = self.handle_input(key);
+                    }
+                }
+            }
+            MouseEventKind::Down(MouseButton::Left) => self.handle_click(column, row),
+            _ => {}
+        }
+    }
+
+    fn handle_click(&mut self, column: u16, row: u16) {
+        match self.view_mode {
+            ViewMode::List => {
+                let clicked = self
+                    .areas
+                    .selectors
+                    .iter()
+                    .position(|a| a.contains(Position::new(column, row)));
+                if let Some(i) = clicked {
+                    self.focus = [
+                        Focus::ContextSelector,
+                        Focus::NamespaceSelector,
+                        Focus::ResourceTypeSelector,
+                    ][i];
+                    self.dropdown_open();
+                    return;
+                }
+                let offset = self.table_state.offset();
+                if let Some(i) = table_row_at(self.areas.list, offset, column, row) {
+                    if i < self.filtered.len() {
+                        self.focus = Focus::ResourceList;
+                        self.dropdown_visible = false;
+                        self.table_state.select(Some(i));
+                    }
+                }
+            }
+            ViewMode::Search => {
+                let offset = self.search_table_state.offset();
+                if let Some(i) = table_row_at(self.areas.search_results, offset, column, row) {
+                    if i < self.search_filtered.len() {
+                        self.search_table_state.select(Some(i));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_filter_input(&mut self, key: KeyEvent) -> InputAction {
+        match key.code {
+            KeyCode::Esc => {
+                self.filter_active = false;
+                if self.filter_dirty {
+                    self.update_filter();
+                }
+                return self.confirm_filter();
+            }
+            KeyCode::Enter => {
+                self.filter_active = false;
+                // Keep the filter but exit filter mode
+                if self.filter_dirty {
+                    self.update_filter();
+                }
+                self.table_state.select(Some(0));
+                return self.confirm_filter();
+            }
+            KeyCode::Backspace => {
+                if self.filter.pop().is_some() {
+                    self.filter_typed();
+                }
+                self.table_state.select(Some(0));
+            }
+            KeyCode::Char(c) => {
+                self.filter.push(c);
+                self.filter_typed();
+                self.table_state.select(Some(0));
+            }
+            _ => {}
+        }
+        InputAction::None
+    }
+
+    /// Restart the watch when leaving filter mode changed its `label:` or
+    /// `field:` terms, so the server only sends the objects they select.
+    /// Typing doesn't, as each keystroke would restart it.
+    fn confirm_filter(&mut self) -> InputAction {
+        let selector = Selector::from_filter(&self.filter);
+        if selector == self.server_selector {
+            return InputAction::None;
+        }
+        self.server_selector = selector;
+        InputAction::ResourceTypeChanged
+    }
+
+    /// What the list's watch asks the server for: the filter's selectors,
+    /// plus the node's pods while `P` is in effect.
+    pub fn watch_selector(&self) -> Selector {
+        let selector = self.server_selector.clone();
+        match &self.pod_node {
+            Some(node) if self.resource_type == ResourceType::Pods => {
+                selector.with_fields(&node_pods_selector(node))
+            }
+            _ => selector,
+        }
+    }
+
+    /// Re-filter after a keystroke in filter mode. Large lists defer it to
+    /// the next tick, so a burst of typing filters once instead of per key.
+    fn filter_typed(&mut self) {
+        if self.resources.len() > FILTER_DEBOUNCE_ROWS {
+            self.filter_dirty = true;
+        } else {
+            self.update_filter();
+        }
+    }
+
+    fn open_help(&mut self) -> InputAction {
+        self.show_help = true;
+        self.help_scroll = 0;
+        InputAction::None
+    }
+
+    fn handle_help_input(&mut self, key: KeyEvent) -> InputAction {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => {
+                self.show_help = false;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.help_scroll = self.help_scroll.saturating_add(1);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.help_scroll = self.help_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+        InputAction::None
+    }
+
+    fn open_message_log(&mut self) -> InputAction {
+        self.show_message_log = true;
+        self.message_log_scroll = 0;
+        InputAction::None
+    }
+
+    fn handle_message_log_input(&mut self, key: KeyEvent) -> InputAction {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('M') | KeyCode::Char('q') => {
+                self.show_message_log = false;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.message_log_scroll = self.message_log_scroll.saturating_add(1);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.message_log_scroll = self.message_log_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+        InputAction::None
+    }
+
+    /// `b`: bookmark the selected resource, or drop its bookmark.
+    fn toggle_bookmark(&mut self) -> InputAction {
+        let Some(name) = self.selected_resource_name() else {
+            return InputAction::None;
+        };
+        // Cluster-scoped objects have no namespace to go back to
+        let namespace = if self.resource_type.is_cluster_scoped() {
+            String::new()
+        } else {
+            self.selected_resource_namespace()
+        };
+        let bookmark = Bookmark {
+            context: self.current_context().to_string(),
+            namespace,
+            resource_type: self.resource_type,
+            name,
+        };
+        let label = format!("{}/{}", bookmark.resource_type, bookmark.name);
+        if let Some(i) = self.bookmarks.iter().position(|b| *b == bookmark) {
+            self.bookmarks.remove(i);
+            self.set_info(format!("Removed bookmark {}", label));
+        } else {
+            self.bookmarks.push(bookmark);
+            self.set_info(format!("Bookmarked {}", label));
+        }
+        InputAction::SaveState
+    }
+
+    fn open_bookmarks(&mut self) -> InputAction {
+        self.show_bookmarks = true;
+        self.bookmark_selected = 0;
+        InputAction::None
+    }
+
+    fn handle_bookmarks_input(&mut self, key: KeyEvent) -> InputAction {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('B') | KeyCode::Char('q') => {
+                self.show_bookmarks = false;
+            }
+            KeyCode::Char('j') | KeyCode::Down if !self.bookmarks.is_empty() => {
+                self.bookmark_selected = step_index(
+                    self.bookmark_selected,
+                    self.bookmarks.len(),
+                    true,
+                    self.wrap_navigation,
+                );
+            }
+            KeyCode::Char('k') | KeyCode::Up if !self.bookmarks.is_empty() => {
+                self.bookmark_selected = step_index(
+                    self.bookmark_selected,
+                    self.bookmarks.len(),
+                    false,
+                    self.wrap_navigation,
+                );
+            }
+            KeyCode::Char('d') if self.bookmark_selected < self.bookmarks.len() => {
+                self.bookmarks.remove(self.bookmark_selected);
+                self.bookmark_selected = self
+                    .bookmark_selected
+                    .min(self.bookmarks.len().saturating_sub(1));
+                return InputAction::SaveState;
+            }
+            KeyCode::Enter => {
+                if let Some(bookmark) = self.bookmarks.get(self.bookmark_selected).cloned() {
+                    return self.jump_to_bookmark(bookmark);
+                }
+            }
+            _ => {}
+        }
+        InputAction::None
+    }
+
+    /// Switch to the bookmark's context, namespace and type as needed and
+    /// select it once its list arrives. Cluster-scoped bookmarks keep the
+    /// current namespace.
+    fn jump_to_bookmark(&mut self, bookmark: Bookmark) -> InputAction {
+        let same_context = bookmark.context == self.current_context();
+        if !same_context && !self.contexts.contains(&bookmark.context) {
+            let msg = format!(
+                "Context {} is no longer in the kubeconfig",
+                bookmark.context
+            );
+            self.set_warning(msg);
+            return InputAction::None;
+        }
+        let cluster_scoped = bookmark.resource_type.is_cluster_scoped();
+        let namespace = self
+            .namespaces
+            .iter()
+            .position(|n| *n == bookmark.namespace);
+        if same_context && namespace.is_none() && !cluster_scoped {
+            self.set_warning(format!(
+                "Namespace {} not found in {}",
+                bookmark.namespace, bookmark.context
+            ));
+            return InputAction::None;
+        }
+
+        self.show_bookmarks = false;
+        self.view_mode = ViewMode::List;
+        // The saved scroll belongs to the list left behind, not this one
+        self.list_offset = None;
+        self.focus = Focus::ResourceList;
+        self.dropdown_visible = false;
+        self.filter.clear();
+        self.filter_active = false;
+        self.problems_only = false;
+        self.marked.clear();
+        self.pending_select = Some(format!("{}/{}", bookmark.namespace, bookmark.name));
+        let type_changed = bookmark.resource_type != self.resource_type
+            || self.pod_node.take().is_some()
+            || !std::mem::take(&mut self.server_selector).is_empty();
+        if type_changed {
+            self.resource_type = bookmark.resource_type;
+            self.sort_column = None;
+            self.sort_ascending = true;
+        }
+        self.update_filter();
+
+        if !same_context {
+            self.selected_context = self
+                .contexts
+                .iter()
+                .position(|c| *c == bookmark.context)
+                .unwrap_or_default();
+            self.pending_namespace = Some(bookmark.namespace).filter(|_| !cluster_scoped);
+            return InputAction::ContextChanged;
+        }
+        match namespace {
+            Some(idx) if idx != self.selected_namespace => {
+                self.selected_namespace = idx;
+                InputAction::NamespaceChanged
+            }
+            _ if type_changed => InputAction::ResourceTypeChanged,
+            _ => {
+                self.apply_pending_select();
+                InputAction::None
+            }
+        }
+    }
+
+    fn open_palette(&mut self) -> InputAction {
+        self.filter_active = false;
+        self.palette = Some(Palette::default());
+        self.update_palette_filter();
+        InputAction::None
+    }
+
+    /// Re-rank the palette entries for the current type against the query,
+    /// keeping registry order while it is empty.
+    fn update_palette_filter(&mut self) {
+        let resource_type = self.resource_type;
+        let Some(palette) = self.palette.as_mut() else {
+            return;
+        };
+        let mut scored: Vec<(usize, i64)> = PALETTE_ENTRIES
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| (e.applies)(&resource_type))
+            .filter_map(|(i, e)| fuzzy_match(&palette.query, e.label).map(|score| (i, score)))
+            .collect();
+        if !palette.query.is_empty() {
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        }
+        palette.filtered = scored.into_iter().map(|(i, _)| i).collect();
+        palette.selected = 0;
+    }
+
+    fn handle_palette_input(&mut self, key: KeyEvent) -> InputAction {
+        let Some(palette) = self.palette.as_mut() else {
+            return InputAction::None;
+        };
+        let len = palette.filtered.len();
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => self.palette = None,
+            KeyCode::Char('p') if ctrl => self.palette = None,
+            KeyCode::Enter => {
+                let chosen = palette.filtered.get(palette.selected).copied();
+                self.palette = None;
+                if let Some(i) = chosen {
+                    return self.run_palette_command(PALETTE_ENTRIES[i].command);
+                }
+            }
+            KeyCode::Down if len > 0 => {
+                palette.selected = step_index(palette.selected, len, true, self.wrap_navigation);
+            }
+            KeyCode::Up if len > 0 => {
+                palette.selected = step_index(palette.selected, len, false, self.wrap_navigation);
+            }
+            KeyCode::Backspace => {
+                palette.query.pop();
+                self.update_palette_filter();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                palette.query.push(c);
+                self.update_palette_filter();
+            }
+            _ => {}
+        }
+        InputAction::None
+    }
+
+    /// Run a palette entry as if its key were pressed in the resource list.
+    fn run_palette_command(&mut self, command: PaletteCommand) -> InputAction {
+        self.focus = Focus::ResourceList;
+        let key = |code: KeyCode, modifiers: KeyModifiers| KeyEvent::new(code, modifiers);
+        match command {
+            PaletteCommand::Key(c) => self.handle_input(key(KeyCode::Char(c), KeyModifiers::NONE)),
+            PaletteCommand::Ctrl(c) => {
+                self.handle_input(key(KeyCode::Char(c), KeyModifiers::CONTROL))
+            }
+            PaletteCommand::Enter => self.handle_input(key(KeyCode::Enter, KeyModifiers::NONE)),
+            PaletteCommand::Focus(focus) => {
+                self.focus = focus;
+                self.dropdown_open();
+                InputAction::None
+            }
+        }
+    }
+
+    fn handle_prompt_input(&mut self, key: KeyEvent) -> InputAction {
+        let Some(prompt) = self.prompt.as_mut() else {
+            return InputAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.prompt = None;
+            }
+            KeyCode::Enter => {
+                if let Some(prompt) = self.prompt.take() {
+                    return self.submit_prompt(prompt);
+                }
+            }
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Char(c) => {
+                prompt.input.push(c);
+            }
+            _ => {}
+        }
+        InputAction::None
+    }
+
+    fn submit_prompt(&mut self, prompt: Prompt) -> InputAction {
+        match prompt.kind {
+            PromptKind::PortForward => {
+                let Some((local_port, remote_port)) = parse_port_mapping(&prompt.input) else {
+                    self.set_warning(format!(
+                        "Invalid port mapping '{}', expected localPort:remotePort",
+                        prompt.input
+                    ));
+                    return InputAction::None;
+                };
+                let Some(item) = self.selected_resource() else {
+                    return InputAction::None;
+                };
+                self.pending_port_forward = Some(PortForward {
+                    namespace: item.namespace.clone(),
+                    name: item.name.clone(),
+                    resource_type: self.resource_type,
+                    local_port,
+                    remote_port,
+                });
+                InputAction::PortForward
+            }
+            PromptKind::TailLines => match parse_tail_lines(&prompt.input) {
+                Some(tail) => {
+                    self.log_tail = tail;
+                    self.restart_logs()
+                }
+                None => {
+                    self.set_warning(format!(
+                        "Invalid tail length '{}', expected a number or 'all'",
+                        prompt.input
+                    ));
+                    InputAction::None
+                }
+            },
+            PromptKind::SaveLogs => {
+                let path = prompt.input.trim();
+                if path.is_empty() {
+                    self.set_warning("No file name given, logs not saved".to_string());
+                    return InputAction::None;
+                }
+                self.pending_log_path = Some(path.to_string());
+                InputAction::SaveLogs
+            }
+            PromptKind::LogSelector => {
+                let selector = prompt.input.trim();
+                if selector.is_empty() {
+                    self.set_warning("No label selector given".to_string());
+                    return InputAction::None;
+                }
+                let selector = selector.to_string();
+                let action = self.open_logs(Vec::new(), InputAction::StreamLogs);
+                self.log_aggregate = true;
+                self.log_selector = Some(selector);
+                action
+            }
+            PromptKind::Scale => match prompt.input.trim().parse::<u16>() {
+                Ok(replicas) => {
+                    self.pending_replicas = Some(i32::from(replicas));
+                    InputAction::Scale
+                }
+                Err(_) => {
+                    self.set_warning(format!(
+                        "Invalid replica count '{}', expected a non-negative number",
+                        prompt.input
+                    ));
+                    InputAction::None
+                }
+            },
+        }
+    }
+
+    /// `=` on a scalable resource: prompt for the new replica count,
+    /// pre-filled with the current desired replicas.
+    fn open_scale_prompt(&mut self) -> InputAction {
+        if !self.resource_type.supports_scale() {
+            return InputAction::None;
+        }
+        if let Some(item) = self.selected_resource() {
+            let input = item
+                .spec
+                .replicas
+                .map(|r| r.to_string())
+                .unwrap_or_default();
+            self.prompt = Some(Prompt {
+                kind: PromptKind::Scale,
+                input,
+            });
+        }
+        InputAction::None
+    }
+
+    /// `y`/`Y`: queue the selected resource's name, or `namespace/name` when
+    /// `qualified`, for main to copy to the clipboard.
+    fn copy_selected(&mut self, qualified: bool) -> InputAction {
+        let Some(item) = self.selected_resource() else {
+            return InputAction::None;
+        };
+        let text = if qualified && !self.resource_type.is_cluster_scoped() {
+            format!("{}/{}", item.namespace, item.name)
+        } else {
+            item.name.clone()
+        };
+        self.pending_clipboard = Some(text);
+        InputAction::CopyName
+    }
+
+    /// Copy a search result as `context/namespace/name` so the cluster it
+    /// came from travels with it.
+    fn copy_search_result(&mut self) -> InputAction {
+        let Some(result) = self.selected_search_result() else {
+            return InputAction::None;
+        };
+        let text = if result.resource_type.is_cluster_scoped() {
+            format!("{}/{}", result.context, result.resource.name)
+        } else {
+            format!(
+                "{}/{}/{}",
+                result.context, result.resource.namespace, result.resource.name
+            )
+        };
+        self.pending_clipboard = Some(text);
+        InputAction::CopyName
+    }
+
+    /// `c` in the detail view: copy the described resource's full YAML,
+    /// which main fetches first unless the YAML view already has. Secret
+    /// values stay masked, as in the YAML view, unless revealed with `x`.
+    fn copy_yaml(&mut self) -> InputAction {
+        let target = if self.entered_from_search {
+            self.selected_search_result()
+                .map(|r| (r.resource.name.clone(), r.resource_type))
+        } else {
+            self.selected_resource_name()
+                .map(|name| (name, self.resource_type))
+        };
+        let Some((name, resource_type)) = target else {
+            return InputAction::None;
+        };
+        if self.detail_raw_yaml.is_empty() {
+            self.loading = true;
+        }
+        // Search results' detail has no `x`, so is always masked
+        let masked = resource_type == ResourceType::Secrets
+            && (!self.secret_revealed || self.entered_from_search);
+        self.yaml_to_clipboard = Some(if masked {
+            format!("the YAML of {} with its values hidden (x to reveal)", name)
+        } else {
+            format!("the YAML of {}", name)
+        });
+        InputAction::CopyYaml
+    }
+
+    /// `p` on a resource: stop its forward if one is running, otherwise
+    /// prompt for the ports to forward.
+    fn toggle_port_forward(&mut self) -> InputAction {
+        if !self.resource_type.supports_port_forward() {
+            return InputAction::None;
+        }
+        let Some(item) = self.selected_resource() else {
+            return InputAction::None;
+        };
+        let resource_type = self.resource_type;
+        if let Some(idx) = self
+            .port_forwards
+            .iter()
+            .position(|f| f.targets(resource_type, item))
+        {
+            self.pending_port_forward = Some(self.port_forwards.remove(idx));
+            return InputAction::StopPortForward;
+        }
+        let input = item
+            .spec
+            .container_port
+            .map(|p| format!("{}:{}", p, p))
+            .unwrap_or_default();
+        self.prompt = Some(Prompt {
+            kind: PromptKind::PortForward,
+            input,
+        });
+        InputAction::None
+    }
+
+    /// Whether the current context matches one of the configured
+    /// `danger_contexts` fragments, ignoring case.
+    pub fn danger_mode(&self) -> bool {
+        let context = self.current_context().to_lowercase();
+        self.danger_contexts
+            .iter()
+            .any(|fragment| !fragment.is_empty() && context.contains(&fragment.to_lowercase()))
+    }
+
+    /// In danger mode the first `y` of a confirmation only arms it; returns
+    /// true once the action should go ahead.
+    fn confirm_pressed(&mut self) -> bool {
+        if self.danger_mode() && !self.confirm_armed {
+            self.confirm_armed = true;
+            return false;
+        }
+        self.confirm_armed = false;
+        true
+    }
+
+    fn handle_confirm_input(&mut self, key: KeyEvent, action: ConfirmAction) -> InputAction {
+        if action == ConfirmAction::ApplyEdit {
+            return self.handle_edit_diff_input(key);
+        }
+        match key.code {
+            // Quitting is never a danger-mode action, one `y` will do
+            KeyCode::Char('y') | KeyCode::Char('Y') if action == ConfirmAction::Quit => {
+                self.should_quit = true;
+                InputAction::None
+            }
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if !self.confirm_pressed() {
+                    return InputAction::None;
+                }
+                self.view_mode = ViewMode::List;
+                self.restore_list_offset();
+                match action {
+                    ConfirmAction::Delete => InputAction::Delete,
+                    ConfirmAction::ForceDelete => InputAction::ForceDelete,
+                    ConfirmAction::Restart => InputAction::Restart,
+                    ConfirmAction::ApplyEdit => InputAction::ApplyEdit,
+                    ConfirmAction::Quit => InputAction::None,
+                }
+            }
+            _ => {
+                // Any other key cancels
+                self.view_mode = ViewMode::List;
+                self.restore_list_offset();
+                self.confirm_armed = false;
+                InputAction::None
+            }
+        }
+    }
+
+    /// Show the diff of YAML edited in `$EDITOR` and wait for `y` before
+    /// applying it.
+    pub fn open_edit_preview(&mut self, edit: PendingEdit) {
+        self.pending_edit = Some(edit);
+        self.edit_diff_scroll = 0;
+        self.view_mode = ViewMode::Confirm(ConfirmAction::ApplyEdit);
+    }
+
+    pub fn take_pending_edit(&mut self) -> Option<PendingEdit> {
+        self.pending_edit.take()
+    }
+
+    /// Record the server's verdict on the dry run. A result for an edit
+    /// that has since been applied or discarded is dropped.
+    pub fn finish_edit_dry_run(&mut self, result: Result<(), String>) {
+        if let Some(ref mut edit) = self.pending_edit {
+            if edit.dry_run == Some(DryRun::Running) {
+                edit.dry_run = Some(match result {
+                    Ok(()) => DryRun::Passed,
+                    Err(e) => DryRun::Failed(e),
+                });
+            }
+        }
+    }
+
+    /// The diff is scrollable, so unlike the other confirmations only
+    /// `n`/`Esc`/`q` cancel. `d` asks the API server to validate the edit
+    /// first without persisting it.
+    fn handle_edit_diff_input(&mut self, key: KeyEvent) -> InputAction {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if !self.confirm_pressed() {
+                    return InputAction::None;
+                }
+                self.view_mode = ViewMode::List;
+                self.restore_list_offset();
+                InputAction::ApplyEdit
+            }
+            KeyCode::Char('d') => match self.pending_edit {
+                Some(ref mut edit) if edit.dry_run != Some(DryRun::Running) => {
+                    edit.dry_run = Some(DryRun::Running);
+                    InputAction::DryRunEdit
+                }
+                _ => InputAction::None,
+            },
+            KeyCode::Char('n') | KeyCode::Char('q') | KeyCode::Esc => {
+                self.view_mode = ViewMode::List;
+                self.restore_list_offset();
+                self.confirm_armed = false;
+                self.pending_edit = None;
+                self.set_info("Edit discarded".to_string());
+                InputAction::None
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.edit_diff_scroll = self.edit_diff_scroll.saturating_add(1);
+                InputAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.edit_diff_scroll = self.edit_diff_scroll.saturating_sub(1);
+                InputAction::None
+            }
+            KeyCode::Char('g') => {
+                self.edit_diff_scroll = 0;
+                InputAction::None
+            }
+            KeyCode::Char('G') => {
+                // Clamped to the last screenful when rendered
+                self.edit_diff_scroll = u16::MAX;
+                InputAction::None
+            }
+            _ => InputAction::None,
+        }
+    }
+
+    /// Space: mark or unmark the selected row for a batch delete, then move
+    /// down so consecutive rows can be marked quickly.
+    fn toggle_mark(&mut self) {
+        let Some(item) = self.selected_resource() else {
+            return;
+        };
+        let key = format!("{}/{}", item.namespace, item.name);
+        if !self.marked.remove(&key) {
+            self.marked.insert(key);
+        }
+        self.select_next();
+    }
+
+    /// Marked resources, in display order. Only visible rows stay marked
+    /// (see `update_filter`), so this is everything a batch delete removes.
+    pub fn marked_resources(&self) -> Vec<&ResourceItem> {
+        self.filtered_resources()
+            .into_iter()
+            .filter(|r| self.marked.contains(&format!("{}/{}", r.namespace, r.name)))
+            .collect()
+    }
+
+    /// `(namespace, name)` of everything a confirmed delete removes: the
+    /// marked rows if any (clearing the marks), otherwise the selection.
+    pub fn take_delete_targets(&mut self) -> Vec<(String, String)> {
+        let targets: Vec<(String, String)> = if self.marked.is_empty() {
+            self.selected_resource_name()
+                .map(|name| (self.selected_resource_namespace(), name))
+                .into_iter()
+                .collect()
+        } else {
+            self.marked_resources()
+                .into_iter()
+                .map(|r| (r.namespace.clone(), r.name.clone()))
+                .collect()
+        };
+        self.marked.clear();
+        targets
+    }
+
+    /// Open the delete confirmation for the selected resource. For types
+    /// whose delete cascades to owned objects, also kick off computing the
+    /// preview of what will be removed.
+    fn request_delete(&mut self) -> InputAction {
+        if self.selected_resource().is_none() {
+            return InputAction::None;
+        }
+        self.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
+        self.delete_preview = None;
+        if self.marked.is_empty() && self.resource_type.has_dependents() {
+            InputAction::PreviewDelete
+        } else {
+            InputAction::None
+        }
+    }
+
+    /// `D`: confirm deleting the selected (or marked) pods with a zero
+    /// grace period.
+    fn request_force_delete(&mut self) -> InputAction {
+        if self.resource_type.supports_force_delete() && self.selected_resource().is_some() {
+            self.view_mode = ViewMode::Confirm(ConfirmAction::ForceDelete);
+        }
+        InputAction::None
+    }
+
+    /// `L` in the resource list: prompt for a label selector and stream the
+    /// logs of every matching pod in the namespace.
+    fn open_log_selector_prompt(&mut self) -> InputAction {
+        self.prompt = Some(Prompt {
+            kind: PromptKind::LogSelector,
+            input: self.log_selector.clone().unwrap_or_default(),
+        });
+        InputAction::None
+    }
+
+    /// Switch to the Logs view. Pods with several containers open the
+    /// container picker first; otherwise `stream` is returned straight away.
+    /// `l` on the selected resource: a pod's own logs, or the merged logs of
+    /// every pod a workload selects.
+    fn open_selected_logs(&mut self) -> InputAction {
+        if !self.resource_type.supports_logs() {
+            return InputAction::None;
+        }
+        let Some(containers) = self.selected_resource().map(|r| r.spec.containers.clone()) else {
+            return InputAction::None;
+        };
+        let action = self.open_logs(containers, InputAction::StreamLogs);
+        self.log_aggregate = self.resource_type.aggregates_logs();
+        action
+    }
+
+    fn open_logs(&mut self, containers: Vec<String>, stream: InputAction) -> InputAction {
+        if self.view_mode == ViewMode::List {
+            self.save_list_offset();
+        }
+        self.view_mode = ViewMode::Logs;
+        self.log_aggregate = false;
+        self.log_selector = None;
+        self.log_lines.clear();
+        self.clear_log_search();
+        self.log_grep = false;
+        self.log_scroll = 0;
+        self.log_hscroll = 0;
+        self.log_follow = true;
+        self.log_container = None;
+        self.log_previous = false;
+        self.container_selected = 0;
+        if containers.len() > 1 {
+            self.container_choices = containers;
+            InputAction::None
         } else {
-            self.dropdown_visible = false;
+            self.container_choices.clear();
+            self.log_container = containers.into_iter().next();
+            stream
         }
-        action
     }
 
-    pub fn handle_tick(&mut self) {
-        if let Some(ref _msg) = self.error_message {
-            self.error_ticks += 1;
-            if self.error_ticks > 20 {
-                // ~5 seconds at 250ms tick
-                self.error_message = None;
-                self.error_ticks = 0;
-            }
+    /// Options for (re)opening the current log stream.
+    pub fn log_options(&self) -> LogOptions {
+        LogOptions {
+            tail_lines: self.log_tail,
+            previous: self.log_previous,
+            timestamps: self.log_timestamps,
+            since_seconds: self.log_since,
+            open_timeout: self.load_timeout,
         }
     }
 
-    pub fn set_error(&mut self, msg: String) {
-        self.error_message = Some(msg);
-        self.error_ticks = 0;
+    /// `P` in the Logs view: switch between the running and the previous
+    /// container instance.
+    fn toggle_previous_logs(&mut self) -> InputAction {
+        self.log_previous = !self.log_previous;
+        self.restart_logs()
     }
 
-    /// Handle key input. Returns true if an action requiring K8s interaction was triggered.
-    pub fn handle_input(&mut self, key: KeyEvent) -> InputAction {
-        // Global quit
-        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
-            self.should_quit = true;
+    /// `t` in the Logs view: re-stream with or without timestamps. The
+    /// buffer is refetched so the two formats never mix.
+    fn toggle_log_timestamps(&mut self) -> InputAction {
+        self.log_timestamps = !self.log_timestamps;
+        self.restart_logs()
+    }
+
+    /// `S` in the Logs view: step through `LOG_SINCE_WINDOWS` and
+    /// re-stream only the lines inside the new window.
+    fn cycle_log_since(&mut self) -> InputAction {
+        let next = LOG_SINCE_WINDOWS
+            .iter()
+            .position(|w| *w == self.log_since)
+            .map_or(0, |i| (i + 1) % LOG_SINCE_WINDOWS.len());
+        self.log_since = LOG_SINCE_WINDOWS[next];
+        self.restart_logs()
+    }
+
+    /// `w` in the Logs view: switch between wrapping long lines and
+    /// scrolling them horizontally with `h`/`l`.
+    fn toggle_log_wrap(&mut self) -> InputAction {
+        self.log_wrap = !self.log_wrap;
+        self.log_hscroll = 0;
+        InputAction::None
+    }
+
+    /// `h`/`l` in the Logs view. Only meaningful without wrapping; render
+    /// clamps the offset to the longest line.
+    fn scroll_logs_horizontal(&mut self, right: bool) -> InputAction {
+        if !self.log_wrap {
+            self.log_hscroll = if right {
+                self.log_hscroll.saturating_add(LOG_HSCROLL_STEP)
+            } else {
+                self.log_hscroll.saturating_sub(LOG_HSCROLL_STEP)
+            };
+        }
+        InputAction::None
+    }
+
+    /// `s` in the Logs view: prompt for a file to save the buffer to,
+    /// pre-filled with `<pod>-<timestamp>.log`.
+    fn open_save_logs_prompt(&mut self) -> InputAction {
+        if self.log_lines.is_empty() {
             return InputAction::None;
         }
+        let pod = if self.entered_from_search {
+            self.selected_search_result()
+                .map(|r| r.resource.name.clone())
+        } else {
+            self.selected_resource_name()
+        };
+        let stamp = k8s_openapi::jiff::Timestamp::now().strftime("%Y%m%d-%H%M%S");
+        self.prompt = Some(Prompt {
+            kind: PromptKind::SaveLogs,
+            input: format!(
+                "{}-{}.log",
+                pod.unwrap_or_else(|| "logs".to_string()),
+                stamp
+            ),
+        });
+        InputAction::None
+    }
 
-        // Global Ctrl+F to enter search (from List or selector views, not from other modes)
-        if key.modifiers.contains(KeyModifiers::CONTROL)
-            && key.code == KeyCode::Char('f')
-            && self.view_mode == ViewMode::List
-        {
-            self.view_mode = ViewMode::Search;
-            self.search_query.clear();
-            self.search_results.clear();
-            self.search_filtered.clear();
-            self.search_table_state.select(None);
-            self.search_loading = true;
-            self.search_contexts_done = 0;
-            self.entered_from_search = false;
-            return InputAction::StartSearch;
+    /// `T` in the Logs view: prompt for how many lines of history to fetch.
+    fn open_tail_prompt(&mut self) -> InputAction {
+        self.prompt = Some(Prompt {
+            kind: PromptKind::TailLines,
+            input: tail_label(self.log_tail),
+        });
+        InputAction::None
+    }
+
+    /// Clear the buffer and re-stream the current pod/container, e.g. after
+    /// the stream options changed. Search pattern and follow are kept.
+    fn restart_logs(&mut self) -> InputAction {
+        self.log_lines.clear();
+        self.log_matches.clear();
+        self.log_match_idx = 0;
+        self.log_scroll = 0;
+        if self.entered_from_search {
+            InputAction::SearchStreamLogs
+        } else {
+            InputAction::StreamLogs
         }
+    }
 
-        // Filter mode input
-        if self.filter_active {
-            return self.handle_filter_input(key);
+    /// Append a streamed log line, keeping search matches (and so the grep
+    /// view) up to date. The full buffer is always kept.
+    pub fn push_log_line(&mut self, line: String) {
+        let line = match self.log_ansi {
+            LogAnsi::Strip => ansi::strip(&line).into_owned(),
+            LogAnsi::Render | LogAnsi::Raw => line,
+        };
+        if !self.log_search.is_empty() && self.log_line_matches(&line) {
+            self.log_matches.push(self.log_lines.len());
         }
+        self.log_lines.push(line);
+    }
 
-        // Confirmation dialog
-        if let ViewMode::Confirm(action) = self.view_mode {
-            return self.handle_confirm_input(key, action);
+    /// Whether `line` contains the search pattern, honouring ignore-case.
+    /// Rendered color codes are invisible, so they don't count.
+    pub fn log_line_matches(&self, line: &str) -> bool {
+        let line = match self.log_ansi {
+            LogAnsi::Render => ansi::strip(line),
+            LogAnsi::Strip | LogAnsi::Raw => Cow::Borrowed(line),
+        };
+        if self.log_ignore_case {
+            line.to_ascii_lowercase()
+                .contains(&self.log_search.to_ascii_lowercase())
+        } else {
+            line.contains(&self.log_search)
         }
+    }
 
-        match self.view_mode {
-            ViewMode::List => self.handle_list_input(key),
-            ViewMode::Detail if self.entered_from_search => self.handle_search_detail_input(key),
-            ViewMode::Detail => self.handle_detail_input(key),
-            ViewMode::Logs if self.entered_from_search => self.handle_search_logs_input(key),
-            ViewMode::Logs => self.handle_logs_input(key),
-            ViewMode::Confirm(_) => unreachable!(),
-            ViewMode::Search => self.handle_search_input(key),
+    /// Indices into `log_lines` that the Logs view shows: only matches
+    /// while grep is on, otherwise every line.
+    pub fn visible_log_lines(&self) -> Vec<usize> {
+        if self.grep_active() {
+            self.log_matches.clone()
+        } else {
+            (0..self.log_lines.len()).collect()
         }
     }
 
-    fn handle_filter_input(&mut self, key: KeyEvent) -> InputAction {
+    /// Grep only filters once there is a pattern to filter by.
+    pub fn grep_active(&self) -> bool {
+        self.log_grep && !self.log_search.is_empty()
+    }
+
+    fn clear_log_search(&mut self) {
+        self.log_search.clear();
+        self.log_search_active = false;
+        self.log_matches.clear();
+        self.log_match_idx = 0;
+    }
+
+    fn update_log_matches(&mut self) {
+        self.log_matches = if self.log_search.is_empty() {
+            Vec::new()
+        } else {
+            self.log_lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| self.log_line_matches(line))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        self.log_match_idx = 0;
+    }
+
+    /// The log line index of the current search match, if any.
+    pub fn current_log_match(&self) -> Option<usize> {
+        self.log_matches.get(self.log_match_idx).copied()
+    }
+
+    /// Move to the next (or previous) match, wrapping around the buffer.
+    fn jump_log_match(&mut self, forward: bool) {
+        let count = self.log_matches.len();
+        if count == 0 {
+            return;
+        }
+        self.log_match_idx = if forward {
+            (self.log_match_idx + 1) % count
+        } else {
+            (self.log_match_idx + count - 1) % count
+        };
+        self.scroll_to_log_match();
+    }
+
+    fn scroll_to_log_match(&mut self) {
+        if let Some(line) = self.current_log_match() {
+            self.log_follow = false;
+            // In grep mode only matches are shown, so scroll by match index
+            self.log_scroll = if self.grep_active() {
+                self.log_match_idx as u16
+            } else {
+                line as u16
+            };
+        }
+    }
+
+    /// Search keys shared by both Logs views. Returns None for keys the
+    /// view-specific handler should process.
+    fn handle_log_search_keys(&mut self, key: KeyEvent) -> Option<InputAction> {
+        match key.code {
+            KeyCode::Char('/') => {
+                self.clear_log_search();
+                self.log_search_active = true;
+                // Stop the view jumping while the user is searching
+                self.log_follow = false;
+            }
+            KeyCode::Char('&') => {
+                self.log_grep = !self.log_grep;
+                self.log_scroll = 0;
+                if self.log_grep && self.log_search.is_empty() {
+                    // No pattern yet: prompt for one
+                    self.log_search_active = true;
+                    self.log_follow = false;
+                }
+            }
+            KeyCode::Char('i') => {
+                self.log_ignore_case = !self.log_ignore_case;
+                self.update_log_matches();
+            }
+            KeyCode::Char('n') if !self.log_search.is_empty() => self.jump_log_match(true),
+            KeyCode::Char('N') if !self.log_search.is_empty() => self.jump_log_match(false),
+            // Esc clears an active search (and grep) before it leaves the view
+            KeyCode::Esc if !self.log_search.is_empty() || self.log_grep => {
+                self.clear_log_search();
+                self.log_grep = false;
+            }
+            _ => return None,
+        }
+        Some(InputAction::None)
+    }
+
+    fn handle_log_search_input(&mut self, key: KeyEvent) -> InputAction {
         match key.code {
             KeyCode::Esc => {
-                self.filter_active = false;
+                self.clear_log_search();
+                self.log_grep = false;
             }
             KeyCode::Enter => {
-                self.filter_active = false;
-                // Keep the filter but exit filter mode
-                self.table_state.select(Some(0));
+                self.log_search_active = false;
+                if self.log_search.is_empty() {
+                    self.clear_log_search();
+                }
             }
             KeyCode::Backspace => {
-                self.filter.pop();
-                self.table_state.select(Some(0));
+                self.log_search.pop();
+                self.update_log_matches();
+                self.scroll_to_log_match();
             }
             KeyCode::Char(c) => {
-                self.filter.push(c);
-                self.table_state.select(Some(0));
+                self.log_search.push(c);
+                self.update_log_matches();
+                self.scroll_to_log_match();
             }
             _ => {}
         }
         InputAction::None
     }
 
-    fn handle_confirm_input(&mut self, key: KeyEvent, action: ConfirmAction) -> InputAction {
+    fn handle_container_picker_input(&mut self, key: KeyEvent) -> InputAction {
         match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') => {
-                self.view_mode = ViewMode::List;
-                match action {
-                    ConfirmAction::Delete => InputAction::Delete,
-                    ConfirmAction::Restart => InputAction::Restart,
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.container_choices.clear();
+                self.view_mode = if self.entered_from_search {
+                    ViewMode::Search
+                } else {
+                    ViewMode::List
+                };
+                InputAction::None
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if self.container_selected + 1 < self.container_choices.len() {
+                    self.container_selected += 1;
                 }
+                InputAction::None
             }
-            _ => {
-                // Any other key cancels
-                self.view_mode = ViewMode::List;
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.container_selected = self.container_selected.saturating_sub(1);
                 InputAction::None
             }
+            KeyCode::Enter => {
+                let choices = std::mem::take(&mut self.container_choices);
+                self.log_container = choices.into_iter().nth(self.container_selected);
+                if self.entered_from_search {
+                    InputAction::SearchStreamLogs
+                } else {
+                    InputAction::StreamLogs
+                }
+            }
+            _ => InputAction::None,
         }
     }
 
     fn handle_list_input(&mut self, key: KeyEvent) -> InputAction {
-        match self.focus {
+        let action = match self.focus {
             Focus::ResourceList => self.handle_resource_list_input(key),
             Focus::ContextSelector
             | Focus::NamespaceSelector
             | Focus::ResourceTypeSelector => self.handle_selector_input(key),
+        };
+        // Marks only apply while the list itself is in view
+        if !matches!(self.view_mode, ViewMode::List | ViewMode::Confirm(_)) {
+            self.marked.clear();
         }
+        action
     }
 
+    /// Vim-style motions on top of the list keys: a count prefix (`5j`,
+    /// `10G`), `gg` for the first row and `G` for the last. A `g` followed
+    /// by anything but `g` is dropped along with that key.
     fn handle_resource_list_input(&mut self, key: KeyEvent) -> InputAction {
+        let count = self.pending_count.take();
+        let pending_g = std::mem::take(&mut self.pending_g);
+        if pending_g {
+            if key.code == KeyCode::Char('g') {
+                self.select_row(count.unwrap_or(1).saturating_sub(1));
+            }
+            return InputAction::None;
+        }
+        match (key.code, count) {
+            (KeyCode::Char(c @ '0'..='9'), _) if c != '0' || count.is_some() => {
+                let digit = c.to_digit(10).unwrap_or_default() as usize;
+                self.pending_count =
+                    Some(count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+                InputAction::None
+            }
+            (KeyCode::Char('g'), _) => {
+                self.pending_g = true;
+                self.pending_count = count;
+                InputAction::None
+            }
+            (KeyCode::Char('G'), _) => {
+                self.select_row(count.map_or(usize::MAX, |n| n.saturating_sub(1)));
+                InputAction::None
+            }
+            (KeyCode::Char('j') | KeyCode::Down, Some(n)) => {
+                let current = self.table_state.selected().unwrap_or(0);
+                self.select_row(current.saturating_add(n));
+                InputAction::None
+            }
+            (KeyCode::Char('k') | KeyCode::Up, Some(n)) => {
+                let current = self.table_state.selected().unwrap_or(0);
+                self.select_row(current.saturating_sub(n));
+                InputAction::None
+            }
+            _ => self.handle_resource_list_key(key),
+        }
+    }
+
+    fn handle_resource_list_key(&mut self, key: KeyEvent) -> InputAction {
         match key.code {
             KeyCode::Char('q') => {
-                self.should_quit = true;
+                if self.in_flight.count() == 0 {
+                    self.should_quit = true;
+                } else {
+                    self.view_mode = ViewMode::Confirm(ConfirmAction::Quit);
+                }
                 InputAction::None
             }
             KeyCode::Char('j') | KeyCode::Down => {
@@ -458,6 +2415,46 @@ impl App {
                 self.select_prev();
                 InputAction::None
             }
+            KeyCode::Char('s') => {
+                self.cycle_sort();
+                InputAction::None
+            }
+            KeyCode::Char(' ') => {
+                self.toggle_mark();
+                InputAction::None
+            }
+            KeyCode::Char('!') => {
+                self.problems_only = !self.problems_only;
+                self.update_filter();
+                self.table_state.select(Some(0));
+                InputAction::None
+            }
+            KeyCode::Char('i') if self.resource_type.supports_image_column() => {
+                self.show_images = !self.show_images;
+                // Column indices differ between layouts
+                self.sort_column = None;
+                self.sort_ascending = true;
+                self.update_filter();
+                InputAction::None
+            }
+            KeyCode::Char('w') if self.resource_type.supports_column_layouts() => {
+                self.column_layout = self.column_layout.next();
+                self.show_images = false;
+                self.sort_column = None;
+                self.sort_ascending = true;
+                self.update_filter();
+                InputAction::None
+            }
+            KeyCode::Char('y') => self.copy_selected(false),
+            KeyCode::Char('Y') => self.copy_selected(true),
+            KeyCode::Char('b') => self.toggle_bookmark(),
+            KeyCode::Char('B') => self.open_bookmarks(),
+            KeyCode::Char('P') => self.show_node_pods(),
+            KeyCode::Esc if self.pod_node.is_some() => {
+                self.pod_node = None;
+                self.update_filter();
+                InputAction::ResourceTypeChanged
+            }
             KeyCode::Tab => {
                 self.focus = self.focus.next();
                 if matches!(
@@ -484,29 +2481,41 @@ impl App {
             }
             KeyCode::Enter => {
                 if self.selected_resource().is_some() {
+                    self.save_list_offset();
                     self.view_mode = ViewMode::Detail;
                     self.detail_scroll = 0;
+                    self.detail_yaml = false;
+                    self.detail_raw_yaml.clear();
+                    self.detail_events = false;
+                    self.secret_revealed = false;
                     InputAction::Describe
                 } else {
                     InputAction::None
                 }
             }
-            KeyCode::Char('l') => {
-                if self.resource_type.supports_logs() && self.selected_resource().is_some() {
-                    self.view_mode = ViewMode::Logs;
-                    self.log_lines.clear();
-                    self.log_scroll = 0;
-                    self.log_follow = true;
-                    InputAction::StreamLogs
+            KeyCode::Char('E') => {
+                if self.selected_resource().is_some() {
+                    self.save_list_offset();
+                    self.view_mode = ViewMode::Detail;
+                    self.detail_scroll = 0;
+                    self.detail_yaml = false;
+                    self.detail_raw_yaml.clear();
+                    self.secret_revealed = false;
+                    self.detail_events = true;
+                    InputAction::DescribeEvents
                 } else {
                     InputAction::None
                 }
             }
-            KeyCode::Char('d') => {
-                if self.selected_resource().is_some() {
-                    self.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
-                }
-                InputAction::None
+            KeyCode::Char('l') => self.open_selected_logs(),
+            KeyCode::Char('L') => self.open_log_selector_prompt(),
+            KeyCode::Char('d') => self.request_delete(),
+            KeyCode::Char('D') => self.request_force_delete(),
+            KeyCode::Char('p') => self.toggle_port_forward(),
+            KeyCode::Char('=') => self.open_scale_prompt(),
+            KeyCode::Char('R') | KeyCode::F(5) => {
+                self.loading = true;
+                InputAction::Refresh
             }
             KeyCode::Char('r') => {
                 if self.resource_type.supports_restart() && self.selected_resource().is_some() {
@@ -521,21 +2530,40 @@ impl App {
                     InputAction::None
                 }
             }
+            KeyCode::Char('n') => {
+                if self.resource_type.supports_create() {
+                    InputAction::Create
+                } else {
+                    self.set_warning(format!("No template to create {} from", self.resource_type));
+                    InputAction::None
+                }
+            }
             KeyCode::Char('/') => {
                 self.filter_active = true;
                 self.filter.clear();
+                self.update_filter();
                 InputAction::None
             }
-            KeyCode::Char('?') => {
-                // TODO: help overlay
-                InputAction::None
-            }
+            KeyCode::Char('?') => self.open_help(),
+            KeyCode::Char('M') => self.open_message_log(),
             _ => InputAction::None,
         }
     }
 
     fn handle_selector_input(&mut self, key: KeyEvent) -> InputAction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
         match key.code {
+            KeyCode::Char('s') if ctrl && self.focus == Focus::ContextSelector => {
+                if self.dropdown_visible {
+                    self.toggle_favorite_context()
+                } else {
+                    InputAction::None
+                }
+            }
+            KeyCode::Char('o') if ctrl && self.focus == Focus::ContextSelector => {
+                self.toggle_favorites_only();
+                InputAction::None
+            }
             KeyCode::Esc => {
                 if self.dropdown_visible {
                     // Close dropdown, stay on selector
@@ -584,8 +2612,12 @@ impl App {
                     self.update_dropdown_filter();
                 }
                 if !self.dropdown_filtered.is_empty() {
-                    self.dropdown_selected =
-                        (self.dropdown_selected + 1) % self.dropdown_filtered.len();
+                    self.dropdown_selected = step_index(
+                        self.dropdown_selected,
+                        self.dropdown_filtered.len(),
+                        true,
+                        self.wrap_navigation,
+                    );
                 }
                 InputAction::None
             }
@@ -595,11 +2627,12 @@ impl App {
                     self.update_dropdown_filter();
                 }
                 if !self.dropdown_filtered.is_empty() {
-                    self.dropdown_selected = if self.dropdown_selected == 0 {
-                        self.dropdown_filtered.len() - 1
-                    } else {
-                        self.dropdown_selected - 1
-                    };
+                    self.dropdown_selected = step_index(
+                        self.dropdown_selected,
+                        self.dropdown_filtered.len(),
+                        false,
+                        self.wrap_navigation,
+                    );
                 }
                 InputAction::None
             }
@@ -624,10 +2657,26 @@ impl App {
         }
     }
 
+    /// Remember how far the list is scrolled before detail or logs replace
+    /// it, so coming back lands on the same rows and not just the same
+    /// selection.
+    fn save_list_offset(&mut self) {
+        self.list_offset = Some(self.table_state.offset());
+    }
+
+    /// Scroll the list back to where `save_list_offset` left it. Views
+    /// opened from search saved nothing and leave the list as it is.
+    fn restore_list_offset(&mut self) {
+        if let Some(offset) = self.list_offset.take() {
+            *self.table_state.offset_mut() = offset;
+        }
+    }
+
     fn handle_detail_input(&mut self, key: KeyEvent) -> InputAction {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
                 self.view_mode = ViewMode::List;
+                self.restore_list_offset();
                 InputAction::None
             }
             KeyCode::Char('j') | KeyCode::Down => {
@@ -639,32 +2688,49 @@ impl App {
                 InputAction::None
             }
             KeyCode::Char('G') => {
-                // Jump to bottom
-                let lines = self.detail_text.lines().count() as u16;
-                self.detail_scroll = lines.saturating_sub(10);
+                // Jump to bottom; render clamps this to the last screenful
+                self.detail_scroll = u16::MAX;
                 InputAction::None
             }
-            KeyCode::Char('g') => {
+            KeyCode::Char('v') => self.toggle_detail_yaml(),
+            KeyCode::Char('x') if self.resource_type.supports_reveal() => {
+                // Re-describe so decoded values are only fetched when asked for,
+                // and fetch the YAML again for `v`
+                self.secret_revealed = !self.secret_revealed;
+                self.detail_raw_yaml.clear();
+                self.detail_yaml = false;
+                self.detail_events = false;
                 self.detail_scroll = 0;
-                InputAction::None
+                InputAction::Describe
             }
-            KeyCode::Char('l') => {
-                if self.resource_type.supports_logs() && self.selected_resource().is_some() {
-                    self.view_mode = ViewMode::Logs;
-                    self.log_lines.clear();
-                    self.log_scroll = 0;
-                    self.log_follow = true;
-                    InputAction::StreamLogs
+            KeyCode::Char('E') => {
+                // Switch between the full description and just the events
+                self.detail_events = !self.detail_events;
+                self.detail_yaml = false;
+                self.detail_scroll = 0;
+                if self.detail_events {
+                    InputAction::DescribeEvents
                 } else {
-                    InputAction::None
+                    InputAction::Describe
                 }
             }
-            KeyCode::Char('d') => {
-                if self.selected_resource().is_some() {
-                    self.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
-                }
+            KeyCode::Char('g') => {
+                self.detail_scroll = 0;
+                InputAction::None
+            }
+            KeyCode::Char('f') => {
+                self.detail_live = !self.detail_live;
                 InputAction::None
             }
+            KeyCode::Char('l') => self.open_selected_logs(),
+            KeyCode::Char('o') => self.jump_to_owner(),
+            KeyCode::Char('d') => self.request_delete(),
+            KeyCode::Char('D') => self.request_force_delete(),
+            KeyCode::Char('p') => self.toggle_port_forward(),
+            KeyCode::Char('=') => self.open_scale_prompt(),
+            KeyCode::Char('y') => self.copy_selected(false),
+            KeyCode::Char('Y') => self.copy_selected(true),
+            KeyCode::Char('c') => self.copy_yaml(),
             KeyCode::Char('r') => {
                 if self.resource_type.supports_restart() && self.selected_resource().is_some() {
                     self.view_mode = ViewMode::Confirm(ConfirmAction::Restart);
@@ -678,25 +2744,131 @@ impl App {
                     InputAction::None
                 }
             }
+            KeyCode::Char('?') => self.open_help(),
+            KeyCode::Char('M') => self.open_message_log(),
             _ => InputAction::None,
         }
     }
 
+    /// Leave the detail view for the list of the described resource's
+    /// owner and select it there. Owners live in the same namespace.
+    fn jump_to_owner(&mut self) -> InputAction {
+        let Some(item) = self.selected_resource() else {
+            return InputAction::None;
+        };
+        let Some((kind, name)) = item.spec.owner.clone() else {
+            let msg = format!("{} has no owner", item.name);
+            self.set_warning(msg);
+            return InputAction::None;
+        };
+        let Some(owner_type) = ResourceType::from_kind(&kind) else {
+            self.set_warning(format!(
+                "Owner {}/{} is not a supported resource type",
+                kind, name
+            ));
+            return InputAction::None;
+        };
+        // A Node owns its mirror pods, and Node rows have no namespace
+        let namespace = if owner_type.is_cluster_scoped() {
+            ""
+        } else {
+            item.namespace.as_str()
+        };
+        let key = format!("{}/{}", namespace, name);
+
+        self.view_mode = ViewMode::List;
+        self.list_offset = None;
+        self.focus = Focus::ResourceList;
+        self.filter.clear();
+        self.filter_active = false;
+        self.problems_only = false;
+        self.marked.clear();
+        self.pending_select = Some(key);
+        let server_filtered = !std::mem::take(&mut self.server_selector).is_empty();
+        if owner_type == self.resource_type && !server_filtered {
+            self.update_filter();
+            self.apply_pending_select();
+            return InputAction::None;
+        }
+        self.resource_type = owner_type;
+        self.pod_node = None;
+        self.sort_column = None;
+        self.sort_ascending = true;
+        self.update_filter();
+        InputAction::ResourceTypeChanged
+    }
+
+    /// `P` on a node, or on a pod for the node it runs on: list the pods
+    /// scheduled there in every namespace, until `Esc` or picking another
+    /// type or namespace.
+    fn show_node_pods(&mut self) -> InputAction {
+        let node = match self.resource_type {
+            ResourceType::Nodes => self.selected_resource_name(),
+            ResourceType::Pods => self
+                .selected_resource()
+                .and_then(|r| r.extra.iter().find(|(k, _)| k == "node"))
+                .map(|(_, node)| node.clone()),
+            _ => return InputAction::None,
+        };
+        let Some(node) = node.filter(|n| !n.is_empty() && n != "<none>") else {
+            self.set_warning("The pod isn't scheduled on a node yet".to_string());
+            return InputAction::None;
+        };
+        self.pod_node = Some(node);
+        self.server_selector = Selector::default();
+        self.resource_type = ResourceType::Pods;
+        if let Some(idx) = self.namespaces.iter().position(|n| n == ALL_NAMESPACES) {
+            self.selected_namespace = idx;
+        }
+        self.filter.clear();
+        self.filter_active = false;
+        self.problems_only = false;
+        self.marked.clear();
+        self.sort_column = None;
+        self.sort_ascending = true;
+        self.update_filter();
+        InputAction::NamespaceChanged
+    }
+
+    /// Select the row queued in `pending_select`, reporting it if the
+    /// freshly listed resources don't contain it.
+    pub fn apply_pending_select(&mut self) {
+        let Some(key) = self.pending_select.take() else {
+            return;
+        };
+        let idx = self
+            .filtered_resources()
+            .iter()
+            .position(|r| format!("{}/{}", r.namespace, r.name) == key);
+        match idx {
+            Some(idx) => self.table_state.select(Some(idx)),
+            None => self.set_warning(format!("{} not found in {}", key, self.resource_type)),
+        }
+    }
+
     fn handle_logs_input(&mut self, key: KeyEvent) -> InputAction {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
                 self.view_mode = ViewMode::List;
+                self.restore_list_offset();
                 InputAction::StopLogs
             }
             KeyCode::Char('f') => {
                 self.log_follow = !self.log_follow;
                 InputAction::None
             }
+            KeyCode::Char('P') => self.toggle_previous_logs(),
+            KeyCode::Char('t') => self.toggle_log_timestamps(),
+            KeyCode::Char('T') => self.open_tail_prompt(),
+            KeyCode::Char('S') => self.cycle_log_since(),
             KeyCode::Char('o') => InputAction::OpenLogsInEditor,
             KeyCode::Char('O') => InputAction::OpenLogsInLess,
+            KeyCode::Char('s') => self.open_save_logs_prompt(),
+            KeyCode::Char('w') => self.toggle_log_wrap(),
+            KeyCode::Char('h') | KeyCode::Left => self.scroll_logs_horizontal(false),
+            KeyCode::Char('l') | KeyCode::Right => self.scroll_logs_horizontal(true),
             KeyCode::Char('G') => {
-                let lines = self.log_lines.len() as u16;
-                self.log_scroll = lines.saturating_sub(10);
+                // Following pins the view to the bottom at render time
                 self.log_follow = true;
                 InputAction::None
             }
@@ -715,6 +2887,8 @@ impl App {
                 self.log_follow = false;
                 InputAction::None
             }
+            KeyCode::Char('?') => self.open_help(),
+            KeyCode::Char('M') => self.open_message_log(),
             _ => InputAction::None,
         }
     }
@@ -724,13 +2898,32 @@ impl App {
             KeyCode::Esc => {
                 self.view_mode = ViewMode::List;
                 self.entered_from_search = false;
-                InputAction::None
+                // Leaving stops a scan that is still running
+                if std::mem::take(&mut self.search_loading) {
+                    InputAction::CancelSearch
+                } else {
+                    InputAction::None
+                }
             }
             KeyCode::Backspace => {
                 self.search_query.pop();
                 self.update_search_filter();
                 InputAction::None
             }
+            // Plain letters go to the query, so copying needs a modifier here
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.copy_search_result()
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.cycle_search_type_filter();
+                InputAction::None
+            }
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if !self.search_failures.is_empty() {
+                    self.search_failures_expanded = !self.search_failures_expanded;
+                }
+                InputAction::None
+            }
             KeyCode::Char(c) => {
                 self.search_query.push(c);
                 self.update_search_filter();
@@ -749,6 +2942,8 @@ impl App {
                     self.view_mode = ViewMode::Detail;
                     self.entered_from_search = true;
                     self.detail_scroll = 0;
+                    self.detail_yaml = false;
+                    self.detail_raw_yaml.clear();
                     self.detail_text.clear();
                     InputAction::SearchDescribe
                 } else {
@@ -774,29 +2969,24 @@ impl App {
                 InputAction::None
             }
             KeyCode::Char('G') => {
-                let lines = self.detail_text.lines().count() as u16;
-                self.detail_scroll = lines.saturating_sub(10);
+                // Jump to bottom; render clamps this to the last screenful
+                self.detail_scroll = u16::MAX;
                 InputAction::None
             }
+            KeyCode::Char('v') => self.toggle_detail_yaml(),
             KeyCode::Char('g') => {
                 self.detail_scroll = 0;
                 InputAction::None
             }
-            KeyCode::Char('l') => {
-                if let Some(result) = self.selected_search_result() {
-                    if result.resource_type == ResourceType::Pods {
-                        self.view_mode = ViewMode::Logs;
-                        self.log_lines.clear();
-                        self.log_scroll = 0;
-                        self.log_follow = true;
-                        InputAction::SearchStreamLogs
-                    } else {
-                        InputAction::None
-                    }
-                } else {
-                    InputAction::None
+            KeyCode::Char('y') => self.copy_search_result(),
+            KeyCode::Char('c') => self.copy_yaml(),
+            KeyCode::Char('l') => match self.selected_search_result() {
+                Some(result) if result.resource_type == ResourceType::Pods => {
+                    let containers = result.resource.spec.containers.clone();
+                    self.open_logs(containers, InputAction::SearchStreamLogs)
                 }
-            }
+                _ => InputAction::None,
+            },
             _ => InputAction::None,
         }
     }
@@ -811,11 +3001,18 @@ impl App {
                 self.log_follow = !self.log_follow;
                 InputAction::None
             }
+            KeyCode::Char('P') => self.toggle_previous_logs(),
+            KeyCode::Char('t') => self.toggle_log_timestamps(),
+            KeyCode::Char('T') => self.open_tail_prompt(),
+            KeyCode::Char('S') => self.cycle_log_since(),
             KeyCode::Char('o') => InputAction::OpenLogsInEditor,
             KeyCode::Char('O') => InputAction::OpenLogsInLess,
+            KeyCode::Char('s') => self.open_save_logs_prompt(),
+            KeyCode::Char('w') => self.toggle_log_wrap(),
+            KeyCode::Char('h') | KeyCode::Left => self.scroll_logs_horizontal(false),
+            KeyCode::Char('l') | KeyCode::Right => self.scroll_logs_horizontal(true),
             KeyCode::Char('G') => {
-                let lines = self.log_lines.len() as u16;
-                self.log_scroll = lines.saturating_sub(10);
+                // Following pins the view to the bottom at render time
                 self.log_follow = true;
                 InputAction::None
             }
@@ -846,7 +3043,7 @@ impl App {
         let i = self
             .search_table_state
             .selected()
-            .map(|i| (i + 1) % len)
+            .map(|i| step_index(i, len, true, self.wrap_navigation))
             .unwrap_or(0);
         self.search_table_state.select(Some(i));
     }
@@ -859,53 +3056,111 @@ impl App {
         let i = self
             .search_table_state
             .selected()
-            .map(|i| if i == 0 { len - 1 } else { i - 1 })
+            .map(|i| step_index(i, len, false, self.wrap_navigation))
             .unwrap_or(0);
         self.search_table_state.select(Some(i));
     }
 
     fn select_next(&mut self) {
-        let len = self.filtered_resources().len();
+        let len = self.filtered.len();
         if len == 0 {
             return;
         }
         let i = self
             .table_state
             .selected()
-            .map(|i| (i + 1) % len)
+            .map(|i| step_index(i, len, true, self.wrap_navigation))
             .unwrap_or(0);
         self.table_state.select(Some(i));
     }
 
+    /// Select row `i`, clamped to the last row. Unlike `j`/`k` this never
+    /// wraps around, whatever `wrap_navigation` says.
+    fn select_row(&mut self, i: usize) {
+        let len = self.filtered.len();
+        if len == 0 {
+            return;
+        }
+        self.table_state.select(Some(i.min(len - 1)));
+    }
+
     fn select_prev(&mut self) {
-        let len = self.filtered_resources().len();
+        let len = self.filtered.len();
         if len == 0 {
             return;
         }
         let i = self
             .table_state
             .selected()
-            .map(|i| if i == 0 { len - 1 } else { i - 1 })
+            .map(|i| step_index(i, len, false, self.wrap_navigation))
             .unwrap_or(0);
         self.table_state.select(Some(i));
     }
 }
 
+/// Screen areas of the last frame that react to the mouse. Empty until
+/// first drawn.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenAreas {
+    pub selectors: [Rect; 3], // Context, Namespace, Type
+    pub list: Rect,
+    pub search_results: Rect,
+    pub content: Rect, // detail or logs pane
+}
+
+/// Row of a bordered table with a one-line header under `(column, row)`,
+/// offset by the rows scrolled past.
+fn table_row_at(area: Rect, offset: usize, column: u16, row: u16) -> Option<usize> {
+    let first_row = area.y.checked_add(2)?;
+    let inside = area.contains(Position::new(column, row)) && row >= first_row;
+    (inside && row < area.bottom().saturating_sub(1)).then(|| offset + (row - first_row) as usize)
+}
+
+/// Move `i` one step through `len` rows, wrapping past either end or
+/// stopping there. `len` must be non-zero.
+fn step_index(i: usize, len: usize, forward: bool, wrap: bool) -> usize {
+    match (forward, wrap) {
+        (true, true) => (i + 1) % len,
+        (true, false) => (i + 1).min(len - 1),
+        (false, true) => i.checked_sub(1).unwrap_or(len - 1),
+        (false, false) => i.saturating_sub(1),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputAction {
     None,
     ContextChanged,
     NamespaceChanged,
     ResourceTypeChanged,
+    Refresh,
     Describe,
+    DescribeEvents,
+    LoadYaml,
     StreamLogs,
     StopLogs,
     Delete,
+    ForceDelete,
+    ApplyEdit,
+    DryRunEdit,
+    PreviewDelete,
     Restart,
+    PortForward,
+    StopPortForward,
+    Scale,
+    CopyName,
+    /// Copy `detail_raw_yaml` to the clipboard, loading it first if needed.
+    CopyYaml,
     Edit,
+    /// Open a template for a new resource of the current type in `$EDITOR`.
+    Create,
     OpenLogsInEditor,
     OpenLogsInLess,
+    SaveLogs,
     StartSearch,
+    CancelSearch,
     SearchDescribe,
     SearchStreamLogs,
+    /// Remembered state (recent namespaces, favorites) changed; main saves it.
+    SaveState,
 }
@@ -3,7 +3,14 @@ mod tests {
     use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 
     use crate::app::{App, InputAction};
-    use crate::types::{ConfirmAction, Focus, ResourceItem, ResourceType, ViewMode};
+    use crate::k8s::resources::{
+        daemonset_to_resource_item, job_to_resource_item, pod_status, secret_yaml,
+    };
+    use crate::test_support::{ago, fake_event};
+    use crate::types::{
+        ColumnLayout, ConfirmAction, ContextHealth, Focus, PromptKind, ResourceItem, ResourceType,
+        SpecSummary, ViewMode, ALL_NAMESPACES,
+    };
 
     fn key(code: KeyCode) -> KeyEvent {
         KeyEvent {
@@ -28,23 +35,23 @@ mod tests {
             name: name.to_string(),
             namespace: "default".to_string(),
             status: status.to_string(),
-            age: "1h".to_string(),
+            created: ago(3600),
             extra: vec![
                 ("restarts".to_string(), "0".to_string()),
                 ("node".to_string(), "node-a".to_string()),
             ],
-            raw_yaml: "---\napiVersion: v1\nkind: Pod".to_string(),
+            spec: SpecSummary::default(),
         }
     }
 
     fn app_with_pods() -> App {
         let mut app = App::new();
         app.focus = Focus::ResourceList;
-        app.resources = vec![
+        app.set_resources(vec![
             fake_pod("pod-0", "Running"),
             fake_pod("pod-1", "Pending"),
             fake_pod("pod-2", "Running"),
-        ];
+        ]);
         app
     }
 
@@ -63,6 +70,40 @@ mod tests {
         assert!(app.should_quit);
     }
 
+    #[test]
+    fn test_quit_asks_while_actions_are_in_flight() {
+        let mut app = app_with_pods();
+        let _operation = app.in_flight.start();
+        app.handle_input(key(KeyCode::Char('q')));
+        assert!(!app.should_quit);
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Quit));
+
+        app.handle_input(key(KeyCode::Char('n')));
+        assert_eq!(app.view_mode, ViewMode::List);
+        assert!(!app.should_quit);
+
+        app.danger_contexts = vec![app.current_context().to_string()];
+        app.handle_input(key(KeyCode::Char('q')));
+        app.handle_input(key(KeyCode::Char('y')));
+        assert!(app.should_quit, "One y quits, even in danger mode");
+
+        let mut app = app_with_pods();
+        let _busy = app.in_flight.start();
+        app.handle_input(key_with_mod(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(app.should_quit, "Ctrl+C quits without asking");
+    }
+
+    #[test]
+    fn test_finished_actions_no_longer_block_quit() {
+        let mut app = app_with_pods();
+        let operation = app.in_flight.start();
+        assert_eq!(app.in_flight.count(), 1);
+        drop(operation);
+        assert_eq!(app.in_flight.count(), 0);
+        app.handle_input(key(KeyCode::Char('q')));
+        assert!(app.should_quit);
+    }
+
     #[test]
     fn test_navigate_down_j() {
         let mut app = app_with_pods();
@@ -169,6 +210,109 @@ mod tests {
         assert_eq!(app.focus, Focus::NamespaceSelector);
     }
 
+    #[test]
+    fn test_recent_namespaces_pinned_in_dropdown() {
+        let mut app = App::new();
+        app.contexts = vec!["gke-prod".to_string()];
+        app.set_namespaces(vec![
+            "default".to_string(),
+            "kube-system".to_string(),
+            "payments".to_string(),
+            "search".to_string(),
+        ]);
+        let pick = |app: &mut App, ns: &str| {
+            app.focus = Focus::NamespaceSelector;
+            app.dropdown_open();
+            type_str(app, ns);
+            app.handle_input(key(KeyCode::Enter))
+        };
+
+        assert_eq!(pick(&mut app, "search"), InputAction::NamespaceChanged);
+        assert_eq!(pick(&mut app, "payments"), InputAction::NamespaceChanged);
+        assert_eq!(
+            app.recent_namespaces["gke-prod"],
+            vec!["payments", "search"]
+        );
+
+        app.focus = Focus::NamespaceSelector;
+        app.dropdown_open();
+        let items = app.dropdown_items();
+        let order: Vec<&str> = app
+            .dropdown_filtered
+            .iter()
+            .map(|&i| items[i].as_str())
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                "payments",
+                "search",
+                ALL_NAMESPACES,
+                "default",
+                "kube-system"
+            ]
+        );
+        assert_eq!(
+            order[app.dropdown_selected], "payments",
+            "The current namespace is selected"
+        );
+
+        // Picking one again moves it to the front instead of repeating it
+        app.handle_input(key(KeyCode::Esc));
+        pick(&mut app, "search");
+        assert_eq!(
+            app.recent_namespaces["gke-prod"],
+            vec!["search", "payments"]
+        );
+    }
+
+    #[test]
+    fn test_favorite_contexts_filter_dropdown_and_search() {
+        let mut app = App::new();
+        app.contexts = vec![
+            "gke-prod".to_string(),
+            "gke-staging".to_string(),
+            "minikube".to_string(),
+        ];
+        let ctrl = |c| key_with_mod(KeyCode::Char(c), KeyModifiers::CONTROL);
+        app.focus = Focus::ContextSelector;
+        app.dropdown_open();
+
+        // Nothing starred yet: favorites-only refuses and search scans everything
+        app.handle_input(ctrl('o'));
+        assert!(!app.favorites_only);
+        assert_eq!(app.search_contexts().len(), 3);
+
+        app.dropdown_selected = 2;
+        assert_eq!(app.handle_input(ctrl('s')), InputAction::SaveState);
+        assert!(app.favorite_contexts.contains("minikube"));
+        assert_eq!(
+            app.dropdown_filtered,
+            vec![2, 0, 1],
+            "Favorites are pinned first"
+        );
+        assert_eq!(
+            app.dropdown_selected, 0,
+            "The highlight follows the starred context"
+        );
+        assert_eq!(app.search_contexts(), vec!["minikube"]);
+
+        app.handle_input(ctrl('o'));
+        assert!(app.favorites_only);
+        assert_eq!(app.dropdown_filtered, vec![2]);
+        type_str(&mut app, "gke");
+        assert!(
+            app.dropdown_filtered.is_empty(),
+            "The query only matches favorites"
+        );
+
+        app.handle_input(key(KeyCode::Esc));
+        app.dropdown_open();
+        app.handle_input(ctrl('s'));
+        assert!(app.favorite_contexts.is_empty(), "Ctrl+S again unstars");
+        assert_eq!(app.search_contexts().len(), 3);
+    }
+
     #[test]
     fn test_context_selector_fuzzy_filter() {
         let mut app = App::new();
@@ -215,6 +359,43 @@ mod tests {
         assert_eq!(app.focus, Focus::ResourceTypeSelector);
     }
 
+    #[test]
+    fn test_namespace_dropdown_accepts_typed_name() {
+        let mut app = App::new();
+        app.set_namespaces(vec!["default".to_string()]);
+        app.focus = Focus::NamespaceSelector;
+        app.dropdown_open();
+
+        // Enter with nothing typed and nothing matching does nothing
+        app.dropdown_filtered.clear();
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::None);
+        assert_eq!(app.current_namespace(), "default");
+
+        // A name that matches none listed is opened as typed
+        app.focus = Focus::NamespaceSelector;
+        app.dropdown_open();
+        type_str(&mut app, " team-a ");
+        assert!(app.dropdown_filtered.is_empty());
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::NamespaceChanged
+        );
+        assert_eq!(app.current_namespace(), "team-a");
+        assert_eq!(app.namespaces, vec![ALL_NAMESPACES, "default", "team-a"]);
+        assert_eq!(app.recent_namespaces[app.current_context()], vec!["team-a"]);
+
+        // A name that matches goes through the list as usual
+        app.focus = Focus::NamespaceSelector;
+        app.dropdown_open();
+        type_str(&mut app, "default");
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::NamespaceChanged
+        );
+        assert_eq!(app.current_namespace(), "default");
+        assert_eq!(app.namespaces.len(), 3);
+    }
+
     #[test]
     fn test_resource_type_selector_dropdown() {
         let mut app = App::new();
@@ -435,6 +616,477 @@ mod tests {
         assert_eq!(app.detail_scroll, 0);
     }
 
+    #[test]
+    fn test_detail_yaml_toggle() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Enter));
+        app.detail_text = "Name: pod-0".to_string();
+        assert_eq!(app.detail_content(), "Name: pod-0");
+
+        app.detail_scroll = 3;
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('v'))),
+            InputAction::LoadYaml,
+            "YAML is fetched when first shown"
+        );
+        assert!(app.detail_yaml);
+        assert_eq!(app.detail_scroll, 0, "Switching views starts at the top");
+        app.detail_raw_yaml = "---\napiVersion: v1\nkind: Pod".to_string();
+        assert_eq!(app.detail_content(), "---\napiVersion: v1\nkind: Pod");
+
+        app.handle_input(key(KeyCode::Char('v')));
+        assert_eq!(app.detail_content(), "Name: pod-0");
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('v'))),
+            InputAction::None,
+            "Already fetched for this resource"
+        );
+
+        // Re-entering detail always starts on the describe text
+        app.handle_input(key(KeyCode::Esc));
+        app.handle_input(key(KeyCode::Enter));
+        assert!(!app.detail_yaml);
+        assert!(
+            app.detail_raw_yaml.is_empty(),
+            "A new resource needs a new fetch"
+        );
+    }
+
+    #[test]
+    fn test_secret_reveal_toggle() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('x'))),
+            InputAction::None,
+            "Only Secrets can be revealed"
+        );
+        assert!(!app.secret_revealed);
+
+        app.resource_type = ResourceType::Secrets;
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('x'))),
+            InputAction::Describe
+        );
+        assert!(app.secret_revealed);
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('x'))),
+            InputAction::Describe
+        );
+        assert!(!app.secret_revealed);
+
+        // The YAML view is fetched again with the new setting
+        app.detail_raw_yaml = "data: {password: <hidden, 6 bytes>}".to_string();
+        app.handle_input(key(KeyCode::Char('x')));
+        assert!(app.detail_raw_yaml.is_empty());
+
+        // Leaving and re-entering detail hides values again
+        app.handle_input(key(KeyCode::Esc));
+        app.handle_input(key(KeyCode::Enter));
+        assert!(!app.secret_revealed);
+    }
+
+    #[test]
+    fn test_secret_yaml_masks_values_until_revealed() {
+        let secret = serde_yaml::from_str(
+            "
+metadata:
+  name: credentials
+  annotations:
+    kubectl.kubernetes.io/last-applied-configuration: '{\"stringData\":{\"password\":\"hunter2\"}}'
+data:
+  password: aHVudGVyMg==
+",
+        )
+        .unwrap();
+        let masked = secret_yaml(&secret, false).unwrap();
+        assert!(
+            masked.contains("password: <hidden, 7 bytes>"),
+            "got:\n{}",
+            masked
+        );
+        assert!(!masked.contains("aHVudGVyMg=="));
+        assert!(!masked.contains("hunter2"));
+        assert!(masked.contains("name: credentials"));
+
+        let revealed = secret_yaml(&secret, true).unwrap();
+        assert!(revealed.contains("password: aHVudGVyMg=="));
+    }
+
+    #[test]
+    fn test_job_rows_take_containers_from_the_template() {
+        let job = job_to_resource_item(
+            &serde_yaml::from_str(
+                "
+metadata: {name: migrate, namespace: default}
+spec:
+  template:
+    spec:
+      containers:
+      - {name: migrate, ports: [{containerPort: 8080}]}
+      - {name: proxy}
+",
+            )
+            .unwrap(),
+        );
+        assert_eq!(job.spec.containers, vec!["migrate", "proxy"]);
+        assert_eq!(job.spec.container_port, Some(8080));
+    }
+
+    #[test]
+    fn test_jump_to_owner() {
+        let mut app = app_with_pods();
+        app.resources[0].spec.owner = Some(("ReplicaSet".to_string(), "web-5d8f".to_string()));
+        app.filter = "pod-0".to_string();
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('o'))),
+            InputAction::ResourceTypeChanged
+        );
+        assert_eq!(app.resource_type, ResourceType::ReplicaSets);
+        assert_eq!(app.view_mode, ViewMode::List);
+        assert!(app.filter.is_empty(), "Filter could hide the owner");
+
+        app.set_resources(vec![fake_pod("web-1111", ""), fake_pod("web-5d8f", "")]);
+        app.apply_pending_select();
+        assert_eq!(app.table_state.selected(), Some(1));
+        assert!(app.pending_select.is_none());
+    }
+
+    #[test]
+    fn test_jump_to_node_owner_of_mirror_pod() {
+        let mut app = app_with_pods();
+        app.resources[0].spec.owner = Some(("Node".to_string(), "node-b".to_string()));
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('o'))),
+            InputAction::ResourceTypeChanged
+        );
+        assert_eq!(app.resource_type, ResourceType::Nodes);
+        assert_eq!(app.pending_select.as_deref(), Some("/node-b"));
+
+        let mut nodes = vec![fake_pod("node-a", "Ready"), fake_pod("node-b", "Ready")];
+        for node in &mut nodes {
+            node.namespace = String::new();
+        }
+        app.set_resources(nodes);
+        app.apply_pending_select();
+        assert_eq!(app.table_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_bookmark_toggle_and_jump_in_same_context() {
+        let mut app = app_with_pods();
+        app.set_namespaces(vec!["default".to_string(), "payments".to_string()]);
+        app.selected_namespace = 2;
+        app.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('b'))),
+            InputAction::SaveState
+        );
+        assert_eq!(app.bookmarks.len(), 1);
+        assert_eq!(app.bookmarks[0].name, "pod-1");
+        assert_eq!(app.bookmarks[0].namespace, "payments");
+        assert_eq!(app.status_text(), Some("Bookmarked pods/pod-1"));
+
+        // Going back from another namespace and type
+        app.selected_namespace = 1;
+        app.resource_type = ResourceType::Services;
+        app.handle_input(key(KeyCode::Char('B')));
+        assert!(app.show_bookmarks);
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::NamespaceChanged
+        );
+        assert!(!app.show_bookmarks);
+        assert_eq!(app.current_namespace(), "payments");
+        assert_eq!(app.resource_type, ResourceType::Pods);
+        let mut pods = vec![fake_pod("pod-0", ""), fake_pod("pod-1", "")];
+        for pod in &mut pods {
+            pod.namespace = "payments".to_string();
+        }
+        app.set_resources(pods);
+        app.apply_pending_select();
+        assert_eq!(app.table_state.selected(), Some(1));
+
+        // `b` again on the same row removes it
+        app.handle_input(key(KeyCode::Char('b')));
+        assert!(app.bookmarks.is_empty());
+    }
+
+    #[test]
+    fn test_bookmark_jump_switches_context() {
+        let mut app = app_with_pods();
+        app.contexts = vec!["gke-prod".to_string(), "minikube".to_string()];
+        app.bookmarks.push(crate::types::Bookmark {
+            context: "minikube".to_string(),
+            namespace: "cache".to_string(),
+            resource_type: ResourceType::StatefulSets,
+            name: "redis".to_string(),
+        });
+        app.bookmarks.push(crate::types::Bookmark {
+            context: "gone".to_string(),
+            namespace: "default".to_string(),
+            resource_type: ResourceType::Pods,
+            name: "pod-0".to_string(),
+        });
+
+        app.handle_input(key(KeyCode::Char('B')));
+        app.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::None);
+        assert_eq!(
+            app.status_text(),
+            Some("Context gone is no longer in the kubeconfig")
+        );
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('d'))),
+            InputAction::SaveState
+        );
+        assert_eq!(app.bookmarks.len(), 1);
+        assert_eq!(app.bookmark_selected, 0);
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::ContextChanged
+        );
+        assert_eq!(app.current_context(), "minikube");
+        assert_eq!(app.pending_namespace.as_deref(), Some("cache"));
+        assert_eq!(app.resource_type, ResourceType::StatefulSets);
+        assert_eq!(app.pending_select.as_deref(), Some("cache/redis"));
+    }
+
+    #[test]
+    fn test_bookmark_on_cluster_scoped_type() {
+        let mut app = app_with_pods();
+        app.set_namespaces(vec!["default".to_string(), "payments".to_string()]);
+        app.selected_namespace = 2;
+        app.resource_type = ResourceType::Nodes;
+        let mut nodes = vec![fake_pod("node-a", "Ready"), fake_pod("node-b", "Ready")];
+        for node in &mut nodes {
+            node.namespace = String::new();
+        }
+        app.set_resources(nodes.clone());
+        app.update_filter();
+        app.handle_input(key(KeyCode::Char('j')));
+        app.handle_input(key(KeyCode::Char('b')));
+        assert_eq!(app.bookmarks[0].namespace, "");
+
+        // The namespace is left as it is and the row found by name
+        app.selected_namespace = 1;
+        app.resource_type = ResourceType::Services;
+        app.handle_input(key(KeyCode::Char('B')));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::ResourceTypeChanged
+        );
+        assert_eq!(app.current_namespace(), "default");
+        assert_eq!(app.resource_type, ResourceType::Nodes);
+        app.set_resources(nodes);
+        app.apply_pending_select();
+        assert_eq!(app.table_state.selected(), Some(1));
+
+        // In another context, the context's default namespace opens
+        app.contexts = vec!["gke-prod".to_string(), "minikube".to_string()];
+        app.bookmarks = vec![crate::types::Bookmark {
+            context: "minikube".to_string(),
+            namespace: String::new(),
+            resource_type: ResourceType::PersistentVolumes,
+            name: "pv-0".to_string(),
+        }];
+        app.handle_input(key(KeyCode::Char('B')));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::ContextChanged
+        );
+        assert_eq!(app.pending_namespace, None);
+        assert_eq!(app.pending_select.as_deref(), Some("/pv-0"));
+    }
+
+    #[test]
+    fn test_bookmark_saved_with_type_name() {
+        let bookmark = crate::types::Bookmark {
+            context: "gke-prod".to_string(),
+            namespace: "payments".to_string(),
+            resource_type: ResourceType::Deployments,
+            name: "api".to_string(),
+        };
+        let yaml = serde_yaml::to_string(&bookmark).unwrap();
+        assert!(yaml.contains("type: deployments"), "got: {}", yaml);
+        let parsed: crate::types::Bookmark = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, bookmark);
+        assert!(serde_yaml::from_str::<crate::types::Bookmark>(
+            "context: a\nnamespace: b\ntype: widgets\nname: c\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_state_remembers_resource_type() {
+        let state = crate::state::State {
+            resource_type: Some(ResourceType::StatefulSets),
+            ..Default::default()
+        };
+        let yaml = serde_yaml::to_string(&state).unwrap();
+        assert!(
+            yaml.contains("resource_type: statefulsets"),
+            "got: {}",
+            yaml
+        );
+        let parsed: crate::state::State = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.resource_type, Some(ResourceType::StatefulSets));
+
+        let older: crate::state::State = serde_yaml::from_str("bookmarks: []\n").unwrap();
+        assert_eq!(
+            older.resource_type, None,
+            "State saved before the type was kept"
+        );
+    }
+
+    #[test]
+    fn test_state_parse_errors_are_reported() {
+        use crate::state::parse;
+
+        assert!(
+            parse("").unwrap().bookmarks.is_empty(),
+            "An empty file is a fresh state"
+        );
+        // A newer kterm's resource type must not silently drop the bookmarks
+        let err = parse(
+            "bookmarks: [{context: a, namespace: b, type: pods, name: c}]\n\
+             resource_type: widgets\n",
+        );
+        assert!(err.is_err());
+        assert!(parse("bookmarks: {").is_err());
+
+        // One unreadable bookmark only loses itself
+        let state = parse(
+            "bookmarks:\n\
+             - {context: a, namespace: b, type: widgets, name: c}\n\
+             - {context: a, namespace: b, name: no-type}\n\
+             - {context: a, namespace: b, type: pods, name: d}\n",
+        )
+        .unwrap();
+        let names: Vec<&str> = state.bookmarks.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["d"]);
+    }
+
+    #[test]
+    fn test_jump_to_owner_without_supported_owner() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(app.handle_input(key(KeyCode::Char('o'))), InputAction::None);
+        assert_eq!(app.view_mode, ViewMode::Detail);
+        assert_eq!(app.status_text(), Some("pod-0 has no owner"));
+
+        app.resources[0].spec.owner = Some(("Widget".to_string(), "w".to_string()));
+        assert_eq!(app.handle_input(key(KeyCode::Char('o'))), InputAction::None);
+        assert_eq!(app.resource_type, ResourceType::Pods);
+        assert_eq!(
+            app.status_text(),
+            Some("Owner Widget/w is not a supported resource type")
+        );
+    }
+
+    #[test]
+    fn test_image_layout_toggle() {
+        let mut app = app_with_pods();
+        app.resources[1]
+            .extra
+            .push(("image".to_string(), "nginx:1.27".to_string()));
+        app.sort_column = Some(5);
+        app.handle_input(key(KeyCode::Char('i')));
+        assert!(app.show_images);
+        assert_eq!(app.sort_column, None, "Layouts don't share column indices");
+        assert_eq!(app.column_headers()[4], "IMAGE");
+        let cols = app.resources[1].columns(&app.column_headers());
+        assert_eq!(cols[4], "nginx:1.27");
+        assert_eq!(app.resources[0].columns(&app.column_headers())[4], "<none>");
+
+        app.resource_type = ResourceType::Services;
+        assert_eq!(
+            app.column_headers(),
+            ResourceType::Services.column_headers(ColumnLayout::Regular)
+        );
+        app.handle_input(key(KeyCode::Char('i')));
+        assert!(app.show_images, "Only types with an image layout toggle it");
+    }
+
+    #[test]
+    fn test_column_layout_cycle() {
+        let mut app = app_with_pods();
+        app.resources[1]
+            .extra
+            .push(("ip".to_string(), "10.0.0.7".to_string()));
+        app.show_images = true;
+        app.sort_column = Some(4);
+
+        app.handle_input(key(KeyCode::Char('w')));
+        assert_eq!(app.column_layout, ColumnLayout::Wide);
+        assert!(!app.show_images, "Cycling leaves the image layout");
+        assert_eq!(app.sort_column, None, "Layouts don't share column indices");
+        assert_eq!(app.column_headers()[5], "IP");
+        assert_eq!(
+            app.resources[1].columns(&app.column_headers())[5],
+            "10.0.0.7"
+        );
+
+        app.handle_input(key(KeyCode::Char('w')));
+        assert_eq!(app.column_headers(), vec!["NAME", "STATUS", "AGE"]);
+        app.handle_input(key(KeyCode::Char('w')));
+        assert_eq!(app.column_layout, ColumnLayout::Regular);
+
+        app.resource_type = ResourceType::Services;
+        app.handle_input(key(KeyCode::Char('w')));
+        assert_eq!(
+            app.column_layout,
+            ColumnLayout::Regular,
+            "Only pods have layouts"
+        );
+    }
+
+    #[test]
+    fn test_events_view_toggle() {
+        let mut app = app_with_pods();
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('E'))),
+            InputAction::DescribeEvents
+        );
+        assert_eq!(app.view_mode, ViewMode::Detail);
+        assert!(app.detail_events);
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('E'))),
+            InputAction::Describe
+        );
+        assert!(!app.detail_events);
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('E'))),
+            InputAction::DescribeEvents
+        );
+
+        // Enter always opens the full description
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::Describe);
+        assert!(!app.detail_events);
+    }
+
+    #[test]
+    fn test_refresh_relists_resources() {
+        let mut app = app_with_pods();
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('R'))),
+            InputAction::Refresh
+        );
+        assert!(
+            app.loading,
+            "Refresh shows the loading state until results arrive"
+        );
+
+        app.loading = false;
+        assert_eq!(app.handle_input(key(KeyCode::F(5))), InputAction::Refresh);
+        assert_eq!(app.view_mode, ViewMode::List);
+    }
+
     #[test]
     fn test_logs_view_for_pods() {
         let mut app = app_with_pods();
@@ -453,6 +1105,188 @@ mod tests {
         assert_eq!(app.view_mode, ViewMode::List);
     }
 
+    #[test]
+    fn test_logs_for_workload_aggregate_pods() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Char('l')));
+        assert!(!app.log_aggregate, "Pod logs come from a single pod");
+        app.handle_input(key(KeyCode::Esc));
+
+        app.resource_type = ResourceType::Deployments;
+        app.resources[0].spec.containers = vec!["app".to_string(), "sidecar".to_string()];
+        let action = app.handle_input(key(KeyCode::Char('l')));
+        assert_eq!(
+            action,
+            InputAction::None,
+            "Template containers open the picker"
+        );
+        assert_eq!(app.container_choices, vec!["app", "sidecar"]);
+        assert!(app.log_aggregate);
+
+        let action = app.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, InputAction::StreamLogs);
+        assert_eq!(app.log_container.as_deref(), Some("app"));
+    }
+
+    #[test]
+    fn test_daemonset_logs_aggregate_pods() {
+        let daemonset = |containers: &str| {
+            let yaml = format!(
+                "
+metadata: {{name: logging, namespace: default}}
+spec:
+  selector: {{matchLabels: {{app: logging}}}}
+  template:
+    spec:
+      containers: {}
+",
+                containers
+            );
+            daemonset_to_resource_item(&serde_yaml::from_str(&yaml).unwrap())
+        };
+        let mut app = app_with_pods();
+        app.resource_type = ResourceType::DaemonSets;
+        app.set_resources(vec![daemonset("[{name: fluent-bit}]")]);
+        app.update_filter();
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('l'))),
+            InputAction::StreamLogs
+        );
+        assert!(app.log_aggregate);
+        assert_eq!(app.log_container.as_deref(), Some("fluent-bit"));
+
+        // Several containers in the template: pick one first
+        app.handle_input(key(KeyCode::Esc));
+        app.set_resources(vec![daemonset("[{name: fluent-bit}, {name: exporter}]")]);
+        app.update_filter();
+        assert_eq!(app.handle_input(key(KeyCode::Char('l'))), InputAction::None);
+        assert_eq!(app.container_choices, vec!["fluent-bit", "exporter"]);
+    }
+
+    #[test]
+    fn test_logs_by_label_selector() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Char('L')));
+        assert_eq!(
+            app.prompt.as_ref().map(|p| p.kind),
+            Some(PromptKind::LogSelector)
+        );
+
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(
+            app.view_mode,
+            ViewMode::List,
+            "An empty selector is rejected"
+        );
+        assert!(app.status_message.is_some());
+
+        app.handle_input(key(KeyCode::Char('L')));
+        type_str(&mut app, "app=nginx");
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::StreamLogs
+        );
+        assert_eq!(app.view_mode, ViewMode::Logs);
+        assert!(app.log_aggregate);
+        assert_eq!(app.log_selector.as_deref(), Some("app=nginx"));
+
+        app.handle_input(key(KeyCode::Esc));
+        app.handle_input(key(KeyCode::Char('l')));
+        assert_eq!(app.log_selector, None, "Pod logs don't keep the selector");
+    }
+
+    #[test]
+    fn test_live_detail_refreshes_until_frozen() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Enter));
+        app.detail_target = Some((
+            "default".to_string(),
+            "pod-0".to_string(),
+            ResourceType::Pods,
+        ));
+        app.loading = true;
+        assert!(
+            (0..16).all(|_| !app.detail_refresh_due()),
+            "Not while a describe loads"
+        );
+
+        app.loading = false;
+        app.detail_text = "described".to_string();
+        app.detail_scroll = 3;
+        let due = (0..16).filter(|_| app.detail_refresh_due()).count();
+        assert_eq!(due, 2, "About every 2s of ticks");
+        assert_eq!(app.detail_scroll, 3);
+
+        // A slow refresh holds off the next one until it answers
+        app.detail_refresh_in_flight = true;
+        assert!((0..16).all(|_| !app.detail_refresh_due()));
+        let target = app.detail_target.clone().unwrap();
+        app.detail_loaded(target, "described again".to_string());
+        assert!(!app.detail_refresh_in_flight);
+        assert_eq!((0..16).filter(|_| app.detail_refresh_due()).count(), 2);
+
+        app.handle_input(key(KeyCode::Char('f')));
+        assert!(!app.detail_live);
+        assert!((0..16).all(|_| !app.detail_refresh_due()));
+
+        app.handle_input(key(KeyCode::Char('f')));
+        app.handle_input(key(KeyCode::Esc));
+        assert!(
+            (0..16).all(|_| !app.detail_refresh_due()),
+            "Only in the detail view"
+        );
+    }
+
+    #[test]
+    fn test_detail_results_only_apply_to_the_open_resource() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Enter));
+        let target = |name: &str| ("default".to_string(), name.to_string(), ResourceType::Pods);
+        app.detail_target = Some(target("pod-1"));
+        app.loading = true;
+
+        // A slow describe of the previous resource lands after the switch
+        app.detail_loaded(target("pod-0"), "pod-0 text".to_string());
+        app.detail_failed(target("pod-0"), "Describe error: gone".to_string());
+        assert!(app.detail_text.is_empty());
+        assert!(app.loading);
+        assert!(app.status_message.is_none());
+
+        app.detail_loaded(target("pod-1"), "pod-1 text".to_string());
+        assert_eq!(app.detail_text, "pod-1 text");
+        assert!(!app.loading);
+
+        // Same name, other type: not the open view either
+        let deployment = (
+            "default".to_string(),
+            "pod-1".to_string(),
+            ResourceType::Deployments,
+        );
+        app.detail_loaded(deployment, "deployment text".to_string());
+        assert_eq!(app.detail_text, "pod-1 text");
+
+        // An error freezes the live view instead of recurring every refresh
+        app.detail_failed(target("pod-1"), "Describe error: not found".to_string());
+        assert!(!app.detail_live);
+        assert!((0..16).all(|_| !app.detail_refresh_due()));
+        let (_, message) = app.status_message.clone().unwrap();
+        assert!(message.contains("not found"), "{}", message);
+        assert_eq!(app.detail_text, "pod-1 text", "Keeps the last good text");
+    }
+
+    #[test]
+    fn test_set_namespaces_selects_kubeconfig_default() {
+        let mut app = App::new();
+        app.preferred_namespace = Some("team-b".to_string());
+        app.set_namespaces(vec!["default".to_string(), "team-b".to_string()]);
+        assert_eq!(app.current_namespace(), "team-b");
+
+        // A context whose default isn't in the cluster opens the first one
+        app.preferred_namespace = Some("missing".to_string());
+        app.set_namespaces(vec!["kube-system".to_string(), "team-b".to_string()]);
+        assert_eq!(app.current_namespace(), "kube-system");
+    }
+
     #[test]
     fn test_logs_follow_toggle() {
         let mut app = app_with_pods();
@@ -476,6 +1310,22 @@ mod tests {
         assert_eq!(app.view_mode, ViewMode::List);
     }
 
+    #[test]
+    fn test_all_namespaces_targets_row_namespace() {
+        let mut app = app_with_pods();
+        app.namespaces = vec![ALL_NAMESPACES.to_string(), "default".to_string()];
+        app.selected_namespace = 0;
+        app.resources[0].namespace = "kube-system".to_string();
+        app.table_state.select(Some(0));
+
+        assert!(app.all_namespaces());
+        assert_eq!(app.selected_resource_namespace(), "kube-system");
+
+        app.selected_namespace = 1;
+        assert!(!app.all_namespaces());
+        assert_eq!(app.selected_resource_namespace(), "default");
+    }
+
     #[test]
     fn test_delete_confirm_flow() {
         let mut app = app_with_pods();
@@ -492,536 +1342,2981 @@ mod tests {
     }
 
     #[test]
-    fn test_delete_cancel_flow() {
+    fn test_danger_context_needs_two_confirmations() {
         let mut app = app_with_pods();
+        app.contexts = vec!["gke-Prod-eu".to_string(), "minikube".to_string()];
+        app.danger_contexts = vec!["prod".to_string()];
+        assert!(app.danger_mode(), "Fragments match ignoring case");
 
         app.handle_input(key(KeyCode::Char('d')));
+        assert_eq!(app.handle_input(key(KeyCode::Char('y'))), InputAction::None);
+        assert!(app.confirm_armed);
         assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Delete));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('y'))),
+            InputAction::Delete
+        );
+        assert!(!app.confirm_armed);
 
-        // Press n -> cancel
-        let action = app.handle_input(key(KeyCode::Char('n')));
-        assert_eq!(action, InputAction::None);
+        // Cancelling disarms, so the next dialog starts over
+        app.handle_input(key(KeyCode::Char('d')));
+        app.handle_input(key(KeyCode::Char('y')));
+        app.handle_input(key(KeyCode::Char('n')));
+        assert!(!app.confirm_armed);
         assert_eq!(app.view_mode, ViewMode::List);
+
+        app.open_edit_preview(crate::types::PendingEdit::new(
+            "default".to_string(),
+            "pod-0".to_string(),
+            ResourceType::Pods,
+            "spec:\n  replicas: 1\n",
+            "spec:\n  replicas: 2\n".to_string(),
+        ));
+        assert_eq!(app.handle_input(key(KeyCode::Char('y'))), InputAction::None);
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('y'))),
+            InputAction::ApplyEdit
+        );
+
+        app.selected_context = 1;
+        assert!(!app.danger_mode());
+        app.handle_input(key(KeyCode::Char('d')));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('y'))),
+            InputAction::Delete
+        );
     }
 
     #[test]
-    fn test_restart_confirm_flow() {
+    fn test_force_delete_confirm_flow() {
         let mut app = app_with_pods();
 
-        app.handle_input(key(KeyCode::Char('r')));
-        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Restart));
+        app.handle_input(key(KeyCode::Char('D')));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::ForceDelete));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('y'))),
+            InputAction::ForceDelete
+        );
+        assert_eq!(app.view_mode, ViewMode::List);
 
-        let action = app.handle_input(key(KeyCode::Char('y')));
-        assert_eq!(action, InputAction::Restart);
+        // Only pods can be force deleted
+        app.resource_type = ResourceType::Deployments;
+        app.handle_input(key(KeyCode::Char('D')));
         assert_eq!(app.view_mode, ViewMode::List);
     }
 
     #[test]
-    fn test_edit_action() {
+    fn test_list_vim_motions() {
         let mut app = app_with_pods();
-        let action = app.handle_input(key(KeyCode::Char('e')));
-        assert_eq!(action, InputAction::Edit);
+        app.table_state.select(Some(0));
+
+        app.handle_input(key(KeyCode::Char('G')));
+        assert_eq!(app.table_state.selected(), Some(2));
+        type_str(&mut app, "gg");
+        assert_eq!(app.table_state.selected(), Some(0));
+
+        // Counts clamp at the ends instead of wrapping
+        type_str(&mut app, "5j");
+        assert_eq!(app.table_state.selected(), Some(2));
+        type_str(&mut app, "1k");
+        assert_eq!(app.table_state.selected(), Some(1));
+        type_str(&mut app, "2gg");
+        assert_eq!(app.table_state.selected(), Some(1));
+        type_str(&mut app, "1G");
+        assert_eq!(app.table_state.selected(), Some(0));
+
+        // A lone g swallows the next key rather than acting on it
+        type_str(&mut app, "gj");
+        assert_eq!(app.table_state.selected(), Some(0));
+        assert!(!app.pending_g);
+        type_str(&mut app, "gq");
+        assert!(!app.should_quit);
+
+        // A count before a non-motion key is dropped
+        type_str(&mut app, "3 ");
+        assert_eq!(app.pending_count, None);
+        assert_eq!(app.marked.len(), 1);
     }
 
     #[test]
-    fn test_filter_mode() {
+    fn test_wrap_navigation_off_stops_at_ends() {
         let mut app = app_with_pods();
+        app.table_state.select(Some(2));
+        app.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(app.table_state.selected(), Some(0), "Wraps by default");
 
-        // Enter filter mode
-        app.handle_input(key(KeyCode::Char('/')));
-        assert!(app.filter_active);
-        assert!(app.filter.is_empty());
-
-        // Type filter text
-        app.handle_input(key(KeyCode::Char('p')));
-        app.handle_input(key(KeyCode::Char('o')));
-        app.handle_input(key(KeyCode::Char('d')));
-        assert_eq!(app.filter, "pod");
+        app.wrap_navigation = false;
+        app.handle_input(key(KeyCode::Char('k')));
+        assert_eq!(app.table_state.selected(), Some(0));
+        app.table_state.select(Some(2));
+        app.handle_input(key(KeyCode::Down));
+        assert_eq!(app.table_state.selected(), Some(2));
 
-        // Backspace
-        app.handle_input(key(KeyCode::Backspace));
-        assert_eq!(app.filter, "po");
+        // Dropdowns clamp too
+        app.focus = Focus::ContextSelector;
+        app.handle_input(key(KeyCode::Up));
+        assert!(app.dropdown_visible);
+        assert_eq!(app.dropdown_selected, 0);
 
-        // Apply filter with enter
-        app.handle_input(key(KeyCode::Enter));
-        assert!(!app.filter_active);
-        assert_eq!(app.filter, "po");
+        let config = crate::config::parse("wrap_navigation: false\n").unwrap();
+        assert!(!config.wrap_navigation);
+        assert!(crate::config::parse("").unwrap().wrap_navigation);
     }
 
     #[test]
-    fn test_filter_esc_cancels() {
-        let mut app = app_with_pods();
-        app.handle_input(key(KeyCode::Char('/')));
-        app.handle_input(key(KeyCode::Char('x')));
-        app.handle_input(key(KeyCode::Esc));
-        assert!(!app.filter_active);
+    fn test_load_timeout_reaches_log_streams() {
+        let config = crate::config::parse("load_timeout_secs: 5\n").unwrap();
+        assert_eq!(config.load_timeout_secs, 5);
+        assert_eq!(crate::config::parse("").unwrap().load_timeout_secs, 30);
+        let config = crate::config::parse("search_concurrency: 12\n").unwrap();
+        assert_eq!(config.search_concurrency, 12);
+        assert_eq!(crate::config::parse("").unwrap().search_concurrency, 5);
+
+        let mut app = App::new();
+        assert_eq!(
+            app.log_options().open_timeout,
+            std::time::Duration::from_secs(30)
+        );
+        app.load_timeout = std::time::Duration::from_secs(5);
+        assert_eq!(
+            app.log_options().open_timeout,
+            std::time::Duration::from_secs(5)
+        );
     }
 
     #[test]
-    fn test_filtered_resources() {
-        let mut app = app_with_pods();
-        assert_eq!(app.filtered_resources().len(), 3);
+    fn test_edit_target_mismatch() {
+        use crate::types::PendingEdit;
+
+        let original = "metadata:\n  name: web\n  namespace: prod\n";
+        let check = |rt: ResourceType, yaml: &str| {
+            PendingEdit::new(
+                "prod".to_string(),
+                "web".to_string(),
+                rt,
+                original,
+                yaml.to_string(),
+            )
+            .target_mismatch()
+        };
 
-        app.filter = "pod-0".to_string();
-        assert_eq!(app.filtered_resources().len(), 1);
-        assert_eq!(app.filtered_resources()[0].name, "pod-0");
+        assert_eq!(
+            check(ResourceType::Deployments, "metadata:\n  name: web\n"),
+            None
+        );
+        assert_eq!(
+            check(
+                ResourceType::Deployments,
+                "metadata:\n  name: web2\n  namespace: prod\n"
+            ),
+            Some("metadata.name changed from 'web' to 'web2'".to_string())
+        );
+        assert_eq!(
+            check(
+                ResourceType::Deployments,
+                "metadata:\n  name: web\n  namespace: dev\n"
+            ),
+            Some("metadata.namespace changed from 'prod' to 'dev'".to_string())
+        );
+        assert!(check(ResourceType::Deployments, "spec: {}\n").is_some());
+        assert!(check(ResourceType::Deployments, "metadata: [\n")
+            .unwrap()
+            .contains("invalid YAML"));
+        // Cluster-scoped objects have no namespace to change
+        assert_eq!(
+            check(
+                ResourceType::Nodes,
+                "metadata:\n  name: web\n  namespace: dev\n"
+            ),
+            None
+        );
+    }
 
-        app.filter = "nonexistent".to_string();
-        assert_eq!(app.filtered_resources().len(), 0);
+    #[test]
+    fn test_create_from_template() {
+        use crate::k8s::templates::template;
+        use crate::types::PendingEdit;
+
+        for rt in ResourceType::ALL
+            .iter()
+            .copied()
+            .filter(ResourceType::supports_create)
+        {
+            let yaml = template(rt, "team-a").unwrap();
+            let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+            let kind = doc["kind"].as_str().unwrap_or_default();
+            assert_eq!(ResourceType::from_kind(kind), Some(rt), "{}", yaml);
+
+            let create = PendingEdit::create("default".to_string(), rt, yaml).unwrap();
+            assert!(create.target().is_none());
+            assert_eq!(create.target_mismatch(), None);
+            assert!(!create.name.is_empty());
+            let namespace = if rt.is_cluster_scoped() { "" } else { "team-a" };
+            assert_eq!(create.namespace, namespace, "Taken from the YAML");
+        }
+        let pod = template(ResourceType::Pods, "team-a").unwrap();
+        let pod: k8s_openapi::api::core::v1::Pod = serde_yaml::from_str(&pod).unwrap();
+        assert_eq!(pod.metadata.namespace.as_deref(), Some("team-a"));
+        assert!(template(ResourceType::Nodes, "team-a").is_none());
+
+        // Without a namespace in the YAML the current one is used
+        let create = PendingEdit::create(
+            "prod".to_string(),
+            ResourceType::ConfigMaps,
+            "kind: ConfigMap\nmetadata:\n  name: web\n".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            (create.namespace.as_str(), create.name.as_str()),
+            ("prod", "web")
+        );
+        let added = vec![
+            "@@ -0,0 +1,3 @@",
+            "+kind: ConfigMap",
+            "+metadata:",
+            "+  name: web",
+        ];
+        assert_eq!(create.diff, added);
+        let nameless = "kind: ConfigMap\nmetadata: {}\n".to_string();
+        let create = PendingEdit::create("prod".to_string(), ResourceType::ConfigMaps, nameless);
+        assert_eq!(
+            create.unwrap().target_mismatch(),
+            Some("metadata.name is missing".to_string())
+        );
+        let broken = "kind: ConfigMap\nmetadata: [name: web\n".to_string();
+        assert!(PendingEdit::create("prod".to_string(), ResourceType::ConfigMaps, broken).is_err());
+
+        // Edits still replace the object they were opened for
+        let web = "web".to_string();
+        let edit = PendingEdit::new(
+            "prod".to_string(),
+            web,
+            ResourceType::Pods,
+            "",
+            String::new(),
+        );
+        assert_eq!(edit.target(), Some("web"));
     }
 
     #[test]
-    fn test_error_auto_dismiss() {
-        let mut app = App::new();
-        app.set_error("test error".to_string());
-        assert!(app.error_message.is_some());
+    fn test_create_action() {
+        use crate::types::Severity;
 
-        // Tick 20 times (should not dismiss yet)
-        for _ in 0..20 {
-            app.handle_tick();
-        }
-        assert!(app.error_message.is_some());
+        let mut app = app_with_pods();
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('n'))),
+            InputAction::Create
+        );
+
+        app.resource_type = ResourceType::Nodes;
+        assert_eq!(app.handle_input(key(KeyCode::Char('n'))), InputAction::None);
+        assert_eq!(
+            app.message_log.back().map(|(_, s, _)| *s),
+            Some(Severity::Warning)
+        );
+    }
 
-        // One more tick should dismiss
-        app.handle_tick();
-        assert!(app.error_message.is_none());
+    #[test]
+    fn test_unified_diff_hunks() {
+        use crate::types::unified_diff;
+
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+        assert_eq!(
+            unified_diff(old, new, 1),
+            vec![
+                "@@ -1,3 +1,3 @@",
+                " a",
+                "-b",
+                "+B",
+                " c",
+                "@@ -10,1 +10,2 @@",
+                " j",
+                "+k"
+            ]
+        );
+        // Changes closer than twice the context share a hunk
+        assert_eq!(
+            unified_diff(old, new, 4)
+                .iter()
+                .filter(|l| l.starts_with("@@"))
+                .count(),
+            1
+        );
+        assert!(unified_diff(old, old, 3).is_empty());
+        assert_eq!(unified_diff("", "x\n", 3), vec!["@@ -0,0 +1,1 @@", "+x"]);
+
+        // A rewrite too big to align is removed and re-added whole
+        let lines = |c: char| {
+            (0..2100)
+                .map(|i| format!("{}{}\n", c, i))
+                .collect::<String>()
+        };
+        let (old, new) = (
+            format!("a\n{}z\n", lines('x')),
+            format!("a\n{}z\n", lines('y')),
+        );
+        let diff = unified_diff(&old, &new, 1);
+        assert_eq!(diff[0], "@@ -1,2102 +1,2102 @@");
+        assert!(diff[2..2102].iter().all(|l| l.starts_with("-x")));
+        assert!(diff[2102..4202].iter().all(|l| l.starts_with("+y")));
     }
 
     #[test]
-    fn test_resource_type_all_variants() {
-        assert_eq!(ResourceType::ALL.len(), 25);
-        assert_eq!(ResourceType::ALL[0], ResourceType::Pods);
-        assert_eq!(ResourceType::ALL[1], ResourceType::Deployments);
-        assert_eq!(ResourceType::ALL[2], ResourceType::StatefulSets);
+    fn test_edit_dry_run() {
+        use crate::types::{DryRun, PendingEdit};
+
+        let mut app = app_with_pods();
+        app.open_edit_preview(PendingEdit::new(
+            "default".to_string(),
+            "pod-0".to_string(),
+            ResourceType::Pods,
+            "spec:\n  replicas: 1\n",
+            "spec:\n  replicas: 2\n".to_string(),
+        ));
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('d'))),
+            InputAction::DryRunEdit
+        );
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::ApplyEdit));
+        assert_eq!(
+            app.pending_edit.as_ref().unwrap().dry_run,
+            Some(DryRun::Running)
+        );
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('d'))),
+            InputAction::None,
+            "Only one dry run runs at a time"
+        );
+
+        app.finish_edit_dry_run(Err("admission webhook denied the request".to_string()));
+        assert_eq!(
+            app.pending_edit.as_ref().unwrap().dry_run,
+            Some(DryRun::Failed(
+                "admission webhook denied the request".to_string()
+            ))
+        );
+
+        // A result arriving after the edit was discarded is dropped
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('d'))),
+            InputAction::DryRunEdit
+        );
+        app.handle_input(key(KeyCode::Esc));
+        app.finish_edit_dry_run(Ok(()));
+        assert!(app.pending_edit.is_none());
     }
 
     #[test]
-    fn test_focus_cycling() {
-        assert_eq!(Focus::ResourceList.next(), Focus::ContextSelector);
-        assert_eq!(Focus::ContextSelector.next(), Focus::NamespaceSelector);
-        assert_eq!(Focus::NamespaceSelector.next(), Focus::ResourceTypeSelector);
-        assert_eq!(Focus::ResourceTypeSelector.next(), Focus::ResourceList);
+    fn test_edit_diff_confirm_flow() {
+        use crate::types::PendingEdit;
 
-        assert_eq!(Focus::ResourceList.prev(), Focus::ResourceTypeSelector);
-        assert_eq!(Focus::ContextSelector.prev(), Focus::ResourceList);
+        let mut app = app_with_pods();
+        let edit = |yaml: &str| {
+            PendingEdit::new(
+                "default".to_string(),
+                "pod-0".to_string(),
+                ResourceType::Pods,
+                "spec:\n  replicas: 1\n",
+                yaml.to_string(),
+            )
+        };
+
+        app.open_edit_preview(edit("spec:\n  replicas: 2\n"));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::ApplyEdit));
+        let pending = app.pending_edit.as_ref().unwrap();
+        assert_eq!(pending.counts(), (1, 1));
+        assert!(!pending.whitespace_only);
+
+        // Scrolling and stray keys neither apply nor cancel
+        assert_eq!(app.handle_input(key(KeyCode::Char('j'))), InputAction::None);
+        assert_eq!(app.edit_diff_scroll, 1);
+        app.handle_input(key(KeyCode::Char('x')));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::ApplyEdit));
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('y'))),
+            InputAction::ApplyEdit
+        );
+        assert_eq!(app.view_mode, ViewMode::List);
+        let applied = app.take_pending_edit().unwrap();
+        assert_eq!(applied.yaml, "spec:\n  replicas: 2\n");
+
+        app.open_edit_preview(edit("spec:\n    replicas: 1\n"));
+        assert!(app.pending_edit.as_ref().unwrap().whitespace_only);
+        assert_eq!(app.handle_input(key(KeyCode::Esc)), InputAction::None);
+        assert_eq!(app.view_mode, ViewMode::List);
+        assert!(
+            app.take_pending_edit().is_none(),
+            "Discarded edits are dropped"
+        );
     }
 
     #[test]
-    fn test_resource_item_columns_pods() {
-        let item = fake_pod("my-pod", "Running");
-        let cols = item.columns(ResourceType::Pods);
-        assert_eq!(cols[0], "my-pod");
-        assert_eq!(cols[1], "Running");
-        assert_eq!(cols[2], "1h");
-        assert_eq!(cols[3], "0");
-        assert_eq!(cols[4], "node-a");
+    fn test_command_palette_runs_actions() {
+        use crate::types::PALETTE_ENTRIES;
+
+        let mut app = app_with_pods();
+        app.handle_input(key_with_mod(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        let palette = app.palette.as_ref().unwrap();
+        assert_eq!(
+            palette.filtered.len(),
+            PALETTE_ENTRIES.len() - 1,
+            "Everything but scaling applies to pods"
+        );
+
+        type_str(&mut app, "restrt");
+        let palette = app.palette.as_ref().unwrap();
+        assert_eq!(PALETTE_ENTRIES[palette.filtered[0]].label, "Restart");
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::None);
+        assert!(app.palette.is_none());
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Restart));
+        app.handle_input(key(KeyCode::Esc));
+
+        // Entries dispatch whatever the list key returns
+        app.handle_input(key_with_mod(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        type_str(&mut app, "describe");
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::Describe);
+        assert_eq!(app.view_mode, ViewMode::Detail);
+
+        // Selector entries move focus and open the dropdown
+        app.view_mode = ViewMode::List;
+        app.handle_input(key_with_mod(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        type_str(&mut app, "switch namespace");
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(app.focus, Focus::NamespaceSelector);
+        assert!(app.dropdown_visible);
+
+        // Entries for unsupported actions are hidden; Esc closes
+        app.focus = Focus::ResourceList;
+        app.dropdown_visible = false;
+        app.resource_type = ResourceType::ConfigMaps;
+        app.handle_input(key_with_mod(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        let palette = app.palette.as_ref().unwrap();
+        let labels: Vec<&str> = palette
+            .filtered
+            .iter()
+            .map(|&i| PALETTE_ENTRIES[i].label)
+            .collect();
+        assert!(!labels.contains(&"Scale replicas"));
+        assert!(!labels.contains(&"View logs"));
+        type_str(&mut app, "zzz");
+        assert!(app.palette.as_ref().unwrap().filtered.is_empty());
+        app.handle_input(key(KeyCode::Esc));
+        assert!(app.palette.is_none());
+        assert!(!app.should_quit);
     }
 
     #[test]
-    fn test_resource_item_columns_pvcs() {
-        let item = ResourceItem {
-            name: "my-pvc".to_string(),
-            namespace: "default".to_string(),
-            status: "Bound".to_string(),
-            age: "2d".to_string(),
-            extra: vec![
-                ("volume".to_string(), "pv-001".to_string()),
-                ("capacity".to_string(), "10Gi".to_string()),
-            ],
-            raw_yaml: String::new(),
-        };
-        let cols = item.columns(ResourceType::PersistentVolumeClaims);
-        assert_eq!(cols[0], "my-pvc");
-        assert_eq!(cols[1], "Bound");
-        assert_eq!(cols[2], "pv-001");
-        assert_eq!(cols[3], "10Gi");
-        assert_eq!(cols[4], "2d");
+    fn test_palette_keeps_ctrl_keys_while_open() {
+        let mut app = app_with_pods();
+        let ctrl = |c: char| key_with_mod(KeyCode::Char(c), KeyModifiers::CONTROL);
+        app.handle_input(ctrl('p'));
+        type_str(&mut app, "log");
+
+        // Ctrl+F and Ctrl+G don't start a search behind the palette
+        assert_eq!(app.handle_input(ctrl('f')), InputAction::None);
+        assert_eq!(app.handle_input(ctrl('g')), InputAction::None);
+        assert_eq!(app.view_mode, ViewMode::List);
+        assert_eq!(
+            app.palette.as_ref().unwrap().query,
+            "log",
+            "Nor type into the query"
+        );
+
+        // Ctrl+P closes it again
+        app.handle_input(ctrl('p'));
+        assert!(app.palette.is_none());
     }
 
     #[test]
-    fn test_resource_item_columns_statefulsets() {
-        let item = ResourceItem {
-            name: "my-ss".to_string(),
-            namespace: "default".to_string(),
-            status: "Active".to_string(),
-            age: "5d".to_string(),
-            extra: vec![("ready".to_string(), "3/3".to_string())],
-            raw_yaml: String::new(),
-        };
-        let cols = item.columns(ResourceType::StatefulSets);
-        assert_eq!(cols[0], "my-ss");
-        assert_eq!(cols[1], "3/3");
-        assert_eq!(cols[2], "5d");
+    fn test_batch_delete_marked_rows() {
+        let mut app = app_with_pods();
+        app.table_state.select(Some(0));
+
+        // Space marks and moves down; marking twice unmarks
+        app.handle_input(key(KeyCode::Char(' ')));
+        assert_eq!(app.table_state.selected(), Some(1));
+        app.handle_input(key(KeyCode::Down));
+        app.handle_input(key(KeyCode::Char(' ')));
+        app.table_state.select(Some(1));
+        app.handle_input(key(KeyCode::Char(' ')));
+        app.table_state.select(Some(1));
+        app.handle_input(key(KeyCode::Char(' ')));
+        let names: Vec<&str> = app
+            .marked_resources()
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["pod-0", "pod-2"]);
+
+        app.handle_input(key(KeyCode::Char('d')));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Delete));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('y'))),
+            InputAction::Delete
+        );
+        assert_eq!(
+            app.take_delete_targets(),
+            vec![
+                ("default".to_string(), "pod-0".to_string()),
+                ("default".to_string(), "pod-2".to_string()),
+            ]
+        );
+        assert!(app.marked.is_empty());
     }
 
     #[test]
-    fn test_detail_view_actions() {
+    fn test_filtering_drops_marks_on_hidden_rows() {
         let mut app = app_with_pods();
-        app.view_mode = ViewMode::Detail;
+        app.table_state.select(Some(0));
+        app.handle_input(key(KeyCode::Char(' ')));
+        app.handle_input(key(KeyCode::Char(' ')));
+        assert_eq!(app.marked.len(), 2);
+
+        // pod-0 is filtered out, so a delete now can't take it along unseen
+        app.filter = "pod-1".to_string();
+        app.update_filter();
+        let names: Vec<&str> = app
+            .marked_resources()
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["pod-1"]);
+        app.filter.clear();
+        app.update_filter();
+        assert_eq!(
+            app.marked.len(),
+            1,
+            "Clearing the filter doesn't bring it back"
+        );
+
+        // Rows a watch update removes lose their marks too
+        app.set_resources(vec![fake_pod("pod-0", "Running")]);
+        assert!(app.marked.is_empty());
+    }
 
-        // Delete from detail
+    #[test]
+    fn test_marks_clear_when_leaving_list() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Char(' ')));
+        assert_eq!(app.marked.len(), 1);
+
+        // Cancelling the confirmation keeps the marks
         app.handle_input(key(KeyCode::Char('d')));
+        app.handle_input(key(KeyCode::Char('n')));
+        assert_eq!(app.marked.len(), 1);
+
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(app.view_mode, ViewMode::Detail);
+        assert!(app.marked.is_empty());
+    }
+
+    #[test]
+    fn test_delete_statefulset_requests_preview() {
+        let mut app = app_with_pods();
+        app.resource_type = ResourceType::StatefulSets;
+        app.delete_preview = Some(vec!["stale".to_string()]);
+
+        let action = app.handle_input(key(KeyCode::Char('d')));
+        assert_eq!(action, InputAction::PreviewDelete);
         assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Delete));
-        app.view_mode = ViewMode::Detail;
+        assert!(
+            app.delete_preview.is_none(),
+            "Old preview should be cleared"
+        );
 
-        // Restart from detail
-        app.handle_input(key(KeyCode::Char('r')));
-        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Restart));
-        app.view_mode = ViewMode::Detail;
+        let action = app.handle_input(key(KeyCode::Char('y')));
+        assert_eq!(action, InputAction::Delete);
+    }
+
+    fn multi_container_pod() -> ResourceItem {
+        let mut pod = fake_pod("pod-multi", "Running");
+        pod.spec.containers = vec!["app".to_string(), "istio-proxy".to_string()];
+        pod
+    }
+
+    #[test]
+    fn test_logs_single_container_skips_picker() {
+        let mut app = app_with_pods();
+        app.resources[0].spec.containers = vec!["only".to_string()];
 
-        // Logs from detail
         let action = app.handle_input(key(KeyCode::Char('l')));
         assert_eq!(action, InputAction::StreamLogs);
-        assert_eq!(app.view_mode, ViewMode::Logs);
+        assert!(app.container_choices.is_empty());
+        assert_eq!(app.log_container.as_deref(), Some("only"));
     }
 
     #[test]
-    fn test_navigate_empty_list() {
-        let mut app = App::new();
-        app.focus = Focus::ResourceList;
-        // Should not panic on empty list
+    fn test_logs_multi_container_picker_flow() {
+        let mut app = app_with_pods();
+        app.set_resources(vec![multi_container_pod()]);
+
+        let action = app.handle_input(key(KeyCode::Char('l')));
+        assert_eq!(
+            action,
+            InputAction::None,
+            "Picker should open before streaming"
+        );
+        assert_eq!(app.view_mode, ViewMode::Logs);
+        assert_eq!(app.container_choices, vec!["app", "istio-proxy"]);
+
         app.handle_input(key(KeyCode::Char('j')));
-        app.handle_input(key(KeyCode::Char('k')));
-        assert_eq!(app.table_state.selected(), Some(0));
+        let action = app.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, InputAction::StreamLogs);
+        assert_eq!(app.log_container.as_deref(), Some("istio-proxy"));
+        assert!(app.container_choices.is_empty());
     }
 
     #[test]
-    fn test_q_from_detail_goes_back() {
+    fn test_logs_container_picker_esc_returns_to_list() {
         let mut app = app_with_pods();
-        app.view_mode = ViewMode::Detail;
-        app.handle_input(key(KeyCode::Char('q')));
+        app.set_resources(vec![multi_container_pod()]);
+
+        app.handle_input(key(KeyCode::Char('l')));
+        let action = app.handle_input(key(KeyCode::Esc));
+        assert_eq!(action, InputAction::None);
         assert_eq!(app.view_mode, ViewMode::List);
-        assert!(!app.should_quit);
+        assert!(app.container_choices.is_empty());
     }
 
-    // --- Fuzzy Search Tests ---
+    #[test]
+    fn test_delete_cancel_flow() {
+        let mut app = app_with_pods();
 
-    use crate::types::{fuzzy_match, SearchResult};
+        app.handle_input(key(KeyCode::Char('d')));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Delete));
 
-    fn fake_search_result(name: &str, ns: &str, ctx: &str, rt: ResourceType) -> SearchResult {
-        SearchResult {
-            resource: ResourceItem {
-                name: name.to_string(),
-                namespace: ns.to_string(),
-                status: "Running".to_string(),
-                age: "1h".to_string(),
-                extra: vec![
-                    ("restarts".to_string(), "0".to_string()),
-                    ("node".to_string(), "node-a".to_string()),
-                ],
-                raw_yaml: String::new(),
-            },
-            context: ctx.to_string(),
-            resource_type: rt,
-        }
+        // Press n -> cancel
+        let action = app.handle_input(key(KeyCode::Char('n')));
+        assert_eq!(action, InputAction::None);
+        assert_eq!(app.view_mode, ViewMode::List);
     }
 
-    fn app_with_search_results() -> App {
-        let mut app = App::new();
-        app.contexts = vec!["gke-prod".to_string(), "gke-staging".to_string()];
-        app.view_mode = ViewMode::Search;
-        app.search_results = vec![
-            fake_search_result("op-geth-node-0", "ethereum", "gke-prod", ResourceType::Pods),
-            fake_search_result("op-geth-node-1", "ethereum", "gke-prod", ResourceType::Pods),
-            fake_search_result("op-geth-node-0", "ethereum", "gke-staging", ResourceType::Pods),
-            fake_search_result("redis-master-0", "cache", "gke-prod", ResourceType::Pods),
-            fake_search_result("nginx-ingress", "default", "gke-prod", ResourceType::StatefulSets),
-        ];
-        app.update_search_filter();
-        app
+    #[test]
+    fn test_restart_confirm_flow() {
+        let mut app = app_with_pods();
+
+        app.handle_input(key(KeyCode::Char('r')));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Restart));
+
+        let action = app.handle_input(key(KeyCode::Char('y')));
+        assert_eq!(action, InputAction::Restart);
+        assert_eq!(app.view_mode, ViewMode::List);
     }
 
     #[test]
-    fn test_ctrl_f_enters_search_mode() {
+    fn test_edit_action() {
         let mut app = app_with_pods();
-        app.contexts = vec!["ctx-1".to_string()];
-        let action = app.handle_input(key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL));
-        assert_eq!(action, InputAction::StartSearch);
-        assert_eq!(app.view_mode, ViewMode::Search);
-        assert!(app.search_query.is_empty());
-        assert!(app.search_loading);
+        let action = app.handle_input(key(KeyCode::Char('e')));
+        assert_eq!(action, InputAction::Edit);
+    }
+
+    #[test]
+    fn test_filter_mode() {
+        let mut app = app_with_pods();
+
+        // Enter filter mode
+        app.handle_input(key(KeyCode::Char('/')));
+        assert!(app.filter_active);
+        assert!(app.filter.is_empty());
+
+        // Type filter text
+        app.handle_input(key(KeyCode::Char('p')));
+        app.handle_input(key(KeyCode::Char('o')));
+        app.handle_input(key(KeyCode::Char('d')));
+        assert_eq!(app.filter, "pod");
+
+        // Backspace
+        app.handle_input(key(KeyCode::Backspace));
+        assert_eq!(app.filter, "po");
+
+        // Apply filter with enter
+        app.handle_input(key(KeyCode::Enter));
+        assert!(!app.filter_active);
+        assert_eq!(app.filter, "po");
+    }
+
+    #[test]
+    fn test_filter_esc_cancels() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Char('/')));
+        app.handle_input(key(KeyCode::Char('x')));
+        app.handle_input(key(KeyCode::Esc));
+        assert!(!app.filter_active);
+    }
+
+    #[test]
+    fn test_filtered_resources() {
+        let mut app = app_with_pods();
+        assert_eq!(app.filtered_resources().len(), 3);
+
+        app.filter = "pod-0".to_string();
+        app.update_filter();
+        assert_eq!(app.filtered_resources().len(), 1);
+        assert_eq!(app.filtered_resources()[0].name, "pod-0");
+
+        app.filter = "nonexistent".to_string();
+        app.update_filter();
+        assert_eq!(app.filtered_resources().len(), 0);
+    }
+
+    #[test]
+    fn test_filtered_rows_follow_resource_updates() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Char('/')));
+        for c in "pod-1".chars() {
+            app.handle_input(key(KeyCode::Char(c)));
+        }
+        assert_eq!(names(&app), ["pod-1"]);
+
+        // A watch update re-applies the filter to the new list
+        app.set_resources(vec![
+            fake_pod("pod-9", "Running"),
+            fake_pod("pod-1", "Running"),
+        ]);
+        assert_eq!(names(&app), ["pod-1"]);
+        assert_eq!(app.selected_resource().unwrap().name, "pod-1");
+    }
+
+    #[test]
+    fn test_filter_typing_on_large_list_applies_on_tick() {
+        let mut app = App::new();
+        app.focus = Focus::ResourceList;
+        app.set_resources(
+            (0..2000)
+                .map(|i| fake_pod(&format!("pod-{}", i), "Running"))
+                .collect(),
+        );
+        app.handle_input(key(KeyCode::Char('/')));
+        for c in "pod-1999".chars() {
+            app.handle_input(key(KeyCode::Char(c)));
+        }
+        assert!(app.filter_dirty);
+        assert_eq!(app.filtered_len(), 2000, "Not re-filtered per keystroke");
+
+        app.handle_tick();
+        assert!(!app.filter_dirty);
+        assert_eq!(names(&app), ["pod-1999"]);
+
+        // Leaving filter mode applies straight away
+        app.handle_input(key(KeyCode::Backspace));
+        app.handle_input(key(KeyCode::Enter));
+        assert!(!app.filter_dirty);
+        assert_eq!(app.filtered_len(), 11, "pod-199 and pod-1990..pod-1999");
+    }
+
+    #[test]
+    fn test_filter_by_status_term() {
+        let mut app = app_with_pods();
+        app.filter = "status:pending".to_string();
+        app.update_filter();
+        let names: Vec<&str> = app
+            .filtered_resources()
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["pod-1"]);
+
+        // Name and status terms combine
+        app.filter = "pod-2 status:run".to_string();
+        app.update_filter();
+        let names: Vec<&str> = app
+            .filtered_resources()
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["pod-2"]);
+    }
+
+    #[test]
+    fn test_problems_only_toggle_hides_healthy() {
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Char('!')));
+        assert!(app.problems_only);
+        let names: Vec<&str> = app
+            .filtered_resources()
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["pod-1"]);
+
+        app.handle_input(key(KeyCode::Char('!')));
+        assert_eq!(app.filtered_resources().len(), 3);
+    }
+
+    #[test]
+    fn test_list_summary_counts_not_ready() {
+        let mut app = app_with_pods();
+        assert_eq!(app.list_summary(), (3, Some(1)));
+
+        // Follows the active filter
+        app.filter = "pod-0".to_string();
+        app.update_filter();
+        assert_eq!(app.list_summary(), (1, Some(0)));
+
+        app.filter.clear();
+        app.update_filter();
+        app.resource_type = ResourceType::StatefulSets;
+        app.resources[0].extra = vec![("ready".to_string(), "1/3".to_string())];
+        app.resources[1].extra = vec![("ready".to_string(), "3/3".to_string())];
+        app.resources[2].extra = vec![("ready".to_string(), "0/0".to_string())];
+        assert_eq!(app.list_summary(), (3, Some(1)));
+
+        app.resource_type = ResourceType::ConfigMaps;
+        assert_eq!(app.list_summary(), (3, None), "No readiness for ConfigMaps");
+    }
+
+    #[test]
+    fn test_error_auto_dismiss() {
+        let mut app = App::new();
+        app.set_error("test error".to_string());
+        assert!(app.status_message.is_some());
+
+        // Tick 20 times (should not dismiss yet)
+        for _ in 0..20 {
+            app.handle_tick();
+        }
+        assert!(app.status_message.is_some());
+
+        // One more tick should dismiss
+        app.handle_tick();
+        assert!(app.status_message.is_none());
+    }
+
+    #[test]
+    fn test_info_messages_dismiss_sooner() {
+        use crate::types::Severity;
+
+        let mut app = App::new();
+        app.set_info("Copied pod-0 to clipboard".to_string());
+        assert_eq!(
+            app.status_message.as_ref().map(|(s, _)| *s),
+            Some(Severity::Info)
+        );
+        for _ in 0..13 {
+            app.handle_tick();
+        }
+        assert!(app.status_message.is_none());
+
+        app.set_warning("No label selector given".to_string());
+        for _ in 0..13 {
+            app.handle_tick();
+        }
+        assert_eq!(app.status_text(), Some("No label selector given"));
+        assert_eq!(
+            app.message_log.back().map(|(_, s, _)| *s),
+            Some(Severity::Warning)
+        );
+    }
+
+    #[test]
+    fn test_error_history_outlives_footer() {
+        let mut app = app_with_pods();
+        app.set_error("first".to_string());
+        app.set_error("second".to_string());
+        for _ in 0..21 {
+            app.handle_tick();
+        }
+        assert!(app.status_message.is_none());
+        let messages: Vec<&str> = app.message_log.iter().map(|(_, _, m)| m.as_str()).collect();
+        assert_eq!(messages, vec!["first", "second"]);
+
+        app.handle_input(key(KeyCode::Char('M')));
+        assert!(app.show_message_log);
+        // Keys scroll the history instead of reaching the list
+        app.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(app.message_log_scroll, 1);
+        assert_eq!(app.table_state.selected(), Some(0));
+        app.handle_input(key(KeyCode::Esc));
+        assert!(!app.show_message_log);
+
+        for i in 0..250 {
+            app.set_error(format!("error {}", i));
+        }
+        assert_eq!(app.message_log.len(), 200, "Oldest messages are dropped");
+        assert_eq!(
+            app.message_log.front().map(|(_, _, m)| m.as_str()),
+            Some("error 50")
+        );
+    }
+
+    #[test]
+    fn test_loading_indicator_advances_on_tick() {
+        let mut app = App::new();
+        assert_eq!(app.loading_indicator(), None);
+
+        app.loading = true;
+        assert_eq!(app.loading_indicator().as_deref(), Some("⠋ 0s"));
+        app.handle_tick();
+        assert_eq!(app.loading_indicator().as_deref(), Some("⠙ 0s"));
+        for _ in 0..9 {
+            app.handle_tick();
+        }
+        assert_eq!(app.loading_indicator().as_deref(), Some("⠋ 2s"));
+
+        // Finishing resets the clock for the next load
+        app.loading = false;
+        app.handle_tick();
+        assert_eq!(app.loading_ticks, 0);
+        assert_eq!(app.loading_indicator(), None);
+    }
+
+    #[test]
+    fn test_context_probes_are_throttled() {
+        let mut app = App::new();
+        app.contexts = vec!["prod".to_string(), "dev".to_string()];
+        assert_eq!(app.context_health("prod"), ContextHealth::Unknown);
+
+        assert_eq!(app.contexts_to_probe(true), vec!["prod", "dev"]);
+        assert!(
+            app.contexts_to_probe(true).is_empty(),
+            "In flight, not sent again"
+        );
+        app.set_context_health("prod".to_string(), ContextHealth::Reachable);
+        app.set_context_health("dev".to_string(), ContextHealth::Unreachable);
+        assert_eq!(app.context_health("dev"), ContextHealth::Unreachable);
+
+        // Re-probed only once stale, and only while the dropdown is open
+        for _ in 0..240 {
+            app.handle_tick();
+        }
+        app.focus = Focus::ResourceList;
+        assert!(app.contexts_to_probe(false).is_empty());
+        app.focus = Focus::ContextSelector;
+        app.dropdown_visible = true;
+        assert_eq!(app.contexts_to_probe(false), vec!["prod", "dev"]);
+        assert_eq!(
+            app.context_health("prod"),
+            ContextHealth::Reachable,
+            "The last result stays shown while re-probing"
+        );
+    }
+
+    #[test]
+    fn test_resource_type_all_variants() {
+        assert_eq!(ResourceType::ALL.len(), 25);
+        assert_eq!(ResourceType::ALL[0], ResourceType::Pods);
+        assert_eq!(ResourceType::ALL[1], ResourceType::Deployments);
+        assert_eq!(ResourceType::ALL[2], ResourceType::StatefulSets);
+    }
+
+    #[test]
+    fn test_focus_cycling() {
+        assert_eq!(Focus::ResourceList.next(), Focus::ContextSelector);
+        assert_eq!(Focus::ContextSelector.next(), Focus::NamespaceSelector);
+        assert_eq!(Focus::NamespaceSelector.next(), Focus::ResourceTypeSelector);
+        assert_eq!(Focus::ResourceTypeSelector.next(), Focus::ResourceList);
+
+        assert_eq!(Focus::ResourceList.prev(), Focus::ResourceTypeSelector);
+        assert_eq!(Focus::ContextSelector.prev(), Focus::ResourceList);
+    }
+
+    #[test]
+    fn test_resource_item_columns_pods() {
+        let item = fake_pod("my-pod", "Running");
+        let cols = item.columns(&ResourceType::Pods.column_headers(ColumnLayout::Regular));
+        assert_eq!(cols[0], "my-pod");
+        assert_eq!(cols[1], "<none>", "No READY value in the fixture");
+        assert_eq!(cols[2], "Running");
+        assert_eq!(cols[3], "1h0m");
+        assert_eq!(cols[4], "0");
+        assert_eq!(cols[5], "node-a");
+    }
+
+    #[test]
+    fn test_resource_item_columns_pvcs() {
+        let item = ResourceItem {
+            name: "my-pvc".to_string(),
+            namespace: "default".to_string(),
+            status: "Bound".to_string(),
+            created: ago(172800),
+            extra: vec![
+                ("volume".to_string(), "pv-001".to_string()),
+                ("capacity".to_string(), "10Gi".to_string()),
+            ],
+            spec: SpecSummary::default(),
+        };
+        let headers = ResourceType::PersistentVolumeClaims.column_headers(ColumnLayout::Regular);
+        let cols = item.columns(&headers);
+        assert_eq!(cols[0], "my-pvc");
+        assert_eq!(cols[1], "Bound");
+        assert_eq!(cols[2], "pv-001");
+        assert_eq!(cols[3], "10Gi");
+        assert_eq!(cols[4], "2d0h");
+    }
+
+    #[test]
+    fn test_resource_item_columns_statefulsets() {
+        let item = ResourceItem {
+            name: "my-ss".to_string(),
+            namespace: "default".to_string(),
+            status: "Active".to_string(),
+            created: ago(432000),
+            extra: vec![("ready".to_string(), "3/3".to_string())],
+            spec: SpecSummary::default(),
+        };
+        let cols = item.columns(&ResourceType::StatefulSets.column_headers(ColumnLayout::Regular));
+        assert_eq!(cols[0], "my-ss");
+        assert_eq!(cols[1], "3/3");
+        assert_eq!(cols[2], "5d0h");
+    }
+
+    #[test]
+    fn test_resource_item_columns_ingresses() {
+        let item = ResourceItem {
+            name: "web".to_string(),
+            namespace: "default".to_string(),
+            status: String::new(),
+            created: ago(3600),
+            extra: vec![
+                ("class".to_string(), "nginx".to_string()),
+                ("hosts".to_string(), "shop.example.com".to_string()),
+                ("address".to_string(), "203.0.113.7".to_string()),
+            ],
+            spec: SpecSummary::default(),
+        };
+        let cols = item.columns(&ResourceType::Ingresses.column_headers(ColumnLayout::Regular));
+        assert_eq!(
+            cols,
+            vec!["web", "nginx", "shop.example.com", "203.0.113.7", "1h0m"]
+        );
+        assert!(!ResourceType::Ingresses.supports_logs());
+        assert!(!ResourceType::Ingresses.supports_restart());
+    }
+
+    #[test]
+    fn test_events_list_newest_first() {
+        let mut app = App::new();
+        app.resource_type = ResourceType::Events;
+        app.set_resources(vec![
+            fake_event("web-0.a", "Normal", "BackOff", 600),
+            fake_event("web-0.b", "Warning", "BackOff", 5),
+            fake_event("web-0.c", "Normal", "BackOff", 60),
+        ]);
+        app.update_filter();
+        let names: Vec<&str> = app
+            .filtered_resources()
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["web-0.b", "web-0.c", "web-0.a"]);
+
+        let headers = ResourceType::Events.column_headers(ColumnLayout::Regular);
+        assert_eq!(
+            app.filtered_resources()[0].columns(&headers),
+            vec![
+                "Warning",
+                "BackOff",
+                "pod/web-0",
+                "Back-off pulling image",
+                "5s"
+            ]
+        );
+
+        // A chosen sort still wins, and the problems toggle keeps warnings
+        app.cycle_sort();
+        app.update_filter();
+        assert_eq!(app.filtered_resources()[0].name, "web-0.a");
+        app.problems_only = true;
+        app.update_filter();
+        assert_eq!(app.filtered_resources().len(), 1);
+        assert_eq!(app.filtered_resources()[0].name, "web-0.b");
+    }
+
+    #[test]
+    fn test_leaving_detail_and_logs_restores_list_scroll() {
+        let mut app = App::new();
+        app.focus = Focus::ResourceList;
+        app.set_resources(
+            (0..60)
+                .map(|i| fake_pod(&format!("pod-{}", i), "Running"))
+                .collect(),
+        );
+        app.update_filter();
+        app.table_state.select(Some(40));
+        *app.table_state.offset_mut() = 30;
+
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(app.view_mode, ViewMode::Detail);
+        *app.table_state.offset_mut() = 0;
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.table_state.offset(), 30);
+        assert_eq!(app.table_state.selected(), Some(40));
+
+        app.handle_input(key(KeyCode::Char('l')));
+        assert_eq!(app.view_mode, ViewMode::Logs);
+        *app.table_state.offset_mut() = 0;
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.table_state.offset(), 30);
+
+        // Nothing saved, nothing restored
+        app.view_mode = ViewMode::Detail;
+        *app.table_state.offset_mut() = 12;
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.table_state.offset(), 12);
+
+        // A delete asked for from the detail view lands on the same rows
+        app.handle_input(key(KeyCode::Enter));
+        *app.table_state.offset_mut() = 0;
+        app.handle_input(key(KeyCode::Char('d')));
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.view_mode, ViewMode::List);
+        assert_eq!(app.table_state.offset(), 12);
+        assert_eq!(app.list_offset, None);
+    }
+
+    #[test]
+    fn test_jumping_away_from_detail_drops_the_saved_scroll() {
+        let mut app = App::new();
+        app.focus = Focus::ResourceList;
+        app.set_resources(
+            (0..60)
+                .map(|i| fake_pod(&format!("pod-{}", i), "Running"))
+                .collect(),
+        );
+        app.update_filter();
+        app.table_state.select(Some(40));
+        *app.table_state.offset_mut() = 30;
+        app.resources[40].spec.owner = Some(("ReplicaSet".to_string(), "web-5d8f".to_string()));
+
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(app.list_offset, Some(30));
+        app.handle_input(key(KeyCode::Char('o')));
+        assert_eq!(app.resource_type, ResourceType::ReplicaSets);
+        assert_eq!(app.list_offset, None, "The owner's list is another list");
+    }
+
+    #[test]
+    fn test_pods_on_a_node() {
+        let mut app = App::new();
+        app.focus = Focus::ResourceList;
+        app.set_namespaces(vec!["default".to_string()]);
+        app.resource_type = ResourceType::Nodes;
+        app.set_resources(vec![fake_pod("node-b", "Ready")]);
+        app.update_filter();
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('P'))),
+            InputAction::NamespaceChanged
+        );
+        assert_eq!(app.pod_node.as_deref(), Some("node-b"));
+        assert_eq!(app.resource_type, ResourceType::Pods);
+        assert!(app.all_namespaces());
+        assert_eq!(
+            crate::types::node_pods_selector("node-b"),
+            "spec.nodeName=node-b"
+        );
+        assert_eq!(
+            app.watch_selector().fields,
+            Some(crate::types::node_pods_selector("node-b"))
+        );
+
+        // Esc lists every node's pods again
+        assert_eq!(
+            app.handle_input(key(KeyCode::Esc)),
+            InputAction::ResourceTypeChanged
+        );
+        assert_eq!(app.pod_node, None);
+        assert_eq!(app.handle_input(key(KeyCode::Esc)), InputAction::None);
+
+        // From a pod, its own node
+        app.set_resources(vec![fake_pod("pod-0", "Running")]);
+        app.update_filter();
+        app.handle_input(key(KeyCode::Char('P')));
+        assert_eq!(app.pod_node.as_deref(), Some("node-a"));
+
+        // Picking another namespace drops the node
+        app.focus = Focus::NamespaceSelector;
+        app.dropdown_open();
+        app.handle_input(key(KeyCode::Down));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::NamespaceChanged
+        );
+        assert_eq!(app.pod_node, None);
+
+        // A pod still pending has no node to go by
+        let mut pending = fake_pod("pod-1", "Pending");
+        pending.extra.retain(|(k, _)| k != "node");
+        app.focus = Focus::ResourceList;
+        app.set_resources(vec![pending]);
+        app.update_filter();
+        assert_eq!(app.handle_input(key(KeyCode::Char('P'))), InputAction::None);
+        assert_eq!(app.pod_node, None);
+    }
+
+    #[test]
+    fn test_filter_selectors_restart_the_watch() {
+        let mut app = App::new();
+        app.focus = Focus::ResourceList;
+        app.set_resources(vec![
+            fake_pod("web-0", "Running"),
+            fake_pod("db-0", "Running"),
+        ]);
+        app.update_filter();
+
+        // Typing doesn't restart the watch, leaving filter mode does
+        app.handle_input(key(KeyCode::Char('/')));
+        for c in "label:app=Web field:status.phase=Running web".chars() {
+            assert_eq!(app.handle_input(key(KeyCode::Char(c))), InputAction::None);
+        }
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::ResourceTypeChanged
+        );
+        let selector = app.watch_selector();
+        assert_eq!(selector.labels.as_deref(), Some("app=Web"));
+        assert_eq!(selector.fields.as_deref(), Some("status.phase=Running"));
+        // The selector terms don't match names
+        assert_eq!(app.filtered_resources().len(), 1);
+        assert_eq!(app.filtered_resources()[0].name, "web-0");
+
+        // The same selectors again leave the watch alone
+        app.filter_active = true;
+        app.handle_input(key(KeyCode::Backspace));
+        assert_eq!(app.handle_input(key(KeyCode::Esc)), InputAction::None);
+
+        // Several terms of a kind are ANDed, and the node's pods add theirs
+        app.filter = "label:app=web label:tier=front".to_string();
+        app.filter_active = true;
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::ResourceTypeChanged
+        );
+        app.pod_node = Some("node-a".to_string());
+        let selector = app.watch_selector();
+        assert_eq!(selector.labels.as_deref(), Some("app=web,tier=front"));
+        assert_eq!(selector.fields.as_deref(), Some("spec.nodeName=node-a"));
+
+        // Clearing the filter lists everything again
+        app.pod_node = None;
+        app.handle_input(key(KeyCode::Char('/')));
+        assert_eq!(
+            app.handle_input(key(KeyCode::Enter)),
+            InputAction::ResourceTypeChanged
+        );
+        assert!(app.watch_selector().is_empty());
+    }
+
+    #[test]
+    fn test_detail_view_actions() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Detail;
+
+        // Delete from detail
+        app.handle_input(key(KeyCode::Char('d')));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Delete));
+        app.view_mode = ViewMode::Detail;
+
+        // Restart from detail
+        app.handle_input(key(KeyCode::Char('r')));
+        assert_eq!(app.view_mode, ViewMode::Confirm(ConfirmAction::Restart));
+        app.view_mode = ViewMode::Detail;
+
+        // Logs from detail
+        let action = app.handle_input(key(KeyCode::Char('l')));
+        assert_eq!(action, InputAction::StreamLogs);
+        assert_eq!(app.view_mode, ViewMode::Logs);
+    }
+
+    #[test]
+    fn test_navigate_empty_list() {
+        let mut app = App::new();
+        app.focus = Focus::ResourceList;
+        // Should not panic on empty list
+        app.handle_input(key(KeyCode::Char('j')));
+        app.handle_input(key(KeyCode::Char('k')));
+        assert_eq!(app.table_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_q_from_detail_goes_back() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Detail;
+        app.handle_input(key(KeyCode::Char('q')));
+        assert_eq!(app.view_mode, ViewMode::List);
+        assert!(!app.should_quit);
+    }
+
+    // --- Sort Tests ---
+
+    use crate::types::parse_age;
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("45s"), Some(45));
+        assert_eq!(parse_age("10m"), Some(600));
+        assert_eq!(parse_age("2d3h"), Some(2 * 86400 + 3 * 3600));
+        assert_eq!(parse_age("<unknown>"), None);
+        assert_eq!(parse_age(""), None);
+    }
+
+    #[test]
+    fn test_age_derived_from_creation_time() {
+        use crate::types::format_age;
+
+        assert_eq!(format_age(ago(90)), "1m");
+        assert_eq!(format_age(ago(26 * 3600)), "1d2h");
+        assert_eq!(format_age(None), "<unknown>");
+
+        // Ages are recomputed on every call, not frozen at conversion time
+        let mut item = fake_pod("pod-0", "Running");
+        item.created = ago(120);
+        let headers = ResourceType::Pods.column_headers(ColumnLayout::Regular);
+        assert_eq!(item.columns(&headers)[3], "2m");
+        item.created = item.created.map(|c| c - 3600);
+        assert_eq!(item.columns(&headers)[3], "1h2m");
+    }
+
+    fn names(app: &App) -> Vec<String> {
+        app.filtered_resources()
+            .iter()
+            .map(|r| r.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_cycles_columns_and_direction() {
+        let mut app = app_with_pods();
+        assert_eq!(app.sort_header(), None);
+
+        app.handle_input(key(KeyCode::Char('s')));
+        assert_eq!(app.sort_header(), Some((0, "NAME")));
+        assert!(app.sort_ascending);
+
+        app.handle_input(key(KeyCode::Char('s')));
+        assert_eq!(app.sort_header(), Some((0, "NAME")));
+        assert!(!app.sort_ascending);
+        assert_eq!(names(&app), vec!["pod-2", "pod-1", "pod-0"]);
+
+        app.handle_input(key(KeyCode::Char('s')));
+        assert_eq!(app.sort_header(), Some((1, "READY")));
+
+        // Cycling past the last column restores the default order
+        for _ in 0..14 {
+            app.handle_input(key(KeyCode::Char('s')));
+        }
+        assert_eq!(app.sort_header(), None);
+        assert_eq!(names(&app), vec!["pod-0", "pod-1", "pod-2"]);
+    }
+
+    #[test]
+    fn test_sort_skips_columns_a_narrow_list_hides() {
+        let mut app = app_with_pods();
+        app.areas.list.width = 60;
+        let mut sorted = Vec::new();
+        loop {
+            app.handle_input(key(KeyCode::Char('s')));
+            match app.sort_header() {
+                Some((_, header)) if app.sort_ascending => sorted.push(header),
+                Some(_) => {}
+                None => break,
+            }
+        }
+        assert!(sorted.contains(&"STATUS"), "{:?}", sorted);
+        assert!(!sorted.contains(&"RESTARTS"), "{:?}", sorted);
+        assert!(!sorted.contains(&"NODE"), "{:?}", sorted);
+        assert_eq!(sorted, app.visible_headers(60));
+
+        app.areas.list.width = 80;
+        assert!(app.visible_headers(80).contains(&"RESTARTS"));
+        assert!(!app.visible_headers(80).contains(&"NODE"));
+    }
+
+    #[test]
+    fn test_sort_by_age_is_numeric() {
+        let mut app = app_with_pods();
+        app.resources[0].created = ago(2 * 86400);
+        app.resources[1].created = ago(10 * 60);
+        app.resources[2].created = ago(3 * 3600 + 5 * 60);
+        app.sort_column = Some(3); // AGE
+        app.update_filter();
+
+        assert_eq!(names(&app), vec!["pod-1", "pod-2", "pod-0"]);
+    }
+
+    #[test]
+    fn test_sort_by_restarts_is_numeric() {
+        let mut app = app_with_pods();
+        app.resources[0].extra[0].1 = "10".to_string();
+        app.resources[1].extra[0].1 = "9".to_string();
+        app.resources[2].extra[0].1 = "0".to_string();
+        app.sort_column = Some(4); // RESTARTS
+        app.sort_ascending = false;
+        app.update_filter();
+
+        assert_eq!(names(&app), vec!["pod-0", "pod-1", "pod-2"]);
+    }
+
+    // --- Pod Metrics Tests ---
+
+    #[test]
+    fn test_apply_pod_metrics_merges_usage() {
+        let mut app = app_with_pods();
+        app.pod_metrics.insert(
+            "default/pod-1".to_string(),
+            ("250m".to_string(), "128Mi".to_string()),
+        );
+        app.apply_pod_metrics();
+
+        let headers = ResourceType::Pods.column_headers(ColumnLayout::Regular);
+        let cols = app.resources[1].columns(&headers);
+        assert_eq!(&cols[6..], ["250m", "128Mi"]);
+        // Pods without metrics fall back to <none>
+        let cols = app.resources[0].columns(&headers);
+        assert_eq!(&cols[6..], ["<none>", "<none>"]);
+
+        // Re-applying replaces rather than duplicates, and empty metrics clear
+        app.apply_pod_metrics();
+        app.pod_metrics.clear();
+        app.apply_pod_metrics();
+        assert!(!app.resources[1].extra.iter().any(|(k, _)| k == "cpu"));
+    }
+
+    // --- Fuzzy Search Tests ---
+
+    use crate::types::{fuzzy_match, SearchResult};
+
+    fn fake_search_result(name: &str, ns: &str, ctx: &str, rt: ResourceType) -> SearchResult {
+        SearchResult {
+            resource: ResourceItem {
+                name: name.to_string(),
+                namespace: ns.to_string(),
+                status: "Running".to_string(),
+                created: ago(3600),
+                extra: vec![
+                    ("restarts".to_string(), "0".to_string()),
+                    ("node".to_string(), "node-a".to_string()),
+                ],
+                spec: SpecSummary::default(),
+            },
+            context: ctx.to_string(),
+            resource_type: rt,
+        }
+    }
+
+    fn app_with_search_results() -> App {
+        let mut app = App::new();
+        app.contexts = vec!["gke-prod".to_string(), "gke-staging".to_string()];
+        app.view_mode = ViewMode::Search;
+        app.search_results = vec![
+            fake_search_result("op-geth-node-0", "ethereum", "gke-prod", ResourceType::Pods),
+            fake_search_result("op-geth-node-1", "ethereum", "gke-prod", ResourceType::Pods),
+            fake_search_result("op-geth-node-0", "ethereum", "gke-staging", ResourceType::Pods),
+            fake_search_result("redis-master-0", "cache", "gke-prod", ResourceType::Pods),
+            fake_search_result("nginx-ingress", "default", "gke-prod", ResourceType::StatefulSets),
+        ];
+        app.update_search_filter();
+        app
+    }
+
+    #[test]
+    fn test_ctrl_f_enters_search_mode() {
+        let mut app = app_with_pods();
+        app.contexts = vec!["ctx-1".to_string()];
+        let action = app.handle_input(key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert_eq!(action, InputAction::StartSearch);
+        assert_eq!(app.view_mode, ViewMode::Search);
+        assert!(app.search_query.is_empty());
+        assert!(app.search_loading);
+    }
+
+    #[test]
+    fn test_ctrl_g_searches_current_namespace() {
+        let mut app = app_with_pods();
+        app.contexts = vec!["ctx-1".to_string(), "ctx-2".to_string()];
+        app.selected_context = 1;
+        app.namespaces = vec!["default".to_string(), "payments".to_string()];
+        app.selected_namespace = 1;
+
+        let action = app.handle_input(key_with_mod(KeyCode::Char('g'), KeyModifiers::CONTROL));
+        assert_eq!(action, InputAction::StartSearch);
+        assert_eq!(app.view_mode, ViewMode::Search);
+        let scope = app.search_scope.clone().expect("scoped search");
+        assert_eq!(scope.to_string(), "ctx-2/payments");
+
+        // A later Ctrl+F search is unscoped again
+        app.handle_input(key(KeyCode::Esc));
+        app.handle_input(key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert!(app.search_scope.is_none());
+    }
+
+    #[test]
+    fn test_search_matches_namespace_and_context_terms() {
+        let mut app = app_with_search_results();
+
+        type_str(&mut app, "ctx:stag");
+        assert_eq!(app.search_filtered.len(), 1);
+        assert_eq!(app.selected_search_result().unwrap().context, "gke-staging");
+
+        app.search_query.clear();
+        type_str(&mut app, "geth ns:eth ctx:prod");
+        let names: Vec<(&str, &str)> = app
+            .search_filtered
+            .iter()
+            .map(|&i| {
+                let r = &app.search_results[i];
+                (r.resource.name.as_str(), r.context.as_str())
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("op-geth-node-0", "gke-prod"),
+                ("op-geth-node-1", "gke-prod")
+            ]
+        );
+
+        app.search_query.clear();
+        type_str(&mut app, "ns:cache");
+        assert_eq!(
+            app.selected_search_result().unwrap().resource.name,
+            "redis-master-0"
+        );
+    }
+
+    #[test]
+    fn test_ctrl_t_cycles_search_type_filter() {
+        let mut app = app_with_search_results();
+        let ctrl_t = key_with_mod(KeyCode::Char('t'), KeyModifiers::CONTROL);
+
+        app.handle_input(ctrl_t);
+        assert_eq!(app.search_type_filter, Some(ResourceType::Pods));
+        assert_eq!(app.search_filtered.len(), 4);
+
+        app.handle_input(ctrl_t);
+        assert_eq!(app.search_type_filter, Some(ResourceType::StatefulSets));
+        assert_eq!(
+            app.selected_search_result().unwrap().resource.name,
+            "nginx-ingress"
+        );
+
+        // The filter combines with the fuzzy query
+        app.handle_input(key(KeyCode::Char('o')));
+        app.handle_input(key(KeyCode::Char('p')));
+        assert!(app.search_filtered.is_empty(), "Only pods match 'op'");
+        app.handle_input(key(KeyCode::Backspace));
+        app.handle_input(key(KeyCode::Backspace));
+
+        app.handle_input(ctrl_t);
+        assert_eq!(app.search_type_filter, None, "Cycles back to every type");
+        assert_eq!(app.search_filtered.len(), 5);
+    }
+
+    #[test]
+    fn test_esc_cancels_running_search() {
+        let mut app = app_with_search_results();
+        app.search_loading = true;
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Esc)),
+            InputAction::CancelSearch
+        );
+        assert!(!app.search_loading);
+        assert_eq!(app.view_mode, ViewMode::List);
+
+        // Nothing left to cancel once the scan has finished
+        app.view_mode = ViewMode::Search;
+        assert_eq!(app.handle_input(key(KeyCode::Esc)), InputAction::None);
+    }
+
+    #[test]
+    fn test_ctrl_f_does_nothing_in_detail_view() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Detail;
+        let action = app.handle_input(key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert_eq!(action, InputAction::None);
+        assert_eq!(app.view_mode, ViewMode::Detail);
+    }
+
+    #[test]
+    fn test_search_typing_updates_query() {
+        let mut app = app_with_search_results();
+
+        app.handle_input(key(KeyCode::Char('o')));
+        assert_eq!(app.search_query, "o");
+
+        app.handle_input(key(KeyCode::Char('p')));
+        assert_eq!(app.search_query, "op");
+
+        app.handle_input(key(KeyCode::Char('-')));
+        assert_eq!(app.search_query, "op-");
+    }
+
+    #[test]
+    fn test_search_backspace_removes_char() {
+        let mut app = app_with_search_results();
+        app.search_query = "op-geth".to_string();
+        app.update_search_filter();
+
+        app.handle_input(key(KeyCode::Backspace));
+        assert_eq!(app.search_query, "op-get");
+
+        app.handle_input(key(KeyCode::Backspace));
+        assert_eq!(app.search_query, "op-ge");
+    }
+
+    #[test]
+    fn test_search_esc_returns_to_list() {
+        let mut app = app_with_search_results();
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.view_mode, ViewMode::List);
+        assert!(!app.entered_from_search);
+    }
+
+    #[test]
+    fn test_search_filter_narrows_results() {
+        let mut app = app_with_search_results();
+
+        // Empty query shows all results
+        assert_eq!(app.search_filtered.len(), 5);
+
+        // Type "op-geth" to narrow down
+        app.search_query = "op-geth".to_string();
+        app.update_search_filter();
+        assert_eq!(app.search_filtered.len(), 3);
+
+        // Type "redis" to switch
+        app.search_query = "redis".to_string();
+        app.update_search_filter();
+        assert_eq!(app.search_filtered.len(), 1);
+        let result = app.selected_search_result().unwrap();
+        assert_eq!(result.resource.name, "redis-master-0");
+    }
+
+    #[test]
+    fn test_search_no_matches() {
+        let mut app = app_with_search_results();
+        app.search_query = "zzzzz".to_string();
+        app.update_search_filter();
+        assert_eq!(app.search_filtered.len(), 0);
+        assert!(app.selected_search_result().is_none());
+    }
+
+    #[test]
+    fn test_search_navigate_down_up() {
+        let mut app = app_with_search_results();
+        assert_eq!(app.search_table_state.selected(), Some(0));
+
+        app.handle_input(key(KeyCode::Down));
+        assert_eq!(app.search_table_state.selected(), Some(1));
+
+        app.handle_input(key(KeyCode::Down));
+        assert_eq!(app.search_table_state.selected(), Some(2));
+
+        app.handle_input(key(KeyCode::Up));
+        assert_eq!(app.search_table_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_search_navigate_wraps() {
+        let mut app = app_with_search_results();
+        // 5 results, go up from 0 wraps to 4
+        app.handle_input(key(KeyCode::Up));
+        assert_eq!(app.search_table_state.selected(), Some(4));
+
+        // Go down from 4 wraps to 0
+        app.handle_input(key(KeyCode::Down));
+        assert_eq!(app.search_table_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_search_enter_opens_detail() {
+        let mut app = app_with_search_results();
+        let action = app.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, InputAction::SearchDescribe);
+        assert_eq!(app.view_mode, ViewMode::Detail);
+        assert!(app.entered_from_search);
+    }
+
+    #[test]
+    fn test_search_enter_on_empty_does_nothing() {
+        let mut app = App::new();
+        app.view_mode = ViewMode::Search;
+        let action = app.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, InputAction::None);
+        assert_eq!(app.view_mode, ViewMode::Search);
+    }
+
+    #[test]
+    fn test_search_detail_esc_returns_to_search() {
+        let mut app = app_with_search_results();
+        app.view_mode = ViewMode::Detail;
+        app.entered_from_search = true;
+
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.view_mode, ViewMode::Search);
+    }
+
+    #[test]
+    fn test_search_detail_q_returns_to_search() {
+        let mut app = app_with_search_results();
+        app.view_mode = ViewMode::Detail;
+        app.entered_from_search = true;
+
+        app.handle_input(key(KeyCode::Char('q')));
+        assert_eq!(app.view_mode, ViewMode::Search);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_search_detail_scroll() {
+        let mut app = app_with_search_results();
+        app.view_mode = ViewMode::Detail;
+        app.entered_from_search = true;
+        app.detail_text = "line1\nline2\nline3\nline4\nline5\nline6\nline7\nline8\nline9\nline10\nline11\nline12".to_string();
+
+        app.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(app.detail_scroll, 1);
+
+        app.handle_input(key(KeyCode::Char('k')));
+        assert_eq!(app.detail_scroll, 0);
+
+        app.handle_input(key(KeyCode::Char('G')));
+        assert!(app.detail_scroll > 0);
+
+        app.handle_input(key(KeyCode::Char('g')));
+        assert_eq!(app.detail_scroll, 0);
+    }
+
+    #[test]
+    fn test_search_detail_logs_for_pods() {
+        let mut app = app_with_search_results();
+        app.view_mode = ViewMode::Detail;
+        app.entered_from_search = true;
+
+        let action = app.handle_input(key(KeyCode::Char('l')));
+        assert_eq!(action, InputAction::SearchStreamLogs);
+        assert_eq!(app.view_mode, ViewMode::Logs);
+        assert!(app.entered_from_search);
+    }
+
+    #[test]
+    fn test_search_logs_esc_returns_to_search() {
+        let mut app = app_with_search_results();
+        app.view_mode = ViewMode::Logs;
+        app.entered_from_search = true;
+
+        let action = app.handle_input(key(KeyCode::Esc));
+        assert_eq!(action, InputAction::StopLogs);
+        assert_eq!(app.view_mode, ViewMode::Search);
+    }
+
+    #[test]
+    fn test_search_logs_follow_toggle() {
+        let mut app = app_with_search_results();
+        app.view_mode = ViewMode::Logs;
+        app.entered_from_search = true;
+        assert!(app.log_follow);
+
+        app.handle_input(key(KeyCode::Char('f')));
+        assert!(!app.log_follow);
+
+        app.handle_input(key(KeyCode::Char('f')));
+        assert!(app.log_follow);
+    }
+
+    #[test]
+    fn test_search_results_across_contexts() {
+        let mut app = app_with_search_results();
+        app.search_query = "op-geth-node-0".to_string();
+        app.update_search_filter();
+
+        // Should find 2 results (one per cluster)
+        assert_eq!(app.search_filtered.len(), 2);
+
+        let r0 = &app.search_results[app.search_filtered[0]];
+        let r1 = &app.search_results[app.search_filtered[1]];
+        assert_eq!(r0.resource.name, "op-geth-node-0");
+        assert_eq!(r1.resource.name, "op-geth-node-0");
+        // Different clusters
+        assert_ne!(r0.context, r1.context);
+    }
+
+    #[test]
+    fn test_fuzzy_match_basic() {
+        // Exact match
+        assert!(fuzzy_match("pod", "pod").is_some());
+
+        // Substring
+        assert!(fuzzy_match("pod", "my-pod-0").is_some());
+
+        // Subsequence
+        assert!(fuzzy_match("ogn0", "op-geth-node-0").is_some());
+
+        // No match
+        assert!(fuzzy_match("xyz", "pod").is_none());
+
+        // Empty query matches everything
+        assert!(fuzzy_match("", "anything").is_some());
+    }
+
+    #[test]
+    fn test_fuzzy_match_case_insensitive() {
+        assert!(fuzzy_match("POD", "pod-0").is_some());
+        assert!(fuzzy_match("pod", "POD-0").is_some());
+    }
+
+    #[test]
+    fn test_fuzzy_match_scoring_prefers_exact() {
+        let exact_score = fuzzy_match("pod", "pod").unwrap();
+        let partial_score = fuzzy_match("pod", "my-pod-long-name").unwrap();
+        // Exact/shorter match should score higher (shorter target bonus)
+        assert!(exact_score > partial_score);
+    }
+
+    #[test]
+    fn test_fuzzy_match_tolerates_transposed_characters() {
+        let typo = fuzzy_match("gtehnode", "geth-node").expect("one swap away");
+        let clean = fuzzy_match("gethnode", "geth-node").unwrap();
+        assert!(clean > typo, "Clean matches rank first");
+
+        // Still a plain subsequence match when one exists
+        assert!(fuzzy_match("ogn0", "op-geth-node-0").unwrap() > 100);
+        assert!(fuzzy_match("xyz", "pod").is_none());
+        assert!(
+            fuzzy_match("po", "op").is_none(),
+            "Short queries aren't swapped"
+        );
+        assert!(
+            fuzzy_match("tgeh", "geth-node").is_none(),
+            "Only one adjacent swap"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_match_positions() {
+        use crate::types::fuzzy_match_positions;
+
+        let (score, positions) = fuzzy_match_positions("ogn0", "op-geth-node-0").unwrap();
+        assert_eq!(Some(score), fuzzy_match("ogn0", "op-geth-node-0"));
+        assert_eq!(positions, [0, 3, 8, 13]);
+        assert_eq!(fuzzy_match_positions("", "pod"), Some((0, vec![])));
+        assert_eq!(fuzzy_match_positions("xyz", "pod"), None);
+    }
+
+    #[test]
+    fn test_full_search_flow() {
+        let mut app = app_with_pods();
+        app.contexts = vec!["ctx-1".to_string()];
+
+        // Enter search
+        let action = app.handle_input(key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert_eq!(action, InputAction::StartSearch);
+        assert_eq!(app.view_mode, ViewMode::Search);
+
+        // Simulate results arriving
+        app.search_results = vec![
+            fake_search_result("op-geth-node-0", "eth", "ctx-1", ResourceType::Pods),
+            fake_search_result("redis-0", "cache", "ctx-1", ResourceType::Pods),
+        ];
+        app.update_search_filter();
+        assert_eq!(app.search_filtered.len(), 2);
+
+        // Type search query
+        app.handle_input(key(KeyCode::Char('o')));
+        app.handle_input(key(KeyCode::Char('p')));
+        assert_eq!(app.search_filtered.len(), 1);
+
+        // Enter detail
+        let action = app.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, InputAction::SearchDescribe);
+        assert_eq!(app.view_mode, ViewMode::Detail);
+        assert!(app.entered_from_search);
+
+        // Go back to search
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.view_mode, ViewMode::Search);
+
+        // Exit search
+        app.handle_input(key(KeyCode::Esc));
+        assert_eq!(app.view_mode, ViewMode::List);
+    }
+
+    // --- Port-forward Tests ---
+
+    use crate::types::{parse_port_mapping, PortForward};
+
+    #[test]
+    fn test_parse_port_mapping() {
+        assert_eq!(parse_port_mapping("8080:80"), Some((8080, 80)));
+        assert_eq!(parse_port_mapping(" 5432 "), Some((5432, 5432)));
+        assert_eq!(parse_port_mapping("8080:"), None);
+        assert_eq!(parse_port_mapping("abc"), None);
+        assert_eq!(parse_port_mapping("0:80"), None);
+        assert_eq!(parse_port_mapping("70000:80"), None);
+    }
+
+    #[test]
+    fn test_port_forward_prompt_prefills_container_port() {
+        let mut app = app_with_pods();
+        app.resources[0].spec.containers = vec!["web".to_string()];
+        app.resources[0].spec.container_port = Some(8080);
+
+        let action = app.handle_input(key(KeyCode::Char('p')));
+        assert_eq!(action, InputAction::None);
+        assert_eq!(
+            app.prompt.as_ref().map(|p| p.input.as_str()),
+            Some("8080:8080")
+        );
+
+        // Edit the remote port down to 80
+        for _ in 0..4 {
+            app.handle_input(key(KeyCode::Backspace));
+        }
+        app.handle_input(key(KeyCode::Char('8')));
+        app.handle_input(key(KeyCode::Char('0')));
+        let action = app.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, InputAction::PortForward);
+        assert!(app.prompt.is_none());
+        let forward = app.pending_port_forward.clone().unwrap();
+        assert_eq!(forward.name, "pod-0");
+        assert_eq!((forward.local_port, forward.remote_port), (8080, 80));
+    }
+
+    #[test]
+    fn test_port_forward_invalid_input_warns() {
+        use crate::types::Severity;
+
+        let mut app = app_with_pods();
+        app.handle_input(key(KeyCode::Char('p')));
+        app.handle_input(key(KeyCode::Char('x')));
+        let action = app.handle_input(key(KeyCode::Enter));
+
+        assert_eq!(action, InputAction::None);
+        assert!(app.prompt.is_none());
+        assert!(app.pending_port_forward.is_none());
+        assert!(app.status_text().unwrap().contains("Invalid port mapping"));
+        assert_eq!(
+            app.message_log.back().map(|(_, s, _)| *s),
+            Some(Severity::Warning),
+            "A typo, not a failure"
+        );
+    }
+
+    #[test]
+    fn test_port_forward_p_again_stops_forward() {
+        let mut app = app_with_pods();
+        app.port_forwards.push(PortForward {
+            namespace: "default".to_string(),
+            name: "pod-0".to_string(),
+            resource_type: ResourceType::Pods,
+            local_port: 8080,
+            remote_port: 80,
+        });
+
+        let action = app.handle_input(key(KeyCode::Char('p')));
+        assert_eq!(action, InputAction::StopPortForward);
+        assert!(app.prompt.is_none());
+        assert!(app.port_forwards.is_empty());
+        assert_eq!(app.pending_port_forward.map(|f| f.local_port), Some(8080));
+    }
+
+    #[test]
+    fn test_port_forward_unsupported_type_ignored() {
+        let mut app = app_with_pods();
+        app.resource_type = ResourceType::ConfigMaps;
+        let action = app.handle_input(key(KeyCode::Char('p')));
+        assert_eq!(action, InputAction::None);
+        assert!(app.prompt.is_none());
+    }
+
+    // --- Scale Tests ---
+
+    fn app_with_statefulset(replicas: i64) -> App {
+        let mut app = app_with_pods();
+        app.resource_type = ResourceType::StatefulSets;
+        app.resources[0].spec.replicas = Some(replicas);
+        app
+    }
+
+    #[test]
+    fn test_scale_prompt_prefilled_with_replicas() {
+        let mut app = app_with_statefulset(3);
+
+        let action = app.handle_input(key(KeyCode::Char('=')));
+        assert_eq!(action, InputAction::None);
+        assert_eq!(app.prompt.as_ref().map(|p| p.input.as_str()), Some("3"));
+
+        app.handle_input(key(KeyCode::Backspace));
+        app.handle_input(key(KeyCode::Char('5')));
+        let action = app.handle_input(key(KeyCode::Enter));
+        assert_eq!(action, InputAction::Scale);
+        assert_eq!(app.pending_replicas, Some(5));
+    }
+
+    #[test]
+    fn test_scale_rejects_non_numeric_input() {
+        let mut app = app_with_statefulset(2);
+        app.handle_input(key(KeyCode::Char('=')));
+        app.handle_input(key(KeyCode::Char('x')));
+        let action = app.handle_input(key(KeyCode::Enter));
+
+        assert_eq!(action, InputAction::None);
+        assert_eq!(app.pending_replicas, None);
+        assert!(app.status_text().unwrap().contains("Invalid replica count"));
+    }
+
+    #[test]
+    fn test_scale_ignored_for_pods() {
+        let mut app = app_with_pods();
+        let action = app.handle_input(key(KeyCode::Char('=')));
+        assert_eq!(action, InputAction::None);
+        assert!(app.prompt.is_none());
+    }
+
+    // --- Help Overlay Tests ---
+
+    #[test]
+    fn test_help_overlay_toggle() {
+        let mut app = app_with_pods();
+
+        app.handle_input(key(KeyCode::Char('?')));
+        assert!(app.show_help);
+
+        // Keys are captured by the overlay while it is open
+        app.handle_input(key(KeyCode::Char('j')));
+        assert_eq!(app.help_scroll, 1);
+        assert_eq!(app.table_state.selected(), Some(0));
+
+        app.handle_input(key(KeyCode::Char('?')));
+        assert!(!app.show_help);
+
+        app.view_mode = ViewMode::Detail;
+        app.handle_input(key(KeyCode::Char('?')));
+        assert!(app.show_help);
+        app.handle_input(key(KeyCode::Esc));
+        assert!(!app.show_help);
+        assert_eq!(app.view_mode, ViewMode::Detail);
+    }
+
+    // --- Log Search Tests ---
+
+    fn app_in_logs(lines: &[&str]) -> App {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_follow = true;
+        app.log_lines = lines.iter().map(|l| l.to_string()).collect();
+        app
+    }
+
+    fn type_str(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_input(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_log_search_jumps_and_wraps() {
+        let mut app = app_in_logs(&["boot", "timeout 1", "ok", "timeout 2", "ok"]);
+
+        app.handle_input(key(KeyCode::Char('/')));
+        assert!(app.log_search_active);
+        assert!(!app.log_follow, "Searching should disable follow");
+
+        type_str(&mut app, "timeout");
+        app.handle_input(key(KeyCode::Enter));
+        assert!(!app.log_search_active);
+        assert_eq!(app.log_matches, vec![1, 3]);
+        assert_eq!(app.log_scroll, 1);
+
+        app.handle_input(key(KeyCode::Char('n')));
+        assert_eq!(app.current_log_match(), Some(3));
+        app.handle_input(key(KeyCode::Char('n')));
+        assert_eq!(
+            app.current_log_match(),
+            Some(1),
+            "n should wrap to the first match"
+        );
+        app.handle_input(key(KeyCode::Char('N')));
+        assert_eq!(
+            app.current_log_match(),
+            Some(3),
+            "N should wrap to the last match"
+        );
+        assert_eq!(app.log_scroll, 3);
+    }
+
+    #[test]
+    fn test_log_search_esc_clears_before_leaving() {
+        let mut app = app_in_logs(&["a", "b"]);
+        app.handle_input(key(KeyCode::Char('/')));
+        type_str(&mut app, "b");
+        app.handle_input(key(KeyCode::Enter));
+
+        let action = app.handle_input(key(KeyCode::Esc));
+        assert_eq!(action, InputAction::None);
+        assert_eq!(app.view_mode, ViewMode::Logs);
+        assert!(app.log_search.is_empty());
+        assert!(app.log_matches.is_empty());
+
+        let action = app.handle_input(key(KeyCode::Esc));
+        assert_eq!(action, InputAction::StopLogs);
+        assert_eq!(app.view_mode, ViewMode::List);
+    }
+
+    #[test]
+    fn test_log_search_tracks_streamed_lines() {
+        let mut app = app_in_logs(&["error one"]);
+        app.handle_input(key(KeyCode::Char('/')));
+        type_str(&mut app, "error");
+        app.handle_input(key(KeyCode::Enter));
+
+        app.push_log_line("fine".to_string());
+        app.push_log_line("error two".to_string());
+        assert_eq!(app.log_matches, vec![0, 2]);
     }
 
     #[test]
-    fn test_ctrl_f_does_nothing_in_detail_view() {
-        let mut app = app_with_pods();
-        app.view_mode = ViewMode::Detail;
-        let action = app.handle_input(key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL));
-        assert_eq!(action, InputAction::None);
-        assert_eq!(app.view_mode, ViewMode::Detail);
+    fn test_log_ansi_codes_are_stripped_or_ignored_by_search() {
+        use crate::config::LogAnsi;
+
+        let mut app = app_in_logs(&[]);
+        app.handle_input(key(KeyCode::Char('/')));
+        type_str(&mut app, "GET /");
+        app.handle_input(key(KeyCode::Enter));
+
+        app.push_log_line("\x1b[32mGET\x1b[0m /health 200".to_string());
+        assert_eq!(
+            app.log_matches,
+            vec![0],
+            "Rendered codes don't split a match"
+        );
+        assert_eq!(
+            app.log_lines[0], "\x1b[32mGET\x1b[0m /health 200",
+            "Kept for rendering"
+        );
+
+        app.log_ansi = LogAnsi::Strip;
+        app.push_log_line("\x1b[1;31mGET\x1b[m /boom 500".to_string());
+        assert_eq!(app.log_lines[1], "GET /boom 500");
+        assert_eq!(app.log_matches, vec![0, 1]);
+
+        app.log_ansi = LogAnsi::Raw;
+        app.push_log_line("\x1b[33mGET\x1b[0m /slow 200".to_string());
+        assert_eq!(app.log_lines[2], "\x1b[33mGET\x1b[0m /slow 200");
+        assert_eq!(
+            app.log_matches,
+            vec![0, 1],
+            "Raw codes are part of the text"
+        );
+
+        let config = crate::config::parse("log_ansi: strip\n").unwrap();
+        assert_eq!(config.log_ansi, LogAnsi::Strip);
+        assert_eq!(crate::config::parse("").unwrap().log_ansi, LogAnsi::Render);
+        assert!(crate::config::parse("log_ansi: colors\n").is_err());
     }
 
     #[test]
-    fn test_search_typing_updates_query() {
-        let mut app = app_with_search_results();
+    fn test_log_grep_filters_and_restores() {
+        let mut app = app_in_logs(&["GET /a 200", "GET /b 500", "GET /c 200"]);
+        app.handle_input(key(KeyCode::Char('/')));
+        type_str(&mut app, "500");
+        app.handle_input(key(KeyCode::Enter));
 
-        app.handle_input(key(KeyCode::Char('o')));
-        assert_eq!(app.search_query, "o");
+        app.handle_input(key(KeyCode::Char('&')));
+        assert!(app.grep_active());
+        assert_eq!(app.visible_log_lines(), vec![1]);
 
-        app.handle_input(key(KeyCode::Char('p')));
-        assert_eq!(app.search_query, "op");
+        // Lines streamed while filtered are kept, and shown if they match
+        app.push_log_line("GET /d 500".to_string());
+        app.push_log_line("GET /e 200".to_string());
+        assert_eq!(app.visible_log_lines(), vec![1, 3]);
 
-        app.handle_input(key(KeyCode::Char('-')));
-        assert_eq!(app.search_query, "op-");
+        app.handle_input(key(KeyCode::Char('&')));
+        assert!(!app.grep_active());
+        assert_eq!(app.visible_log_lines().len(), 5);
     }
 
     #[test]
-    fn test_search_backspace_removes_char() {
-        let mut app = app_with_search_results();
-        app.search_query = "op-geth".to_string();
-        app.update_search_filter();
-
-        app.handle_input(key(KeyCode::Backspace));
-        assert_eq!(app.search_query, "op-get");
-
-        app.handle_input(key(KeyCode::Backspace));
-        assert_eq!(app.search_query, "op-ge");
+    fn test_log_grep_without_pattern_prompts() {
+        let mut app = app_in_logs(&["a"]);
+        app.handle_input(key(KeyCode::Char('&')));
+        assert!(app.log_grep);
+        assert!(app.log_search_active);
+        assert!(
+            !app.grep_active(),
+            "No filtering until a pattern is entered"
+        );
     }
 
     #[test]
-    fn test_search_esc_returns_to_list() {
-        let mut app = app_with_search_results();
-        app.handle_input(key(KeyCode::Esc));
-        assert_eq!(app.view_mode, ViewMode::List);
-        assert!(!app.entered_from_search);
+    fn test_log_ignore_case_toggle() {
+        let mut app = app_in_logs(&["ERROR one", "error two", "ok"]);
+        app.handle_input(key(KeyCode::Char('/')));
+        type_str(&mut app, "error");
+        app.handle_input(key(KeyCode::Enter));
+        assert_eq!(app.log_matches, vec![1]);
+
+        app.handle_input(key(KeyCode::Char('i')));
+        assert_eq!(app.log_matches, vec![0, 1]);
+        app.push_log_line("Error three".to_string());
+        assert_eq!(app.log_matches, vec![0, 1, 3]);
     }
 
+    // --- Copy Tests ---
+
     #[test]
-    fn test_search_filter_narrows_results() {
-        let mut app = app_with_search_results();
+    fn test_copy_name_from_list_and_detail() {
+        let mut app = app_with_pods();
+        app.table_state.select(Some(1));
 
-        // Empty query shows all results
-        assert_eq!(app.search_filtered.len(), 5);
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('y'))),
+            InputAction::CopyName
+        );
+        assert_eq!(app.pending_clipboard.take().as_deref(), Some("pod-1"));
 
-        // Type "op-geth" to narrow down
-        app.search_query = "op-geth".to_string();
-        app.update_search_filter();
-        assert_eq!(app.search_filtered.len(), 3);
+        app.view_mode = ViewMode::Detail;
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('Y'))),
+            InputAction::CopyName
+        );
+        assert_eq!(
+            app.pending_clipboard.take().as_deref(),
+            Some("default/pod-1")
+        );
+    }
 
-        // Type "redis" to switch
-        app.search_query = "redis".to_string();
-        app.update_search_filter();
-        assert_eq!(app.search_filtered.len(), 1);
-        let result = app.selected_search_result().unwrap();
-        assert_eq!(result.resource.name, "redis-master-0");
+    #[test]
+    fn test_copy_with_nothing_selected_is_noop() {
+        let mut app = App::new();
+        app.focus = Focus::ResourceList;
+        assert_eq!(app.handle_input(key(KeyCode::Char('y'))), InputAction::None);
+        assert!(app.pending_clipboard.is_none());
     }
 
     #[test]
-    fn test_search_no_matches() {
+    fn test_copy_search_result_includes_context() {
         let mut app = app_with_search_results();
-        app.search_query = "zzzzz".to_string();
-        app.update_search_filter();
-        assert_eq!(app.search_filtered.len(), 0);
-        assert!(app.selected_search_result().is_none());
+        app.search_table_state.select(Some(0));
+
+        let action = app.handle_input(key_with_mod(KeyCode::Char('y'), KeyModifiers::CONTROL));
+        assert_eq!(action, InputAction::CopyName);
+        assert_eq!(
+            app.pending_clipboard.take().as_deref(),
+            Some("gke-prod/ethereum/op-geth-node-0")
+        );
+        assert!(
+            app.search_query.is_empty(),
+            "Ctrl+Y must not edit the query"
+        );
+
+        // Plain 'y' still types into the search query
+        app.handle_input(key(KeyCode::Char('y')));
+        assert_eq!(app.search_query, "y");
     }
 
     #[test]
-    fn test_search_navigate_down_up() {
+    fn test_copy_yaml_from_detail() {
+        let mut app = app_with_pods();
+        app.table_state.select(Some(1));
+        app.view_mode = ViewMode::Detail;
+
+        // Not loaded yet: main fetches it first
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('c'))),
+            InputAction::CopyYaml
+        );
+        assert_eq!(app.yaml_to_clipboard.as_deref(), Some("the YAML of pod-1"));
+        assert!(app.loading);
+
+        // Already shown by `v`: nothing to wait for
+        app.loading = false;
+        app.detail_raw_yaml = "apiVersion: v1\nkind: Pod".to_string();
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('c'))),
+            InputAction::CopyYaml
+        );
+        assert!(!app.loading);
+
+        // Secrets are copied as the YAML view shows them, masked until `x`
+        app.resource_type = ResourceType::Secrets;
+        app.handle_input(key(KeyCode::Char('c')));
+        let what = app.yaml_to_clipboard.take().unwrap();
+        assert!(what.contains("values hidden"), "got {}", what);
+        app.handle_input(key(KeyCode::Char('x')));
+        app.handle_input(key(KeyCode::Char('c')));
+        assert_eq!(app.yaml_to_clipboard.as_deref(), Some("the YAML of pod-1"));
+
+        // A search result's detail copies that result's YAML
         let mut app = app_with_search_results();
-        assert_eq!(app.search_table_state.selected(), Some(0));
+        app.search_table_state.select(Some(0));
+        app.entered_from_search = true;
+        app.view_mode = ViewMode::Detail;
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('c'))),
+            InputAction::CopyYaml
+        );
+        assert_eq!(
+            app.yaml_to_clipboard.as_deref(),
+            Some("the YAML of op-geth-node-0")
+        );
+    }
 
-        app.handle_input(key(KeyCode::Down));
-        assert_eq!(app.search_table_state.selected(), Some(1));
+    // --- Log Stream Options Tests ---
 
-        app.handle_input(key(KeyCode::Down));
-        assert_eq!(app.search_table_state.selected(), Some(2));
+    use crate::types::parse_tail_lines;
 
-        app.handle_input(key(KeyCode::Up));
-        assert_eq!(app.search_table_state.selected(), Some(1));
+    #[test]
+    fn test_parse_tail_lines() {
+        assert_eq!(parse_tail_lines("1000"), Some(Some(1000)));
+        assert_eq!(parse_tail_lines(" 10 "), Some(Some(10)));
+        assert_eq!(parse_tail_lines("0"), Some(None));
+        assert_eq!(parse_tail_lines("ALL"), Some(None));
+        assert_eq!(parse_tail_lines("-5"), None);
+        assert_eq!(parse_tail_lines("lots"), None);
     }
 
     #[test]
-    fn test_search_navigate_wraps() {
-        let mut app = app_with_search_results();
-        // 5 results, go up from 0 wraps to 4
-        app.handle_input(key(KeyCode::Up));
-        assert_eq!(app.search_table_state.selected(), Some(4));
+    fn test_tail_prompt_restarts_stream() {
+        let mut app = app_in_logs(&["old 1", "old 2"]);
+        assert_eq!(app.log_tail, Some(100));
 
-        // Go down from 4 wraps to 0
-        app.handle_input(key(KeyCode::Down));
-        assert_eq!(app.search_table_state.selected(), Some(0));
-    }
+        app.handle_input(key(KeyCode::Char('T')));
+        assert_eq!(app.prompt.as_ref().map(|p| p.input.as_str()), Some("100"));
 
-    #[test]
-    fn test_search_enter_opens_detail() {
-        let mut app = app_with_search_results();
+        for _ in 0..3 {
+            app.handle_input(key(KeyCode::Backspace));
+        }
+        type_str(&mut app, "all");
         let action = app.handle_input(key(KeyCode::Enter));
-        assert_eq!(action, InputAction::SearchDescribe);
-        assert_eq!(app.view_mode, ViewMode::Detail);
-        assert!(app.entered_from_search);
+        assert_eq!(action, InputAction::StreamLogs);
+        assert_eq!(app.log_tail, None);
+        assert!(
+            app.log_lines.is_empty(),
+            "Buffer is refetched with the new tail"
+        );
     }
 
     #[test]
-    fn test_search_enter_on_empty_does_nothing() {
-        let mut app = App::new();
-        app.view_mode = ViewMode::Search;
-        let action = app.handle_input(key(KeyCode::Enter));
-        assert_eq!(action, InputAction::None);
-        assert_eq!(app.view_mode, ViewMode::Search);
+    fn test_tail_prompt_invalid_keeps_stream() {
+        let mut app = app_in_logs(&["line"]);
+        app.handle_input(key(KeyCode::Char('T')));
+        type_str(&mut app, "x");
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::None);
+        assert_eq!(app.log_tail, Some(100));
+        assert_eq!(app.log_lines.len(), 1);
+        assert!(app.status_message.is_some());
     }
 
     #[test]
-    fn test_search_detail_esc_returns_to_search() {
-        let mut app = app_with_search_results();
-        app.view_mode = ViewMode::Detail;
-        app.entered_from_search = true;
+    fn test_save_logs_prompt_defaults_to_pod_name() {
+        let mut app = app_in_logs(&["line"]);
+        app.handle_input(key(KeyCode::Char('s')));
+        let input = app.prompt.as_ref().map(|p| p.input.clone()).unwrap();
+        assert!(input.starts_with("pod-0-"), "got {}", input);
+        assert!(input.ends_with(".log"));
+
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::SaveLogs);
+        assert_eq!(app.pending_log_path, Some(input));
+    }
 
-        app.handle_input(key(KeyCode::Esc));
-        assert_eq!(app.view_mode, ViewMode::Search);
+    #[test]
+    fn test_save_logs_prompt_rejects_empty_path() {
+        let mut app = app_in_logs(&["line"]);
+        app.handle_input(key(KeyCode::Char('s')));
+        app.prompt.as_mut().unwrap().input = "  ".to_string();
+        assert_eq!(app.handle_input(key(KeyCode::Enter)), InputAction::None);
+        assert!(app.pending_log_path.is_none());
+        assert!(app.status_message.is_some());
+
+        // Nothing to save without output
+        let mut app = app_in_logs(&[]);
+        app.handle_input(key(KeyCode::Char('s')));
+        assert!(app.prompt.is_none());
     }
 
     #[test]
-    fn test_search_detail_q_returns_to_search() {
-        let mut app = app_with_search_results();
-        app.view_mode = ViewMode::Detail;
+    fn test_previous_logs_toggle_restarts_stream() {
+        let mut app = app_in_logs(&["current instance"]);
+        assert!(!app.log_options().previous);
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('P'))),
+            InputAction::StreamLogs
+        );
+        assert!(app.log_options().previous);
+        assert!(app.log_lines.is_empty());
+
         app.entered_from_search = true;
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('P'))),
+            InputAction::SearchStreamLogs
+        );
+        assert!(!app.log_previous);
+    }
 
-        app.handle_input(key(KeyCode::Char('q')));
-        assert_eq!(app.view_mode, ViewMode::Search);
-        assert!(!app.should_quit);
+    #[test]
+    fn test_opening_logs_resets_previous() {
+        let mut app = app_with_pods();
+        app.log_previous = true;
+        app.handle_input(key(KeyCode::Char('l')));
+        assert!(
+            !app.log_previous,
+            "A new pod starts on its running container"
+        );
     }
 
     #[test]
-    fn test_search_detail_scroll() {
-        let mut app = app_with_search_results();
-        app.view_mode = ViewMode::Detail;
-        app.entered_from_search = true;
-        app.detail_text = "line1\nline2\nline3\nline4\nline5\nline6\nline7\nline8\nline9\nline10\nline11\nline12".to_string();
+    fn test_timestamps_toggle_clears_buffer_and_keeps_follow() {
+        let mut app = app_in_logs(&["plain line"]);
+        app.log_follow = false;
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('t'))),
+            InputAction::StreamLogs
+        );
+        assert!(app.log_options().timestamps);
+        assert!(
+            app.log_lines.is_empty(),
+            "Old untimestamped lines are dropped"
+        );
+        assert!(!app.log_follow, "Follow state survives the restart");
+
+        app.log_follow = true;
+        app.handle_input(key(KeyCode::Char('t')));
+        assert!(!app.log_timestamps);
+        assert!(app.log_follow);
+    }
 
-        app.handle_input(key(KeyCode::Char('j')));
-        assert_eq!(app.detail_scroll, 1);
+    #[test]
+    fn test_log_since_cycles_windows_and_restarts() {
+        use crate::types::since_label;
+
+        let mut app = app_in_logs(&["old line"]);
+        assert_eq!(app.log_options().since_seconds, None);
+
+        assert_eq!(
+            app.handle_input(key(KeyCode::Char('S'))),
+            InputAction::StreamLogs
+        );
+        assert_eq!(app.log_options().since_seconds, Some(300));
+        assert!(
+            app.log_lines.is_empty(),
+            "Buffer is refetched for the window"
+        );
+
+        let labels: Vec<String> = (0..4)
+            .map(|_| {
+                app.handle_input(key(KeyCode::Char('S')));
+                since_label(app.log_since)
+            })
+            .collect();
+        assert_eq!(labels, ["15m", "1h", "all", "5m"]);
+    }
 
-        app.handle_input(key(KeyCode::Char('k')));
-        assert_eq!(app.detail_scroll, 0);
+    #[test]
+    fn test_log_wrap_toggle_enables_horizontal_scroll() {
+        let mut app = app_in_logs(&["a long line"]);
+        assert!(app.log_wrap);
+
+        app.handle_input(key(KeyCode::Char('l')));
+        assert_eq!(app.log_hscroll, 0, "Wrapped lines don't scroll sideways");
+
+        app.handle_input(key(KeyCode::Char('w')));
+        assert!(!app.log_wrap);
+        app.handle_input(key(KeyCode::Char('l')));
+        app.handle_input(key(KeyCode::Right));
+        assert_eq!(app.log_hscroll, 16);
+        app.handle_input(key(KeyCode::Char('h')));
+        assert_eq!(app.log_hscroll, 8);
+
+        app.handle_input(key(KeyCode::Char('w')));
+        assert!(app.log_wrap);
+        assert_eq!(app.log_hscroll, 0, "Wrapping again resets the offset");
+    }
 
-        app.handle_input(key(KeyCode::Char('G')));
-        assert!(app.detail_scroll > 0);
+    // --- Command Line Tests ---
 
-        app.handle_input(key(KeyCode::Char('g')));
-        assert_eq!(app.detail_scroll, 0);
+    use crate::cli;
+
+    fn args(list: &[&str]) -> anyhow::Result<cli::Args> {
+        cli::parse(list.iter().map(|s| s.to_string()))
     }
 
     #[test]
-    fn test_search_detail_logs_for_pods() {
-        let mut app = app_with_search_results();
-        app.view_mode = ViewMode::Detail;
-        app.entered_from_search = true;
-
-        let action = app.handle_input(key(KeyCode::Char('l')));
-        assert_eq!(action, InputAction::SearchStreamLogs);
-        assert_eq!(app.view_mode, ViewMode::Logs);
-        assert!(app.entered_from_search);
+    fn test_cli_flags_preselect_start() {
+        let flags = [
+            "--context",
+            "prod",
+            "--namespace=payments",
+            "--resource",
+            "Deployment",
+        ];
+        let parsed = args(&flags).unwrap();
+        assert_eq!(parsed.context.as_deref(), Some("prod"));
+        assert_eq!(parsed.namespace.as_deref(), Some("payments"));
+        assert_eq!(parsed.resource, Some(ResourceType::Deployments));
+        assert_eq!(args(&[]).unwrap(), cli::Args::default());
+        assert!(args(&["-h"]).unwrap().help);
     }
 
     #[test]
-    fn test_search_logs_esc_returns_to_search() {
-        let mut app = app_with_search_results();
-        app.view_mode = ViewMode::Logs;
-        app.entered_from_search = true;
-
-        let action = app.handle_input(key(KeyCode::Esc));
-        assert_eq!(action, InputAction::StopLogs);
-        assert_eq!(app.view_mode, ViewMode::Search);
+    fn test_cli_rejects_bad_values() {
+        let err = args(&["--resource", "widgets"]).unwrap_err().to_string();
+        assert!(
+            err.contains("unknown resource type 'widgets'"),
+            "got: {}",
+            err
+        );
+        assert!(args(&["--context"])
+            .unwrap_err()
+            .to_string()
+            .contains("needs a value"));
+        assert!(args(&["--namespace="]).is_err());
+        assert!(args(&["prod"])
+            .unwrap_err()
+            .to_string()
+            .contains("unknown argument 'prod'"));
     }
 
     #[test]
-    fn test_search_logs_follow_toggle() {
-        let mut app = app_with_search_results();
-        app.view_mode = ViewMode::Logs;
-        app.entered_from_search = true;
-        assert!(app.log_follow);
+    fn test_resource_type_from_name() {
+        assert_eq!(ResourceType::from_name("pods"), Some(ResourceType::Pods));
+        assert_eq!(ResourceType::from_name("pod"), Some(ResourceType::Pods));
+        assert_eq!(
+            ResourceType::from_name("ingress"),
+            Some(ResourceType::Ingresses)
+        );
+        assert_eq!(
+            ResourceType::from_name("NetworkPolicy"),
+            Some(ResourceType::NetworkPolicies)
+        );
+        assert_eq!(
+            ResourceType::from_name("storageclass"),
+            Some(ResourceType::StorageClasses)
+        );
+        assert_eq!(ResourceType::from_name("pd"), None);
+    }
 
-        app.handle_input(key(KeyCode::Char('f')));
-        assert!(!app.log_follow);
+    // --- API Error Tests ---
 
-        app.handle_input(key(KeyCode::Char('f')));
-        assert!(app.log_follow);
+    use crate::k8s::errors;
+
+    fn api_error(code: u16, reason: &str) -> anyhow::Error {
+        let status = kube::core::Status::failure("pods \"web-0\" is forbidden: User ...", reason);
+        anyhow::Error::new(kube::Error::Api(status.with_code(code).boxed()))
+            .context("Failed to delete pod")
     }
 
     #[test]
-    fn test_search_results_across_contexts() {
-        let mut app = app_with_search_results();
-        app.search_query = "op-geth-node-0".to_string();
-        app.update_search_filter();
+    fn test_forbidden_errors_are_explained() {
+        let err = api_error(403, "Forbidden");
+        let rt = ResourceType::Pods;
+        assert_eq!(
+            errors::explain("Delete error", &err, "delete", rt, Some("web-0"), "prod"),
+            "Forbidden: you don't have permission to delete pods/web-0 in namespace prod"
+        );
+        assert_eq!(
+            errors::forbidden(&err, "list", rt, None, ALL_NAMESPACES).as_deref(),
+            Some("Forbidden: you don't have permission to list pods across all namespaces")
+        );
+        assert_eq!(
+            errors::forbidden(&err, "list", ResourceType::Nodes, None, "prod").as_deref(),
+            Some("Forbidden: you don't have permission to list nodes")
+        );
+    }
 
-        // Should find 2 results (one per cluster)
-        assert_eq!(app.search_filtered.len(), 2);
+    #[test]
+    fn test_other_api_errors_keep_their_message() {
+        let err = api_error(404, "NotFound");
+        let rt = ResourceType::Pods;
+        assert_eq!(
+            errors::forbidden(&err, "get", rt, Some("web-0"), "prod"),
+            None
+        );
+        assert_eq!(
+            errors::explain("YAML error", &err, "get", rt, Some("web-0"), "prod"),
+            "YAML error: Failed to delete pod"
+        );
+    }
 
-        let r0 = &app.search_results[app.search_filtered[0]];
-        let r1 = &app.search_results[app.search_filtered[1]];
-        assert_eq!(r0.resource.name, "op-geth-node-0");
-        assert_eq!(r1.resource.name, "op-geth-node-0");
-        // Different clusters
-        assert_ne!(r0.context, r1.context);
+    #[test]
+    fn test_timeouts_say_what_was_being_loaded() {
+        let err = errors::timed_out(std::time::Duration::from_secs(10), "listing pods");
+        assert_eq!(
+            err.to_string(),
+            "Timed out after 10s listing pods; the API server may be unreachable"
+        );
     }
 
     #[test]
-    fn test_fuzzy_match_basic() {
-        // Exact match
-        assert!(fuzzy_match("pod", "pod").is_some());
+    fn test_only_transient_errors_are_retryable() {
+        assert!(errors::is_transient(&api_error(503, "ServiceUnavailable")));
+        assert!(errors::is_transient(&api_error(429, "TooManyRequests")));
+        assert!(!errors::is_transient(&api_error(403, "Forbidden")));
+        assert!(!errors::is_transient(&api_error(404, "NotFound")));
+        assert!(!errors::is_transient(&anyhow::anyhow!("invalid YAML")));
+    }
 
-        // Substring
-        assert!(fuzzy_match("pod", "my-pod-0").is_some());
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_gives_up_after_three_attempts() {
+        let attempts = std::cell::Cell::new(0);
+        let result: anyhow::Result<()> = errors::retry(|| {
+            attempts.set(attempts.get() + 1);
+            async { Err(api_error(503, "ServiceUnavailable")) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 3);
+
+        attempts.set(0);
+        let result: anyhow::Result<()> = errors::retry(|| {
+            attempts.set(attempts.get() + 1);
+            async { Err(api_error(404, "NotFound")) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1, "A 404 is not retried");
+
+        attempts.set(0);
+        let result = errors::retry(|| {
+            attempts.set(attempts.get() + 1);
+            let n = attempts.get();
+            async move {
+                if n < 2 {
+                    Err(api_error(500, "InternalError"))
+                } else {
+                    Ok(n)
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+    }
 
-        // Subsequence
-        assert!(fuzzy_match("ogn0", "op-geth-node-0").is_some());
+    // --- Pod Status Tests ---
 
-        // No match
-        assert!(fuzzy_match("xyz", "pod").is_none());
+    fn pod_from_yaml(yaml: &str) -> k8s_openapi::api::core::v1::Pod {
+        serde_yaml::from_str(yaml).unwrap()
+    }
 
-        // Empty query matches everything
-        assert!(fuzzy_match("", "anything").is_some());
+    #[test]
+    fn test_pod_status_follows_init_containers() {
+        let pod = pod_from_yaml(
+            "
+spec:
+  initContainers: [{name: migrate}, {name: seed}]
+  containers: [{name: app}]
+status:
+  phase: Pending
+  initContainerStatuses:
+  - {name: migrate, image: x, imageID: x, ready: true, restartCount: 0,
+     state: {terminated: {exitCode: 0, reason: Completed}}}
+  - {name: seed, image: x, imageID: x, ready: false, restartCount: 0,
+     state: {running: {}}}
+",
+        );
+        assert_eq!(pod_status(&pod), "Init:1/2");
+
+        let pod = pod_from_yaml(
+            "
+spec:
+  initContainers: [{name: migrate}]
+  containers: [{name: app}]
+status:
+  phase: Pending
+  initContainerStatuses:
+  - {name: migrate, image: x, imageID: x, ready: false, restartCount: 3,
+     state: {waiting: {reason: CrashLoopBackOff}}}
+",
+        );
+        assert_eq!(pod_status(&pod), "Init:CrashLoopBackOff");
+
+        let pod = pod_from_yaml(
+            "
+spec:
+  initContainers: [{name: migrate}]
+  containers: [{name: app}]
+status:
+  phase: Pending
+  initContainerStatuses:
+  - {name: migrate, image: x, imageID: x, ready: false, restartCount: 0,
+     state: {terminated: {exitCode: 2}}}
+",
+        );
+        assert_eq!(pod_status(&pod), "Init:ExitCode:2");
     }
 
     #[test]
-    fn test_fuzzy_match_case_insensitive() {
-        assert!(fuzzy_match("POD", "pod-0").is_some());
-        assert!(fuzzy_match("pod", "POD-0").is_some());
+    fn test_pod_status_shows_why_containers_stopped() {
+        let pod = pod_from_yaml(
+            "
+spec:
+  containers: [{name: job}]
+status:
+  phase: Succeeded
+  containerStatuses:
+  - {name: job, image: x, imageID: x, ready: false, restartCount: 0,
+     state: {terminated: {exitCode: 0, reason: Completed}}}
+",
+        );
+        assert_eq!(pod_status(&pod), "Completed");
+
+        // The first container's reason wins over later ones
+        let pod = pod_from_yaml(
+            "
+spec:
+  containers: [{name: app}, {name: sidecar}]
+status:
+  phase: Running
+  containerStatuses:
+  - {name: app, image: x, imageID: x, ready: false, restartCount: 1,
+     state: {terminated: {exitCode: 137, reason: OOMKilled}}}
+  - {name: sidecar, image: x, imageID: x, ready: false, restartCount: 0,
+     state: {waiting: {reason: ContainerCreating}}}
+",
+        );
+        assert_eq!(pod_status(&pod), "OOMKilled");
+
+        // A finished container doesn't finish a pod that is still serving
+        let pod = pod_from_yaml(
+            "
+spec:
+  containers: [{name: app}, {name: setup}]
+status:
+  phase: Running
+  conditions: [{type: Ready, status: 'True'}]
+  containerStatuses:
+  - {name: app, image: x, imageID: x, ready: true, restartCount: 0, state: {running: {}}}
+  - {name: setup, image: x, imageID: x, ready: false, restartCount: 0,
+     state: {terminated: {exitCode: 0, reason: Completed}}}
+",
+        );
+        assert_eq!(pod_status(&pod), "Running");
     }
 
     #[test]
-    fn test_fuzzy_match_scoring_prefers_exact() {
-        let exact_score = fuzzy_match("pod", "pod").unwrap();
-        let partial_score = fuzzy_match("pod", "my-pod-long-name").unwrap();
-        // Exact/shorter match should score higher (shorter target bonus)
-        assert!(exact_score > partial_score);
+    fn test_pod_status_uses_pod_reason_and_deletion() {
+        let pod = pod_from_yaml(
+            "
+status:
+  phase: Failed
+  reason: Evicted
+",
+        );
+        assert_eq!(pod_status(&pod), "Evicted");
+        assert!(!ResourceItem {
+            status: pod_status(&pod),
+            ..fake_pod("p", "")
+        }
+        .is_healthy());
+
+        let pod = pod_from_yaml(
+            "
+metadata:
+  deletionTimestamp: '2026-01-01T00:00:00Z'
+status:
+  phase: Running
+",
+        );
+        assert_eq!(pod_status(&pod), "Terminating");
+        assert_eq!(pod_status(&pod_from_yaml("{}")), "Unknown");
     }
 
     #[test]
-    fn test_full_search_flow() {
-        let mut app = app_with_pods();
-        app.contexts = vec!["ctx-1".to_string()];
+    fn test_workload_pods_section_lines() {
+        let selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector =
+            serde_yaml::from_str("matchLabels: {app: web, tier: front}").unwrap();
+        assert_eq!(
+            crate::k8s::logs::label_selector_string(&selector).unwrap(),
+            "app=web,tier=front"
+        );
+
+        let pods: Vec<_> = ["web-b", "web-a"]
+            .iter()
+            .map(|name| {
+                pod_from_yaml(&format!(
+                    "
+metadata: {{name: {}}}
+spec:
+  nodeName: node-1
+  containers: [{{name: app}}]
+status:
+  phase: Running
+  containerStatuses:
+  - {{name: app, image: x, imageID: x, ready: true, restartCount: 2,
+     state: {{running: {{}}}}}}
+",
+                    name
+                ))
+            })
+            .collect();
+        let lines = crate::k8s::resources::selector_pod_lines(&pods);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("web-a  Running"), "{}", lines[0]);
+        assert!(lines[1].starts_with("web-b"), "Sorted by name");
+        assert!(lines[0].contains("restarts 2"), "{}", lines[0]);
+        assert!(lines[0].ends_with("node-1"), "{}", lines[0]);
+    }
 
-        // Enter search
-        let action = app.handle_input(key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL));
-        assert_eq!(action, InputAction::StartSearch);
-        assert_eq!(app.view_mode, ViewMode::Search);
+    #[test]
+    fn test_selector_logs_stream_each_pods_default_container() {
+        use crate::k8s::logs::default_container;
+
+        let pod = pod_from_yaml("spec: {containers: [{name: app}, {name: istio-proxy}]}");
+        assert_eq!(default_container(&pod).as_deref(), Some("app"));
+
+        let pod = pod_from_yaml(
+            "
+metadata:
+  annotations: {kubectl.kubernetes.io/default-container: web}
+spec: {containers: [{name: istio-proxy}, {name: web}]}
+",
+        );
+        assert_eq!(default_container(&pod).as_deref(), Some("web"));
+
+        // An annotation naming a missing container falls back to the first
+        let pod = pod_from_yaml(
+            "
+metadata:
+  annotations: {kubectl.kubernetes.io/default-container: gone}
+spec: {containers: [{name: app}]}
+",
+        );
+        assert_eq!(default_container(&pod).as_deref(), Some("app"));
+        assert_eq!(default_container(&pod_from_yaml("{}")), None);
+    }
 
-        // Simulate results arriving
-        app.search_results = vec![
-            fake_search_result("op-geth-node-0", "eth", "ctx-1", ResourceType::Pods),
-            fake_search_result("redis-0", "cache", "ctx-1", ResourceType::Pods),
-        ];
-        app.update_search_filter();
-        assert_eq!(app.search_filtered.len(), 2);
+    // --- Describe Events Tests ---
 
-        // Type search query
-        app.handle_input(key(KeyCode::Char('o')));
-        app.handle_input(key(KeyCode::Char('p')));
-        assert_eq!(app.search_filtered.len(), 1);
+    fn events_from_yaml(yaml: &str) -> Vec<k8s_openapi::api::core::v1::Event> {
+        serde_yaml::from_str(yaml).unwrap()
+    }
 
-        // Enter detail
-        let action = app.handle_input(key(KeyCode::Enter));
-        assert_eq!(action, InputAction::SearchDescribe);
-        assert_eq!(app.view_mode, ViewMode::Detail);
-        assert!(app.entered_from_search);
+    #[test]
+    fn test_event_lines_merge_repeats() {
+        use crate::k8s::resources::event_lines;
+
+        let lines = event_lines(&events_from_yaml(
+            "
+- {metadata: {}, involvedObject: {}, type: Warning, reason: BackOff, message: restarting,
+   count: 2, firstTimestamp: '2020-01-01T00:00:00Z', lastTimestamp: '2020-01-02T00:00:00Z'}
+- {metadata: {}, involvedObject: {}, type: Warning, reason: BackOff, message: restarting,
+   count: 3, firstTimestamp: '2020-01-03T00:00:00Z', lastTimestamp: '2020-01-04T00:00:00Z'}
+- {metadata: {}, involvedObject: {}, reason: Pulled, message: pulled image,
+   lastTimestamp: '2020-01-03T00:00:00Z'}
+",
+        ));
+        assert_eq!(lines.len(), 2, "{:?}", lines);
+        // The repeats span the earliest first to the latest last timestamp
+        let first = crate::types::format_age(Some(1577836800)); // 2020-01-01
+        let last = crate::types::format_age(Some(1578096000)); // 2020-01-04
+        assert!(lines[0].starts_with("Warning  BackOff"), "{}", lines[0]);
+        assert!(
+            lines[0].contains(&format!("{} (x5 over {})", last, first)),
+            "{}",
+            lines[0]
+        );
+        assert!(lines[0].ends_with("restarting"));
+        assert!(
+            lines[1].starts_with("Normal   Pulled"),
+            "Type defaults to Normal"
+        );
+        assert!(!lines[1].contains("(x"), "{}", lines[1]);
+    }
 
-        // Go back to search
-        app.handle_input(key(KeyCode::Esc));
-        assert_eq!(app.view_mode, ViewMode::Search);
+    #[test]
+    fn test_event_lines_newest_first_and_untimed_last() {
+        use crate::k8s::resources::event_lines;
+
+        let lines = event_lines(&events_from_yaml(
+            "
+- {metadata: {}, involvedObject: {}, type: Normal, reason: Untimed, message: a}
+- {metadata: {}, involvedObject: {}, type: Normal, reason: Old, message: b,
+   lastTimestamp: '2020-01-01T00:00:00Z'}
+- {metadata: {}, involvedObject: {}, type: Normal, reason: New, message: c,
+   eventTime: '2020-01-05T00:00:00.000000Z'}
+",
+        ));
+        let reasons: Vec<&str> = lines
+            .iter()
+            .map(|l| l.split_whitespace().nth(1).unwrap())
+            .collect();
+        assert_eq!(reasons, vec!["New", "Old", "Untimed"]);
+        assert!(lines[2].contains("<unknown>"), "{}", lines[2]);
+    }
 
-        // Exit search
-        app.handle_input(key(KeyCode::Esc));
-        assert_eq!(app.view_mode, ViewMode::List);
+    #[test]
+    fn test_last_exit_of_a_crashing_container() {
+        use crate::k8s::resources::last_exit;
+
+        let statuses: Vec<k8s_openapi::api::core::v1::ContainerStatus> = serde_yaml::from_str(
+            "
+- {name: sidecar, image: x, imageID: x, ready: true, restartCount: 1,
+   state: {running: {}},
+   lastState: {terminated: {exitCode: 1, reason: Error}}}
+- {name: app, image: x, imageID: x, ready: false, restartCount: 4,
+   state: {waiting: {reason: CrashLoopBackOff}},
+   lastState: {terminated: {exitCode: 137, reason: OOMKilled}}}
+",
+        )
+        .unwrap();
+        assert_eq!(
+            last_exit(&statuses).as_deref(),
+            Some("OOMKilled: 137"),
+            "Skips running ones"
+        );
+
+        let statuses: Vec<k8s_openapi::api::core::v1::ContainerStatus> = serde_yaml::from_str(
+            "
+- {name: app, image: x, imageID: x, ready: false, restartCount: 1,
+   state: {terminated: {exitCode: 2}},
+   lastState: {terminated: {exitCode: 2}}}
+",
+        )
+        .unwrap();
+        assert_eq!(last_exit(&statuses).as_deref(), Some("Exit: 2"));
+
+        // Waiting for the first time: nothing has exited yet
+        let statuses: Vec<k8s_openapi::api::core::v1::ContainerStatus> = serde_yaml::from_str(
+            "
+- {name: app, image: x, imageID: x, ready: false, restartCount: 0,
+   state: {waiting: {reason: ContainerCreating}}}
+",
+        )
+        .unwrap();
+        assert_eq!(last_exit(&statuses), None);
+        assert_eq!(last_exit(&[]), None);
+    }
+
+    #[test]
+    fn test_label_selector_translates_match_expressions() {
+        use crate::k8s::logs::label_selector_string;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+
+        let selector: LabelSelector = serde_yaml::from_str(
+            "
+matchLabels: {app: web}
+matchExpressions:
+- {key: tier, operator: In, values: [front, edge]}
+- {key: env, operator: NotIn, values: [dev]}
+- {key: team, operator: Exists}
+- {key: canary, operator: DoesNotExist}
+",
+        )
+        .unwrap();
+        assert_eq!(
+            label_selector_string(&selector).unwrap(),
+            "app=web,tier in (front,edge),env notin (dev),team,!canary"
+        );
+
+        // Only expressions still select a subset of the namespace
+        let selector: LabelSelector =
+            serde_yaml::from_str("matchExpressions: [{key: app, operator: Exists}]").unwrap();
+        assert_eq!(label_selector_string(&selector).unwrap(), "app");
+
+        let selector: LabelSelector =
+            serde_yaml::from_str("matchExpressions: [{key: app, operator: Gt, values: ['1']}]")
+                .unwrap();
+        let err = label_selector_string(&selector).unwrap_err().to_string();
+        assert!(err.contains("Gt"), "{}", err);
+        assert_eq!(
+            label_selector_string(&LabelSelector::default()).unwrap(),
+            ""
+        );
     }
 }
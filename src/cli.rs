@@ -0,0 +1,73 @@
+use anyhow::{bail, Context, Result};
+
+use crate::types::ResourceType;
+
+pub const USAGE: &str = "\
+Usage: kterm [--context NAME] [--namespace NAME] [--resource TYPE]
+
+  --context NAME     Start on this kubeconfig context instead of the current one
+  --namespace NAME   Start in this namespace instead of the context's default
+  --resource TYPE    Start on this resource type, e.g. pods or deployment
+  -h, --help         Print this help";
+
+/// Where to start, from the command line. Unset fields keep the kubeconfig's
+/// current context and namespace and the type listed last time.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub context: Option<String>,
+    pub namespace: Option<String>,
+    pub resource: Option<ResourceType>,
+    pub help: bool,
+}
+
+/// Parse the arguments after the program name. Flags take their value as
+/// the next argument or after `=`.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+        if flag == "-h" || flag == "--help" {
+            parsed.help = true;
+            continue;
+        }
+        if !matches!(flag.as_str(), "--context" | "--namespace" | "--resource") {
+            bail!("unknown argument '{}'\n\n{}", arg, USAGE);
+        }
+        let value = match inline {
+            Some(value) => value.to_string(),
+            None => args
+                .next()
+                .with_context(|| format!("{} needs a value", flag))?,
+        };
+        if value.is_empty() {
+            bail!("{} needs a value", flag);
+        }
+        match flag.as_str() {
+            "--context" => parsed.context = Some(value),
+            "--namespace" => parsed.namespace = Some(value),
+            _ => {
+                let resource = ResourceType::from_name(&value).with_context(|| {
+                    format!(
+                        "unknown resource type '{}', expected one of: {}",
+                        value,
+                        type_names()
+                    )
+                })?;
+                parsed.resource = Some(resource);
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+fn type_names() -> String {
+    ResourceType::ALL
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,104 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Settings read from `$XDG_CONFIG_HOME/kterm/config.yaml` (or
+/// `~/.config/kterm/config.yaml`). Every section is optional.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub theme: ThemeConfig,
+    /// `j`/`k` past the last/first row wraps around (the default) instead
+    /// of stopping there.
+    pub wrap_navigation: bool,
+    /// Seconds a describe, log stream or search of one context may wait on
+    /// the API server before giving up with an error.
+    pub load_timeout_secs: u64,
+    /// Contexts a search (or the context selector's reachability check)
+    /// talks to at once.
+    pub search_concurrency: usize,
+    /// Context name fragments (e.g. `prod`) that put kterm in danger mode:
+    /// red borders, and delete, restart and edit ask for `y` twice.
+    pub danger_contexts: Vec<String>,
+    /// What to do with ANSI color codes apps write into their logs.
+    pub log_ansi: LogAnsi,
+}
+
+/// `render` shows ANSI-colored log lines in their colors, `strip` drops
+/// the escape codes, `raw` leaves them in the text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogAnsi {
+    #[default]
+    Render,
+    Strip,
+    Raw,
+}
+
+pub const DEFAULT_LOAD_TIMEOUT_SECS: u64 = 30;
+pub const DEFAULT_SEARCH_CONCURRENCY: usize = 5;
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: ThemeConfig::default(),
+            wrap_navigation: true,
+            load_timeout_secs: DEFAULT_LOAD_TIMEOUT_SECS,
+            search_concurrency: DEFAULT_SEARCH_CONCURRENCY,
+            danger_contexts: Vec::new(),
+            log_ansi: LogAnsi::default(),
+        }
+    }
+}
+
+/// Color overrides by name (`cyan`, `darkgray`, `#aabbcc`, `42`, ...).
+/// Unset entries keep the default palette.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub border_focused: Option<String>,
+    pub border_unfocused: Option<String>,
+    pub header: Option<String>,
+    pub highlight: Option<String>,
+    pub status: StatusColors,
+}
+
+/// Colors for the STATUS column, grouped like `status_style` groups them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct StatusColors {
+    pub ok: Option<String>,
+    pub pending: Option<String>,
+    pub error: Option<String>,
+    pub terminating: Option<String>,
+    pub done: Option<String>,
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(base.join("kterm").join("config.yaml"))
+}
+
+/// Load the config file. A missing file is the same as an empty one.
+pub fn load() -> Result<Config> {
+    let Some(path) = config_path() else {
+        return Ok(Config::default());
+    };
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    parse(&text).with_context(|| format!("Invalid config {}", path.display()))
+}
+
+pub fn parse(text: &str) -> Result<Config> {
+    if text.trim().is_empty() {
+        return Ok(Config::default());
+    }
+    Ok(serde_yaml::from_str(text)?)
+}
@@ -1,20 +1,46 @@
-use crossterm::event::{EventStream, KeyEvent};
+use crossterm::event::{EventStream, KeyEvent, MouseEvent};
 use futures::StreamExt;
 use tokio::sync::mpsc;
 
 use std::collections::HashMap;
 
-use crate::types::{ResourceItem, ResourceType};
+use crate::types::{
+    ContextHealth, DetailTarget, PodMetrics, PortForward, ResourceItem, ResourceType,
+};
 
 #[derive(Debug)]
 pub enum AppEvent {
     Key(KeyEvent),
+    Mouse(MouseEvent),
     #[allow(dead_code)]
     Resize(u16, u16),
     Tick,
     ResourcesUpdated(Vec<ResourceItem>),
-    NamespacesLoaded(Vec<String>),
-    DetailLoaded(String),
+    /// The resource watch hit a transient error and is restarting.
+    WatchReconnecting,
+    /// Namespaces of the current context, with its kubeconfig default
+    /// namespace when known. `listed` is false when listing failed and
+    /// `namespaces` is only the fallback.
+    NamespacesLoaded {
+        namespaces: Vec<String>,
+        preferred: Option<String>,
+        listed: bool,
+    },
+    /// Description of `target`, for the detail view if it still shows it.
+    DetailLoaded {
+        target: DetailTarget,
+        text: String,
+    },
+    /// Describing `target` failed.
+    DetailFailed {
+        target: DetailTarget,
+        message: String,
+    },
+    /// YAML of the described resource, fetched for the YAML view.
+    YamlLoaded(String),
+    DeletePreviewLoaded(Vec<String>),
+    /// Result of a server-side dry run of the pending edit.
+    EditDryRun(Result<(), String>),
     LogLine(String),
     LogStreamEnded,
     ContextsLoaded {
@@ -22,14 +48,37 @@ pub enum AppEvent {
         current: String,
         current_namespace: String,
     },
+    /// API server URL of the context just connected to.
+    ServerUrlLoaded(String),
     K8sError(String),
+    /// A background action worked ("Deleted 3 resources").
+    K8sInfo(String),
+    /// A value given on the command line doesn't exist in the cluster;
+    /// kterm exits with this message.
+    StartupFailed(String),
     SearchResultsBatch {
         context: String,
         resource_type: ResourceType,
         items: Vec<ResourceItem>,
     },
     ResourceCountsLoaded(HashMap<ResourceType, usize>),
+    PodMetricsUpdated(PodMetrics),
     SearchScanComplete(String),
+    /// A search could not connect to a context.
+    SearchContextFailed {
+        context: String,
+        error: String,
+    },
+    /// Result of a background reachability probe of a context.
+    ContextProbed {
+        context: String,
+        health: ContextHealth,
+    },
+    PortForwardStarted(PortForward),
+    PortForwardFailed {
+        forward: PortForward,
+        error: String,
+    },
 }
 
 pub struct EventHandler {
@@ -71,15 +120,20 @@ impl EventHandler {
             let mut reader = EventStream::new();
             loop {
                 match reader.next().await {
-                    Some(Ok(crossterm::event::Event::Key(key))) => {
-                        if tx.send(AppEvent::Key(key)).is_err() {
-                            break;
-                        }
+                    Some(Ok(crossterm::event::Event::Key(key)))
+                        if tx.send(AppEvent::Key(key)).is_err() =>
+                    {
+                        break;
+                    }
+                    Some(Ok(crossterm::event::Event::Mouse(mouse)))
+                        if tx.send(AppEvent::Mouse(mouse)).is_err() =>
+                    {
+                        break;
                     }
-                    Some(Ok(crossterm::event::Event::Resize(w, h))) => {
-                        if tx.send(AppEvent::Resize(w, h)).is_err() {
-                            break;
-                        }
+                    Some(Ok(crossterm::event::Event::Resize(w, h)))
+                        if tx.send(AppEvent::Resize(w, h)).is_err() =>
+                    {
+                        break;
                     }
                     Some(Err(_)) => break,
                     _ => {}
@@ -92,7 +146,7 @@ impl EventHandler {
         let mut kept = Vec::new();
         while let Ok(event) = self.rx.try_recv() {
             match event {
-                AppEvent::Key(_) | AppEvent::Resize(_, _) => {
+                AppEvent::Key(_) | AppEvent::Mouse(_) | AppEvent::Resize(_, _) => {
                     // Discard stale terminal input events
                 }
                 other => kept.push(other),
@@ -103,7 +157,7 @@ impl EventHandler {
         }
     }
 
-    /// Suspend the crossterm reader task and drain any stale key/resize
+    /// Suspend the crossterm reader task and drain any stale key/mouse/resize
     /// events from the channel. Call this before launching a subprocess
     /// that needs stdin.
     pub fn suspend(&mut self) {
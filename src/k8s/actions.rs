@@ -10,7 +10,8 @@ use k8s_openapi::api::core::v1::{
 use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
 use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use k8s_openapi::api::storage::v1::StorageClass;
-use kube::api::{DeleteParams, Patch, PatchParams};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
 use kube::{Api, Client};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -23,84 +24,88 @@ pub async fn delete_resource(
     namespace: &str,
     name: &str,
     resource_type: ResourceType,
+    force: bool,
 ) -> Result<()> {
+    let dp = delete_params(force);
     match resource_type {
-        ResourceType::Pods => {
-            delete_namespaced::<Pod>(client, namespace, name, "pod").await
-        }
+        ResourceType::Pods => delete_namespaced::<Pod>(client, namespace, name, &dp, "pod").await,
         ResourceType::Deployments => {
-            delete_namespaced::<Deployment>(client, namespace, name, "Deployment").await
+            delete_namespaced::<Deployment>(client, namespace, name, &dp, "Deployment").await
         }
         ResourceType::StatefulSets => {
-            delete_namespaced::<StatefulSet>(client, namespace, name, "StatefulSet").await
+            delete_namespaced::<StatefulSet>(client, namespace, name, &dp, "StatefulSet").await
         }
         ResourceType::DaemonSets => {
-            delete_namespaced::<DaemonSet>(client, namespace, name, "DaemonSet").await
+            delete_namespaced::<DaemonSet>(client, namespace, name, &dp, "DaemonSet").await
         }
         ResourceType::ReplicaSets => {
-            delete_namespaced::<ReplicaSet>(client, namespace, name, "ReplicaSet").await
+            delete_namespaced::<ReplicaSet>(client, namespace, name, &dp, "ReplicaSet").await
         }
         ResourceType::ReplicationControllers => {
-            delete_namespaced::<ReplicationController>(client, namespace, name, "ReplicationController").await
-        }
-        ResourceType::Jobs => {
-            delete_namespaced::<Job>(client, namespace, name, "Job").await
+            delete_namespaced::<ReplicationController>(
+                client,
+                namespace,
+                name,
+                &dp,
+                "ReplicationController",
+            )
+            .await
         }
+        ResourceType::Jobs => delete_namespaced::<Job>(client, namespace, name, &dp, "Job").await,
         ResourceType::CronJobs => {
-            delete_namespaced::<CronJob>(client, namespace, name, "CronJob").await
+            delete_namespaced::<CronJob>(client, namespace, name, &dp, "CronJob").await
         }
         ResourceType::HorizontalPodAutoscalers => {
-            delete_namespaced::<HorizontalPodAutoscaler>(client, namespace, name, "HPA").await
+            delete_namespaced::<HorizontalPodAutoscaler>(client, namespace, name, &dp, "HPA").await
         }
         ResourceType::Services => {
-            delete_namespaced::<Service>(client, namespace, name, "Service").await
+            delete_namespaced::<Service>(client, namespace, name, &dp, "Service").await
         }
         ResourceType::Endpoints => {
-            delete_namespaced::<Endpoints>(client, namespace, name, "Endpoints").await
+            delete_namespaced::<Endpoints>(client, namespace, name, &dp, "Endpoints").await
         }
         ResourceType::Ingresses => {
-            delete_namespaced::<Ingress>(client, namespace, name, "Ingress").await
+            delete_namespaced::<Ingress>(client, namespace, name, &dp, "Ingress").await
         }
         ResourceType::NetworkPolicies => {
-            delete_namespaced::<NetworkPolicy>(client, namespace, name, "NetworkPolicy").await
+            delete_namespaced::<NetworkPolicy>(client, namespace, name, &dp, "NetworkPolicy").await
         }
         ResourceType::ConfigMaps => {
-            delete_namespaced::<ConfigMap>(client, namespace, name, "ConfigMap").await
+            delete_namespaced::<ConfigMap>(client, namespace, name, &dp, "ConfigMap").await
         }
         ResourceType::Secrets => {
-            delete_namespaced::<Secret>(client, namespace, name, "Secret").await
+            delete_namespaced::<Secret>(client, namespace, name, &dp, "Secret").await
         }
         ResourceType::PersistentVolumeClaims => {
-            delete_namespaced::<PersistentVolumeClaim>(client, namespace, name, "PVC").await
+            delete_namespaced::<PersistentVolumeClaim>(client, namespace, name, &dp, "PVC").await
         }
         ResourceType::ServiceAccounts => {
-            delete_namespaced::<ServiceAccount>(client, namespace, name, "ServiceAccount").await
+            delete_namespaced::<ServiceAccount>(client, namespace, name, &dp, "ServiceAccount")
+                .await
         }
         ResourceType::Events => {
-            delete_namespaced::<Event>(client, namespace, name, "Event").await
+            delete_namespaced::<Event>(client, namespace, name, &dp, "Event").await
         }
         ResourceType::ResourceQuotas => {
-            delete_namespaced::<ResourceQuota>(client, namespace, name, "ResourceQuota").await
+            delete_namespaced::<ResourceQuota>(client, namespace, name, &dp, "ResourceQuota").await
         }
         ResourceType::LimitRanges => {
-            delete_namespaced::<LimitRange>(client, namespace, name, "LimitRange").await
+            delete_namespaced::<LimitRange>(client, namespace, name, &dp, "LimitRange").await
         }
         ResourceType::PodDisruptionBudgets => {
-            delete_namespaced::<PodDisruptionBudget>(client, namespace, name, "PDB").await
+            delete_namespaced::<PodDisruptionBudget>(client, namespace, name, &dp, "PDB").await
         }
         // Cluster-scoped
         ResourceType::PersistentVolumes => {
-            delete_cluster::<PersistentVolume>(client, name, "PersistentVolume").await
+            delete_cluster::<PersistentVolume>(client, name, &dp, "PersistentVolume").await
         }
         ResourceType::StorageClasses => {
-            delete_cluster::<StorageClass>(client, name, "StorageClass").await
+            delete_cluster::<StorageClass>(client, name, &dp, "StorageClass").await
         }
         ResourceType::Namespaces => {
-            delete_cluster::<Namespace>(client, name, "Namespace").await
-        }
-        ResourceType::Nodes => {
-            delete_cluster::<Node>(client, name, "Node").await
+            delete_cluster::<Namespace>(client, name, &dp, "Namespace").await
         }
+        ResourceType::Nodes => delete_cluster::<Node>(client, name, &dp, "Node").await,
     }
 }
 
@@ -134,113 +139,413 @@ pub async fn restart_resource(
     Ok(())
 }
 
-pub async fn apply_yaml(
+pub async fn scale_resource(
     client: Client,
     namespace: &str,
     name: &str,
     resource_type: ResourceType,
+    replicas: i32,
+) -> Result<()> {
+    match resource_type {
+        ResourceType::Deployments => {
+            patch_replicas::<Deployment>(client, namespace, name, replicas, "Deployment").await
+        }
+        ResourceType::StatefulSets => {
+            patch_replicas::<StatefulSet>(client, namespace, name, replicas, "StatefulSet").await
+        }
+        _ => anyhow::bail!("{} resources cannot be scaled", resource_type),
+    }
+}
+
+/// Replace the resource `target` with `yaml_str`, or create the one it
+/// describes when `target` is `None`. With `dry_run` the API server
+/// validates the change (schema, admission webhooks) without persisting it.
+pub async fn apply_yaml(
+    client: Client,
+    namespace: &str,
+    target: Option<&str>,
+    resource_type: ResourceType,
     yaml_str: &str,
+    dry_run: bool,
 ) -> Result<()> {
     match resource_type {
         ResourceType::Pods => {
-            apply_namespaced::<Pod>(client, namespace, name, yaml_str, "Pod").await
+            apply_namespaced::<Pod>(client, namespace, target, yaml_str, dry_run, "Pod").await
         }
         ResourceType::Deployments => {
-            apply_namespaced::<Deployment>(client, namespace, name, yaml_str, "Deployment").await
+            apply_namespaced::<Deployment>(
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "Deployment",
+            )
+            .await
         }
         ResourceType::StatefulSets => {
-            apply_namespaced::<StatefulSet>(client, namespace, name, yaml_str, "StatefulSet").await
+            apply_namespaced::<StatefulSet>(
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "StatefulSet",
+            )
+            .await
         }
         ResourceType::DaemonSets => {
-            apply_namespaced::<DaemonSet>(client, namespace, name, yaml_str, "DaemonSet").await
+            apply_namespaced::<DaemonSet>(client, namespace, target, yaml_str, dry_run, "DaemonSet")
+                .await
         }
         ResourceType::ReplicaSets => {
-            apply_namespaced::<ReplicaSet>(client, namespace, name, yaml_str, "ReplicaSet").await
+            apply_namespaced::<ReplicaSet>(
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "ReplicaSet",
+            )
+            .await
         }
         ResourceType::ReplicationControllers => {
             apply_namespaced::<ReplicationController>(
-                client, namespace, name, yaml_str, "ReplicationController",
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "ReplicationController",
             )
             .await
         }
         ResourceType::Jobs => {
-            apply_namespaced::<Job>(client, namespace, name, yaml_str, "Job").await
+            apply_namespaced::<Job>(client, namespace, target, yaml_str, dry_run, "Job").await
         }
         ResourceType::CronJobs => {
-            apply_namespaced::<CronJob>(client, namespace, name, yaml_str, "CronJob").await
+            apply_namespaced::<CronJob>(client, namespace, target, yaml_str, dry_run, "CronJob")
+                .await
         }
         ResourceType::HorizontalPodAutoscalers => {
             apply_namespaced::<HorizontalPodAutoscaler>(
-                client, namespace, name, yaml_str, "HPA",
+                client, namespace, target, yaml_str, dry_run, "HPA",
             )
             .await
         }
         ResourceType::Services => {
-            apply_namespaced::<Service>(client, namespace, name, yaml_str, "Service").await
+            apply_namespaced::<Service>(client, namespace, target, yaml_str, dry_run, "Service")
+                .await
         }
         ResourceType::Endpoints => {
-            apply_namespaced::<Endpoints>(client, namespace, name, yaml_str, "Endpoints").await
+            apply_namespaced::<Endpoints>(client, namespace, target, yaml_str, dry_run, "Endpoints")
+                .await
         }
         ResourceType::Ingresses => {
-            apply_namespaced::<Ingress>(client, namespace, name, yaml_str, "Ingress").await
+            apply_namespaced::<Ingress>(client, namespace, target, yaml_str, dry_run, "Ingress")
+                .await
         }
         ResourceType::NetworkPolicies => {
-            apply_namespaced::<NetworkPolicy>(client, namespace, name, yaml_str, "NetworkPolicy")
-                .await
+            apply_namespaced::<NetworkPolicy>(
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "NetworkPolicy",
+            )
+            .await
         }
         ResourceType::ConfigMaps => {
-            apply_namespaced::<ConfigMap>(client, namespace, name, yaml_str, "ConfigMap").await
+            apply_namespaced::<ConfigMap>(client, namespace, target, yaml_str, dry_run, "ConfigMap")
+                .await
         }
         ResourceType::Secrets => {
-            apply_namespaced::<Secret>(client, namespace, name, yaml_str, "Secret").await
+            apply_namespaced::<Secret>(client, namespace, target, yaml_str, dry_run, "Secret").await
         }
         ResourceType::PersistentVolumeClaims => {
-            apply_namespaced::<PersistentVolumeClaim>(client, namespace, name, yaml_str, "PVC")
-                .await
+            apply_namespaced::<PersistentVolumeClaim>(
+                client, namespace, target, yaml_str, dry_run, "PVC",
+            )
+            .await
         }
         ResourceType::ServiceAccounts => {
             apply_namespaced::<ServiceAccount>(
-                client, namespace, name, yaml_str, "ServiceAccount",
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "ServiceAccount",
             )
             .await
         }
         ResourceType::Events => {
-            apply_namespaced::<Event>(client, namespace, name, yaml_str, "Event").await
+            apply_namespaced::<Event>(client, namespace, target, yaml_str, dry_run, "Event").await
         }
         ResourceType::ResourceQuotas => {
-            apply_namespaced::<ResourceQuota>(client, namespace, name, yaml_str, "ResourceQuota")
-                .await
+            apply_namespaced::<ResourceQuota>(
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "ResourceQuota",
+            )
+            .await
         }
         ResourceType::LimitRanges => {
-            apply_namespaced::<LimitRange>(client, namespace, name, yaml_str, "LimitRange").await
+            apply_namespaced::<LimitRange>(
+                client,
+                namespace,
+                target,
+                yaml_str,
+                dry_run,
+                "LimitRange",
+            )
+            .await
         }
         ResourceType::PodDisruptionBudgets => {
-            apply_namespaced::<PodDisruptionBudget>(client, namespace, name, yaml_str, "PDB").await
+            apply_namespaced::<PodDisruptionBudget>(
+                client, namespace, target, yaml_str, dry_run, "PDB",
+            )
+            .await
         }
         // Cluster-scoped
         ResourceType::PersistentVolumes => {
-            apply_cluster::<PersistentVolume>(client, name, yaml_str, "PersistentVolume").await
+            apply_cluster::<PersistentVolume>(client, target, yaml_str, dry_run, "PersistentVolume")
+                .await
         }
         ResourceType::StorageClasses => {
-            apply_cluster::<StorageClass>(client, name, yaml_str, "StorageClass").await
+            apply_cluster::<StorageClass>(client, target, yaml_str, dry_run, "StorageClass").await
         }
         ResourceType::Namespaces => {
-            apply_cluster::<Namespace>(client, name, yaml_str, "Namespace").await
+            apply_cluster::<Namespace>(client, target, yaml_str, dry_run, "Namespace").await
         }
         ResourceType::Nodes => {
-            apply_cluster::<Node>(client, name, yaml_str, "Node").await
+            apply_cluster::<Node>(client, target, yaml_str, dry_run, "Node").await
         }
     }
 }
 
+/// Maximum number of names listed per group in a delete preview.
+const PREVIEW_MAX_NAMES: usize = 8;
+
+/// Enumerates the child resources that a cascading delete of the given
+/// workload would remove, without deleting anything. Returns lines for the
+/// confirm dialog; empty for types that have no dependents.
+pub async fn delete_preview(
+    client: Client,
+    namespace: &str,
+    name: &str,
+    resource_type: ResourceType,
+) -> Result<Vec<String>> {
+    match resource_type {
+        ResourceType::StatefulSets => statefulset_delete_preview(client, namespace, name).await,
+        ResourceType::Deployments => deployment_delete_preview(client, namespace, name).await,
+        _ => Ok(Vec::new()),
+    }
+}
+
+async fn statefulset_delete_preview(
+    client: Client,
+    namespace: &str,
+    name: &str,
+) -> Result<Vec<String>> {
+    let ss_api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let ss = ss_api
+        .get(name)
+        .await
+        .context("Failed to get StatefulSet")?;
+    let uid = ss.metadata.uid.clone().unwrap_or_default();
+    let spec = ss.spec.as_ref();
+
+    let pod_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let lp = selector_params(spec.map(|s| &s.selector));
+    let pods: Vec<String> = pod_api
+        .list(&lp)
+        .await
+        .context("Failed to list pods")?
+        .items
+        .iter()
+        .filter(|p| is_owned_by(&p.metadata, &uid))
+        .filter_map(|p| p.metadata.name.clone())
+        .collect();
+
+    let mut lines = preview_group("Pods", &pods);
+
+    // PVCs from volumeClaimTemplates are named <template>-<statefulset>-<ordinal>
+    let prefixes: Vec<String> = spec
+        .and_then(|s| s.volume_claim_templates.as_ref())
+        .map(|templates| {
+            templates
+                .iter()
+                .filter_map(|t| t.metadata.name.as_ref())
+                .map(|t| format!("{}-{}-", t, name))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !prefixes.is_empty() {
+        let pvc_api: Api<PersistentVolumeClaim> = Api::namespaced(client, namespace);
+        let pvcs: Vec<String> = pvc_api
+            .list(&ListParams::default())
+            .await
+            .context("Failed to list PVCs")?
+            .items
+            .iter()
+            .filter_map(|p| p.metadata.name.clone())
+            .filter(|n| {
+                prefixes.iter().any(|prefix| {
+                    n.strip_prefix(prefix.as_str())
+                        .is_some_and(|ordinal| ordinal.chars().all(|c| c.is_ascii_digit()))
+                })
+            })
+            .collect();
+
+        let policy = spec
+            .and_then(|s| s.persistent_volume_claim_retention_policy.as_ref())
+            .and_then(|p| p.when_deleted.clone())
+            .unwrap_or_else(|| "Retain".to_string());
+        let note = if policy == "Delete" {
+            "may be deleted"
+        } else {
+            "normally kept"
+        };
+        if !pvcs.is_empty() {
+            lines.push(String::new());
+            lines.push(format!(
+                "PVCs from volumeClaimTemplates ({}) - whenDeleted policy is {}, {}:",
+                pvcs.len(),
+                policy,
+                note
+            ));
+            lines.extend(preview_names(&pvcs));
+        }
+    }
+
+    Ok(lines)
+}
+
+async fn deployment_delete_preview(
+    client: Client,
+    namespace: &str,
+    name: &str,
+) -> Result<Vec<String>> {
+    let deploy_api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let deploy = deploy_api
+        .get(name)
+        .await
+        .context("Failed to get Deployment")?;
+    let uid = deploy.metadata.uid.clone().unwrap_or_default();
+    let lp = selector_params(deploy.spec.as_ref().map(|s| &s.selector));
+
+    let rs_api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+    let replicasets: Vec<ReplicaSet> = rs_api
+        .list(&lp)
+        .await
+        .context("Failed to list ReplicaSets")?
+        .items
+        .into_iter()
+        .filter(|rs| is_owned_by(&rs.metadata, &uid))
+        .collect();
+    let rs_uids: Vec<String> = replicasets
+        .iter()
+        .filter_map(|rs| rs.metadata.uid.clone())
+        .collect();
+    let rs_names: Vec<String> = replicasets
+        .iter()
+        .filter_map(|rs| rs.metadata.name.clone())
+        .collect();
+
+    let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+    let pods: Vec<String> = pod_api
+        .list(&lp)
+        .await
+        .context("Failed to list pods")?
+        .items
+        .iter()
+        .filter(|p| {
+            rs_uids
+                .iter()
+                .any(|rs_uid| is_owned_by(&p.metadata, rs_uid))
+        })
+        .filter_map(|p| p.metadata.name.clone())
+        .collect();
+
+    let mut lines = preview_group("ReplicaSets", &rs_names);
+    lines.push(String::new());
+    lines.extend(preview_group("Pods", &pods));
+    Ok(lines)
+}
+
+fn selector_params(selector: Option<&LabelSelector>) -> ListParams {
+    let labels = selector
+        .and_then(|s| s.match_labels.as_ref())
+        .map(|labels| {
+            labels
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default();
+    if labels.is_empty() {
+        ListParams::default()
+    } else {
+        ListParams::default().labels(&labels)
+    }
+}
+
+fn is_owned_by(meta: &ObjectMeta, owner_uid: &str) -> bool {
+    meta.owner_references
+        .as_ref()
+        .is_some_and(|refs| refs.iter().any(|r| r.uid == owner_uid))
+}
+
+fn preview_group(label: &str, names: &[String]) -> Vec<String> {
+    let mut lines = vec![format!("{} ({}):", label, names.len())];
+    lines.extend(preview_names(names));
+    lines
+}
+
+fn preview_names(names: &[String]) -> Vec<String> {
+    let mut lines: Vec<String> = names
+        .iter()
+        .take(PREVIEW_MAX_NAMES)
+        .map(|n| format!("  {}", n))
+        .collect();
+    if names.len() > PREVIEW_MAX_NAMES {
+        lines.push(format!(
+            "  ... and {} more",
+            names.len() - PREVIEW_MAX_NAMES
+        ));
+    }
+    lines
+}
+
 // ---------------------------------------------------------------------------
 // Generic helpers
 // ---------------------------------------------------------------------------
 
+/// Default delete, or with `force` the API equivalent of
+/// `kubectl delete --force --grace-period=0`: remove the object right away
+/// without waiting for graceful termination.
+fn delete_params(force: bool) -> DeleteParams {
+    if force {
+        DeleteParams {
+            grace_period_seconds: Some(0),
+            ..Default::default()
+        }
+    } else {
+        DeleteParams::default()
+    }
+}
+
 async fn delete_namespaced<T>(
     client: Client,
     namespace: &str,
     name: &str,
+    dp: &DeleteParams,
     label: &str,
 ) -> Result<()>
 where
@@ -253,13 +558,13 @@ where
         + 'static,
 {
     let api: Api<T> = Api::namespaced(client, namespace);
-    api.delete(name, &DeleteParams::default())
+    api.delete(name, dp)
         .await
         .context(format!("Failed to delete {}", label))?;
     Ok(())
 }
 
-async fn delete_cluster<T>(client: Client, name: &str, label: &str) -> Result<()>
+async fn delete_cluster<T>(client: Client, name: &str, dp: &DeleteParams, label: &str) -> Result<()>
 where
     T: kube::Resource<DynamicType = ()>
         + Clone
@@ -270,7 +575,7 @@ where
         + 'static,
 {
     let api: Api<T> = Api::all(client);
-    api.delete(name, &DeleteParams::default())
+    api.delete(name, dp)
         .await
         .context(format!("Failed to delete {}", label))?;
     Ok(())
@@ -317,11 +622,11 @@ where
     Ok(())
 }
 
-async fn apply_namespaced<T>(
+async fn patch_replicas<T>(
     client: Client,
     namespace: &str,
     name: &str,
-    yaml_str: &str,
+    replicas: i32,
     label: &str,
 ) -> Result<()>
 where
@@ -335,18 +640,40 @@ where
         + 'static,
 {
     let api: Api<T> = Api::namespaced(client, namespace);
-    let data: T =
-        serde_yaml::from_str(yaml_str).context(format!("Invalid {} YAML", label))?;
-    api.replace(name, &kube::api::PostParams::default(), &data)
+    let patch = json!({ "spec": { "replicas": replicas } });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
         .await
-        .context(format!("Failed to apply {} YAML", label))?;
+        .context(format!("Failed to scale {}", label))?;
     Ok(())
 }
 
+async fn apply_namespaced<T>(
+    client: Client,
+    namespace: &str,
+    target: Option<&str>,
+    yaml_str: &str,
+    dry_run: bool,
+    label: &str,
+) -> Result<()>
+where
+    T: kube::Resource<DynamicType = (), Scope = kube::core::NamespaceResourceScope>
+        + Clone
+        + DeserializeOwned
+        + Serialize
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'static,
+{
+    let api: Api<T> = Api::namespaced(client, namespace);
+    write_yaml(api, target, yaml_str, dry_run, label).await
+}
+
 async fn apply_cluster<T>(
     client: Client,
-    name: &str,
+    target: Option<&str>,
     yaml_str: &str,
+    dry_run: bool,
     label: &str,
 ) -> Result<()>
 where
@@ -360,10 +687,30 @@ where
         + 'static,
 {
     let api: Api<T> = Api::all(client);
-    let data: T =
-        serde_yaml::from_str(yaml_str).context(format!("Invalid {} YAML", label))?;
-    api.replace(name, &kube::api::PostParams::default(), &data)
-        .await
-        .context(format!("Failed to apply {} YAML", label))?;
-    Ok(())
+    write_yaml(api, target, yaml_str, dry_run, label).await
+}
+
+async fn write_yaml<T>(
+    api: Api<T>,
+    target: Option<&str>,
+    yaml_str: &str,
+    dry_run: bool,
+    label: &str,
+) -> Result<()>
+where
+    T: kube::Resource + Clone + DeserializeOwned + Serialize + std::fmt::Debug,
+{
+    let data: T = serde_yaml::from_str(yaml_str).context(format!("Invalid {} YAML", label))?;
+    let params = kube::api::PostParams {
+        dry_run,
+        ..Default::default()
+    };
+    match target {
+        Some(name) => api.replace(name, &params, &data).await.map(drop),
+        None => api.create(&params, &data).await.map(drop),
+    }
+    .with_context(|| {
+        let verb = if target.is_some() { "apply" } else { "create" };
+        format!("Failed to {} {} YAML", verb, label)
+    })
 }
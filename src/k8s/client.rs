@@ -1,21 +1,26 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use k8s_openapi::api::core::v1::Namespace;
 use kube::api::ListParams;
 use kube::config::{KubeConfigOptions, Kubeconfig};
 use kube::{Api, Client, Config};
 
+/// How long a reachability probe waits for the API server.
+const PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
 pub struct K8sManager {
     kubeconfig: Kubeconfig,
     pub current_context: String,
+    /// API server of the current context, as the kubeconfig gives it.
+    pub server_url: String,
     pub client: Client,
 }
 
 impl K8sManager {
-    pub async fn new() -> Result<Self> {
+    /// Connect to `context`, or the kubeconfig's current context if `None`.
+    pub async fn new(context: Option<String>) -> Result<Self> {
         let kubeconfig = Kubeconfig::read().context("Failed to read kubeconfig")?;
-        let current_context = kubeconfig
-            .current_context
-            .clone()
+        let current_context = context
+            .or_else(|| kubeconfig.current_context.clone())
             .unwrap_or_default();
 
         let config = Config::from_kubeconfig(&KubeConfigOptions {
@@ -25,15 +30,35 @@ impl K8sManager {
         .await
         .context("Failed to create config from kubeconfig")?;
 
+        let server_url = server_url(&config);
         let client = Client::try_from(config).context("Failed to create Kubernetes client")?;
 
         Ok(Self {
             kubeconfig,
             current_context,
+            server_url,
             client,
         })
     }
 
+    /// Fail unless the kubeconfig defines a context named `name`.
+    pub fn check_context(name: &str) -> Result<()> {
+        let kubeconfig = Kubeconfig::read().context("Failed to read kubeconfig")?;
+        let names: Vec<&str> = kubeconfig
+            .contexts
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        if !names.contains(&name) {
+            bail!(
+                "kubeconfig has no context '{}' (contexts: {})",
+                name,
+                names.join(", ")
+            );
+        }
+        Ok(())
+    }
+
     pub fn context_names(&self) -> Vec<String> {
         self.kubeconfig
             .contexts
@@ -62,8 +87,10 @@ impl K8sManager {
         .await
         .context("Failed to create config for context")?;
 
+        let server_url = server_url(&config);
         self.client = Client::try_from(config).context("Failed to create client")?;
         self.current_context = context_name.to_string();
+        self.server_url = server_url;
         Ok(())
     }
 
@@ -79,12 +106,26 @@ impl K8sManager {
         Client::try_from(config).context("Failed to create client for context")
     }
 
+    /// Check that a context's API server answers, with a version request
+    /// that gives up after `PROBE_TIMEOUT`.
+    pub async fn probe_context(context_name: &str) -> Result<()> {
+        let client = Self::client_for_context(context_name).await?;
+        tokio::time::timeout(PROBE_TIMEOUT, client.apiserver_version())
+            .await
+            .context("Timed out")?
+            .context("Failed to reach API server")?;
+        Ok(())
+    }
+
     pub async fn list_namespaces(&self) -> Result<Vec<String>> {
         let ns_api: Api<Namespace> = Api::all(self.client.clone());
-        let ns_list = ns_api
-            .list(&ListParams::default())
-            .await
-            .context("Failed to list namespaces")?;
+        let ns_list = super::errors::retry(|| async {
+            ns_api
+                .list(&ListParams::default())
+                .await
+                .context("Failed to list namespaces")
+        })
+        .await?;
 
         let mut names: Vec<String> = ns_list
             .items
@@ -95,3 +136,12 @@ impl K8sManager {
         Ok(names)
     }
 }
+
+/// `config`'s cluster URL without the `/` path `Uri` adds to a bare host.
+fn server_url(config: &Config) -> String {
+    config
+        .cluster_url
+        .to_string()
+        .trim_end_matches('/')
+        .to_string()
+}
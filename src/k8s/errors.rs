@@ -0,0 +1,116 @@
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::Result;
+use kube::core::Status;
+
+use crate::types::{ResourceType, ALL_NAMESPACES};
+
+/// `"{prefix}: {err}"`, unless the API server refused the request for lack
+/// of RBAC permissions, which reads better as `forbidden` explains it than
+/// as the raw 403 status.
+pub fn explain(
+    prefix: &str,
+    err: &anyhow::Error,
+    verb: &str,
+    resource_type: ResourceType,
+    name: Option<&str>,
+    namespace: &str,
+) -> String {
+    forbidden(err, verb, resource_type, name, namespace)
+        .unwrap_or_else(|| format!("{}: {}", prefix, err))
+}
+
+/// "Forbidden: you don't have permission to delete pods/web-0 in namespace
+/// prod" when `err` is (or was caused by) a 403 from the API server.
+pub fn forbidden(
+    err: &anyhow::Error,
+    verb: &str,
+    resource_type: ResourceType,
+    name: Option<&str>,
+    namespace: &str,
+) -> Option<String> {
+    if !is_forbidden(err) {
+        return None;
+    }
+    let target = match name {
+        Some(name) => format!("{}/{}", resource_type, name),
+        None => resource_type.to_string(),
+    };
+    let scope = if resource_type.is_cluster_scoped() {
+        String::new()
+    } else if namespace == ALL_NAMESPACES {
+        " across all namespaces".to_string()
+    } else {
+        format!(" in namespace {}", namespace)
+    };
+    Some(format!(
+        "Forbidden: you don't have permission to {} {}{}",
+        verb, target, scope
+    ))
+}
+
+/// Client errors carry the status as `kube::Error::Api`; watch errors can
+/// also carry it bare.
+fn is_forbidden(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        if let Some(kube::Error::Api(status)) = cause.downcast_ref::<kube::Error>() {
+            return status.is_forbidden();
+        }
+        cause
+            .downcast_ref::<Box<Status>>()
+            .is_some_and(|status| status.is_forbidden())
+            || cause
+                .downcast_ref::<Status>()
+                .is_some_and(Status::is_forbidden)
+    })
+}
+
+/// The error for a request that took longer than `limit`, `what` saying
+/// what was being done, e.g. "listing pods".
+pub fn timed_out(limit: Duration, what: &str) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Timed out after {}s {}; the API server may be unreachable",
+        limit.as_secs(),
+        what
+    )
+}
+
+/// Attempts `retry` makes, counting the first.
+const RETRY_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry, doubled before each further one.
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Run a one-shot read, trying again after a short backoff while it fails
+/// in a way that may clear up on its own (see `is_transient`). Only for
+/// idempotent requests: an attempt whose response was lost is repeated.
+pub async fn retry<T, F, Fut>(mut attempt: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut backoff = RETRY_BACKOFF;
+    for _ in 1..RETRY_ATTEMPTS {
+        match attempt().await {
+            Err(e) if is_transient(&e) => {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+    attempt().await
+}
+
+/// Dropped connections, client-side timeouts, throttling (429) and server
+/// errors (5xx) are worth retrying; a request the API server refused, like
+/// a 403 or 404, will be refused again.
+pub fn is_transient(err: &anyhow::Error) -> bool {
+    err.chain()
+        .any(|cause| match cause.downcast_ref::<kube::Error>() {
+            Some(kube::Error::Api(status)) => status.code == 429 || status.code >= 500,
+            Some(kube::Error::HyperError(_) | kube::Error::Service(_)) => true,
+            _ => false,
+        })
+}
@@ -1,46 +1,370 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
 use anyhow::{Context, Result};
 use futures::AsyncBufReadExt;
-use futures::TryStreamExt;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
 use k8s_openapi::api::core::v1::Pod;
-use kube::api::LogParams;
-use kube::{Api, Client};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::jiff::Timestamp;
+use kube::api::{ListParams, LogParams};
+use kube::{Api, Client, ResourceExt};
 use tokio::sync::mpsc;
+use tokio::task::{AbortHandle, JoinSet};
 
+use super::errors::timed_out;
 use crate::event::AppEvent;
+use crate::types::{LogOptions, ResourceType};
+
+/// How often a workload's pods are re-listed to follow a rollout.
+const WORKLOAD_POD_POLL: Duration = Duration::from_secs(5);
+
+/// Annotation naming the container `kubectl logs` picks when none is given.
+const DEFAULT_CONTAINER_ANNOTATION: &str = "kubectl.kubernetes.io/default-container";
 
+/// Stream a pod's logs with the given options.
 pub async fn stream_pod_logs(
     client: Client,
     namespace: &str,
     pod_name: &str,
     container: Option<&str>,
+    options: LogOptions,
     tx: mpsc::UnboundedSender<AppEvent>,
 ) -> Result<()> {
     let api: Api<Pod> = Api::namespaced(client, namespace);
+    forward_pod_logs(&api, pod_name, container, options, None, None, &tx).await?;
+    let _ = tx.send(AppEvent::LogStreamEnded);
+    Ok(())
+}
+
+/// Stream the logs of every pod selected by a Deployment, StatefulSet or
+/// DaemonSet into one buffer, prefixing each line with `[short-name]`. Pods
+/// are re-listed periodically so new pods of a rollout join and deleted ones
+/// drop out.
+pub async fn stream_workload_logs(
+    client: Client,
+    namespace: &str,
+    resource_type: ResourceType,
+    name: &str,
+    container: Option<&str>,
+    options: LogOptions,
+    tx: mpsc::UnboundedSender<AppEvent>,
+) -> Result<()> {
+    let selector = tokio::time::timeout(
+        options.open_timeout,
+        workload_selector(client.clone(), namespace, resource_type, name),
+    )
+    .await
+    .map_err(|_| timed_out(options.open_timeout, "reading the workload's selector"))??;
+    let pods = PodSet {
+        namespace: Some(namespace),
+        selector: &selector,
+        workload: Some(name),
+    };
+    stream_pod_set_logs(client, pods, container, options, tx).await
+}
+
+/// Stream the logs of every pod matching a label selector, like `stern`:
+/// lines are prefixed with `[pod]` (`[namespace/pod]` across all
+/// namespaces, `namespace` None), and pods that appear later join the
+/// stream on the next re-list. Each pod streams its default container.
+pub async fn stream_selector_logs(
+    client: Client,
+    namespace: Option<&str>,
+    selector: &str,
+    options: LogOptions,
+    tx: mpsc::UnboundedSender<AppEvent>,
+) -> Result<()> {
+    let pods = PodSet {
+        namespace,
+        selector,
+        workload: None,
+    };
+    stream_pod_set_logs(client, pods, None, options, tx).await
+}
+
+/// Pods whose logs are merged into one stream.
+struct PodSet<'a> {
+    namespace: Option<&'a str>, // None = every namespace
+    selector: &'a str,
+    workload: Option<&'a str>, // owner name stripped from the prefixes
+}
+
+/// Re-list the pods of `pods` every `WORKLOAD_POD_POLL` and keep one log
+/// stream per running pod, so new pods of a rollout join and deleted ones
+/// drop out. A stream that ends (its container exited) is reopened from
+/// its last line on the next poll, so a restarted container keeps
+/// streaming without repeating what was already shown.
+async fn stream_pod_set_logs(
+    client: Client,
+    pods: PodSet<'_>,
+    container: Option<&str>,
+    options: LogOptions,
+    tx: mpsc::UnboundedSender<AppEvent>,
+) -> Result<()> {
+    let list_api: Api<Pod> = match pods.namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+    let lp = ListParams::default().labels(pods.selector);
+
+    // Dropping the JoinSet (when this task is aborted) aborts every pod stream
+    let mut streams = JoinSet::new();
+    let mut active: HashMap<String, AbortHandle> = HashMap::new();
+    let mut resume: HashMap<String, Timestamp> = HashMap::new(); // when each pod last logged
+    let mut reported: HashSet<String> = HashSet::new(); // pods whose stream error was shown
+    let mut first = true;
+
+    loop {
+        let listed = tokio::time::timeout(options.open_timeout, list_api.list(&lp))
+            .await
+            .map_err(|_| timed_out(options.open_timeout, "listing pods"))?
+            .context("Failed to list pods")?;
+        if first && listed.items.is_empty() {
+            let _ = tx.send(AppEvent::LogStreamEnded);
+        }
+        first = false;
+
+        let current: Vec<(String, String, Option<String>)> = listed
+            .items
+            .iter()
+            // Pending pods have no logs yet; pick them up on a later poll
+            .filter(|p| p.status.as_ref().and_then(|s| s.phase.as_deref()) != Some("Pending"))
+            .map(|p| {
+                // Pods of a selector needn't share containers, and the API
+                // refuses to pick one of several
+                let container = container.map(String::from).or_else(|| default_container(p));
+                (p.namespace().unwrap_or_default(), p.name_any(), container)
+            })
+            .collect();
+        // Streams are keyed by "namespace/pod"
+        let keys: Vec<String> = current
+            .iter()
+            .map(|(ns, pod, _)| format!("{}/{}", ns, pod))
+            .collect();
+        active.retain(|pod_key, handle| {
+            let keep = keys.contains(pod_key);
+            if !keep {
+                handle.abort();
+            }
+            keep
+        });
+        resume.retain(|pod_key, _| keys.contains(pod_key));
+        reported.retain(|pod_key| keys.contains(pod_key));
+
+        for ((ns, pod, container), pod_key) in current.iter().zip(keys) {
+            if active.contains_key(&pod_key) {
+                continue;
+            }
+            let api: Api<Pod> = Api::namespaced(client.clone(), ns);
+            let container = container.clone();
+            let prefix = match (pods.workload, pods.namespace) {
+                (Some(workload), _) => short_pod_name(workload, pod).to_string(),
+                (None, Some(_)) => pod.clone(),
+                (None, None) => pod_key.clone(),
+            };
+            let tx = tx.clone();
+            let pod_name = pod.clone();
+            let since = resume.get(&pod_key).copied();
+            let task_key = pod_key.clone();
+            let handle = streams.spawn(async move {
+                let result = forward_pod_logs(
+                    &api,
+                    &pod_name,
+                    container.as_deref(),
+                    options,
+                    Some(&prefix),
+                    since,
+                    &tx,
+                )
+                .await;
+                (task_key, pod_name, result)
+            });
+            active.insert(pod_key, handle);
+        }
 
+        // Reap finished streams so their pods are reopened on the next
+        // poll. Previous logs don't change, so those stay finished; a pod
+        // whose stream keeps failing reports the error once
+        while let Some(joined) = streams.try_join_next() {
+            let Ok((pod_key, pod_name, result)) = joined else {
+                continue;
+            };
+            if options.previous {
+                continue;
+            }
+            match result {
+                Ok(last) => {
+                    reported.remove(&pod_key);
+                    if let Some(last) = last {
+                        resume.insert(pod_key.clone(), last);
+                    }
+                }
+                Err(e) => {
+                    if reported.insert(pod_key.clone()) {
+                        let _ = tx.send(AppEvent::K8sError(format!(
+                            "Log stream error for {}: {:#}",
+                            pod_name, e
+                        )));
+                    }
+                }
+            }
+            active.remove(&pod_key);
+        }
+
+        if tx.is_closed() {
+            return Ok(());
+        }
+        tokio::time::sleep(WORKLOAD_POD_POLL).await;
+    }
+}
+
+/// The container `kubectl logs` would stream from `pod` when none is named:
+/// the one its default-container annotation names, else the first.
+pub fn default_container(pod: &Pod) -> Option<String> {
+    let containers = &pod.spec.as_ref()?.containers;
+    let annotated = pod
+        .annotations()
+        .get(DEFAULT_CONTAINER_ANNOTATION)
+        .filter(|name| containers.iter().any(|c| &c.name == *name));
+    annotated
+        .cloned()
+        .or_else(|| containers.first().map(|c| c.name.clone()))
+}
+
+/// Pod name without the workload's prefix, e.g. `web-7d9f-abcde` -> `7d9f-abcde`.
+fn short_pod_name<'a>(workload: &str, pod: &'a str) -> &'a str {
+    pod.strip_prefix(workload)
+        .and_then(|rest| rest.strip_prefix('-'))
+        .filter(|rest| !rest.is_empty())
+        .unwrap_or(pod)
+}
+
+/// Label selector string for the pods a workload owns.
+async fn workload_selector(
+    client: Client,
+    namespace: &str,
+    resource_type: ResourceType,
+    name: &str,
+) -> Result<String> {
+    let selector = match resource_type {
+        ResourceType::Deployments => {
+            let api: Api<Deployment> = Api::namespaced(client, namespace);
+            api.get(name).await?.spec.map(|s| s.selector)
+        }
+        ResourceType::StatefulSets => {
+            let api: Api<StatefulSet> = Api::namespaced(client, namespace);
+            api.get(name).await?.spec.map(|s| s.selector)
+        }
+        ResourceType::DaemonSets => {
+            let api: Api<DaemonSet> = Api::namespaced(client, namespace);
+            api.get(name).await?.spec.map(|s| s.selector)
+        }
+        _ => anyhow::bail!("{} has no pods to aggregate logs from", resource_type),
+    };
+    let selector = match selector {
+        Some(selector) => label_selector_string(&selector)?,
+        None => String::new(),
+    };
+    if selector.is_empty() {
+        anyhow::bail!("{} has no label selector", name);
+    }
+    Ok(selector)
+}
+
+/// A selector's `matchLabels` and `matchExpressions` as one list selector,
+/// e.g. `app=web,tier in (front,edge),!canary`. An operator the list API
+/// has no syntax for is an error rather than dropped, since dropping a
+/// requirement would select more pods than the workload owns.
+pub fn label_selector_string(selector: &LabelSelector) -> Result<String> {
+    let labels = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(k, v)| format!("{}={}", k, v));
+    let mut terms: Vec<String> = labels.collect();
+    for expr in selector.match_expressions.iter().flatten() {
+        let values = || expr.values.as_deref().unwrap_or_default().join(",");
+        terms.push(match expr.operator.as_str() {
+            "In" => format!("{} in ({})", expr.key, values()),
+            "NotIn" => format!("{} notin ({})", expr.key, values()),
+            "Exists" => expr.key.clone(),
+            "DoesNotExist" => format!("!{}", expr.key),
+            op => anyhow::bail!("Unsupported selector operator {} on {}", op, expr.key),
+        });
+    }
+    Ok(terms.join(","))
+}
+
+/// Open one pod's log stream and forward its lines, optionally prefixed.
+/// With `since`, only lines from then on are fetched in place of the tail.
+/// Returns when the last line arrived, if any did. Fetching previous logs
+/// of a container that never restarted fails with a readable message.
+async fn forward_pod_logs(
+    api: &Api<Pod>,
+    pod_name: &str,
+    container: Option<&str>,
+    options: LogOptions,
+    prefix: Option<&str>,
+    since: Option<Timestamp>,
+    tx: &mpsc::UnboundedSender<AppEvent>,
+) -> Result<Option<Timestamp>> {
     let mut params = LogParams {
         follow: true,
-        tail_lines: Some(100),
+        tail_lines: options.tail_lines,
+        previous: options.previous,
+        timestamps: options.timestamps,
+        since_seconds: options.since_seconds,
         ..Default::default()
     };
+    if since.is_some() {
+        // The API prefers sinceSeconds, and a tail would repeat old lines
+        params.tail_lines = None;
+        params.since_seconds = None;
+        params.since_time = since;
+    }
 
     if let Some(c) = container {
         params.container = Some(c.to_string());
     }
 
-    let stream = api
-        .log_stream(pod_name, &params)
+    let opened = tokio::time::timeout(options.open_timeout, api.log_stream(pod_name, &params))
         .await
-        .context("Failed to open log stream")?;
+        .map_err(|_| timed_out(options.open_timeout, "opening the log stream"))?;
+    let stream = match opened {
+        Ok(stream) => stream,
+        // The API answers 400 when there is no terminated instance to read
+        Err(kube::Error::Api(status)) if options.previous && status.code == 400 => {
+            anyhow::bail!(
+                "No previous logs for {}: the container has not restarted",
+                pod_name
+            );
+        }
+        Err(e) => return Err(e).context("Failed to open log stream"),
+    };
 
-    let mut lines = stream.lines();
+    // Lines are read as bytes and decoded lossily: a pod writing binary or
+    // invalid UTF-8 gets replacement characters rather than a dead stream
+    let mut stream = std::pin::pin!(stream);
+    let mut buf = Vec::new();
+    let mut last = None;
 
-    while let Some(line) = lines.try_next().await? {
+    loop {
+        buf.clear();
+        if stream.read_until(b'\n', &mut buf).await? == 0 {
+            break;
+        }
+        let text = String::from_utf8_lossy(&buf);
+        let text = text.trim_end_matches(['\n', '\r']);
+        let line = match prefix {
+            Some(p) => format!("[{}] {}", p, text),
+            None => text.to_string(),
+        };
         if tx.send(AppEvent::LogLine(line)).is_err() {
             break;
         }
+        last = Some(Timestamp::now());
     }
 
-    let _ = tx.send(AppEvent::LogStreamEnded);
-
-    Ok(())
+    Ok(last)
 }
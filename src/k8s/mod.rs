@@ -1,4 +1,7 @@
 pub mod actions;
 pub mod client;
+pub mod errors;
 pub mod logs;
+pub mod portforward;
 pub mod resources;
+pub mod templates;
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{Api, Client};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::{JoinHandle, JoinSet};
+
+use crate::event::AppEvent;
+use crate::types::{PortForward, ResourceType};
+
+/// Owns the background tasks serving active port-forwards.
+#[derive(Default)]
+pub struct PortForwardManager {
+    tasks: HashMap<PortForward, JoinHandle<()>>,
+}
+
+impl PortForwardManager {
+    /// Start forwarding `forward.local_port` to the target pod. Sends
+    /// `PortForwardStarted` once the local port is bound, or
+    /// `PortForwardFailed` if the forward could not be set up.
+    pub fn start(
+        &mut self,
+        client: Client,
+        forward: PortForward,
+        tx: mpsc::UnboundedSender<AppEvent>,
+    ) {
+        self.stop(&forward);
+        let fwd = forward.clone();
+        let handle = tokio::spawn(async move {
+            if let Err(e) = run_forward(client, &fwd, tx.clone()).await {
+                let _ = tx.send(AppEvent::PortForwardFailed {
+                    forward: fwd,
+                    error: format!("{:#}", e),
+                });
+            }
+        });
+        self.tasks.insert(forward, handle);
+    }
+
+    /// Stop a forward. Aborting the listener task also drops every open
+    /// connection it spawned.
+    pub fn stop(&mut self, forward: &PortForward) {
+        if let Some(handle) = self.tasks.remove(forward) {
+            handle.abort();
+        }
+    }
+}
+
+/// Pod that receives the traffic: the pod itself, or ordinal 0 of a StatefulSet.
+fn target_pod(forward: &PortForward) -> String {
+    match forward.resource_type {
+        ResourceType::StatefulSets => format!("{}-0", forward.name),
+        _ => forward.name.clone(),
+    }
+}
+
+async fn run_forward(
+    client: Client,
+    forward: &PortForward,
+    tx: mpsc::UnboundedSender<AppEvent>,
+) -> Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", forward.local_port))
+        .await
+        .with_context(|| format!("Failed to bind local port {}", forward.local_port))?;
+    let _ = tx.send(AppEvent::PortForwardStarted(forward.clone()));
+
+    let api: Api<Pod> = Api::namespaced(client, &forward.namespace);
+    let pod = target_pod(forward);
+    let mut connections = JoinSet::new();
+
+    loop {
+        let (conn, _) = listener
+            .accept()
+            .await
+            .context("Failed to accept connection")?;
+        // Reap finished connections so the set doesn't grow unbounded
+        while connections.try_join_next().is_some() {}
+
+        let api = api.clone();
+        let pod = pod.clone();
+        let remote_port = forward.remote_port;
+        let conn_tx = tx.clone();
+        connections.spawn(async move {
+            if let Err(e) = forward_connection(&api, &pod, remote_port, conn).await {
+                let _ = conn_tx.send(AppEvent::K8sError(format!("Port-forward error: {:#}", e)));
+            }
+        });
+    }
+}
+
+async fn forward_connection(
+    api: &Api<Pod>,
+    pod: &str,
+    port: u16,
+    mut conn: TcpStream,
+) -> Result<()> {
+    let mut forwarder = api
+        .portforward(pod, &[port])
+        .await
+        .with_context(|| format!("Failed to port-forward to {}:{}", pod, port))?;
+    let mut upstream = forwarder
+        .take_stream(port)
+        .context("Port-forward stream unavailable")?;
+
+    tokio::io::copy_bidirectional(&mut conn, &mut upstream)
+        .await
+        .context("Port-forward connection failed")?;
+
+    drop(upstream);
+    forwarder.join().await.context("Port-forward closed")?;
+    Ok(())
+}
@@ -1,37 +1,50 @@
 use std::collections::BTreeMap;
 use std::fmt::Debug;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use futures::{StreamExt, TryStreamExt};
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::autoscaling::v1::HorizontalPodAutoscaler;
 use k8s_openapi::api::batch::v1::{CronJob, Job};
 use k8s_openapi::api::core::v1::{
-    ConfigMap, Endpoints, Event, LimitRange, Namespace, Node, PersistentVolume,
-    PersistentVolumeClaim, Pod, ReplicationController, ResourceQuota, Secret, Service,
-    ServiceAccount,
+    ConfigMap, ContainerStateTerminated, ContainerStatus, Endpoints, Event, LimitRange, Namespace,
+    Node, PersistentVolume, PersistentVolumeClaim, Pod, PodSpec, PodTemplateSpec,
+    ReplicationController, ResourceQuota, Secret, Service, ServiceAccount,
 };
-use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::networking::v1::{Ingress, IngressBackend, NetworkPolicy};
 use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use k8s_openapi::api::storage::v1::StorageClass;
-use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
-use kube::api::ListParams;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, Time};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{ApiResource, DynamicObject, ListParams};
 use kube::runtime::watcher;
-use kube::runtime::WatchStreamExt;
 use kube::{Api, Client, Resource, ResourceExt};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use tokio::sync::mpsc;
 
+use super::logs::label_selector_string;
 use crate::event::AppEvent;
-use crate::types::{ResourceItem, ResourceType};
+use crate::types::{
+    format_age, PodMetrics, ResourceItem, ResourceType, Selector, SpecSummary, ALL_NAMESPACES,
+};
 
 // ---------------------------------------------------------------------------
 // Generic watch / list / describe helpers
 // ---------------------------------------------------------------------------
 
+/// Upper bound for the delay between watch restarts.
+const WATCH_BACKOFF_MAX: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Watch the objects of `api` that `config` selects and send the full item
+/// list on every change. Objects are converted when they arrive, so a
+/// change to one object doesn't re-serialize the YAML of every other row.
+/// Transient failures (network blips, API server restarts) send
+/// `WatchReconnecting` and restart the watch from a fresh list after a
+/// backoff; only auth failures and rejected selectors end the task.
 async fn watch_generic<T, F>(
     api: Api<T>,
+    config: watcher::Config,
     tx: mpsc::UnboundedSender<AppEvent>,
     converter: F,
 ) -> Result<()>
@@ -39,80 +52,217 @@ where
     T: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + Sync + 'static,
     F: Fn(&T) -> ResourceItem,
 {
-    let mut stream = watcher(api, watcher::Config::default())
-        .default_backoff()
-        .boxed();
-
-    let mut cache: BTreeMap<String, T> = BTreeMap::new();
-
-    while let Some(event) = stream.try_next().await? {
-        match event {
-            watcher::Event::Apply(obj) | watcher::Event::InitApply(obj) => {
-                let name = ResourceExt::name_any(&obj);
-                let ns = ResourceExt::namespace(&obj).unwrap_or_default();
-                let key = format!("{}/{}", ns, name);
-                cache.insert(key, obj);
-            }
-            watcher::Event::Delete(obj) => {
-                let name = ResourceExt::name_any(&obj);
-                let ns = ResourceExt::namespace(&obj).unwrap_or_default();
-                let key = format!("{}/{}", ns, name);
-                cache.remove(&key);
+    let mut cache: BTreeMap<String, ResourceItem> = BTreeMap::new();
+    let mut backoff = std::time::Duration::from_secs(1);
+
+    loop {
+        let mut stream = watcher(api.clone(), config.clone()).boxed();
+        // Objects from an in-progress (re)list. The previous list stays on
+        // screen until it completes so a reconnect doesn't blank the table.
+        let mut relist: BTreeMap<String, ResourceItem> = BTreeMap::new();
+
+        loop {
+            let event = match stream.try_next().await {
+                Ok(Some(event)) => event,
+                Ok(None) => return Ok(()),
+                Err(e) if is_fatal_watch_error(&e) => return Err(e.into()),
+                Err(_) => {
+                    if tx.send(AppEvent::WatchReconnecting).is_err() {
+                        return Ok(());
+                    }
+                    break;
+                }
+            };
+
+            match event {
+                watcher::Event::Apply(obj) => {
+                    cache.insert(object_key(&obj), converter(&obj));
+                }
+                watcher::Event::Delete(obj) => {
+                    cache.remove(&object_key(&obj));
+                }
+                watcher::Event::Init => {
+                    relist.clear();
+                    continue;
+                }
+                watcher::Event::InitApply(obj) => {
+                    relist.insert(object_key(&obj), converter(&obj));
+                    continue;
+                }
+                watcher::Event::InitDone => {
+                    cache = std::mem::take(&mut relist);
+                    backoff = std::time::Duration::from_secs(1);
+                }
             }
-            watcher::Event::Init => {
-                cache.clear();
+
+            let items: Vec<ResourceItem> = cache.values().cloned().collect();
+            if tx.send(AppEvent::ResourcesUpdated(items)).is_err() {
+                return Ok(());
             }
-            watcher::Event::InitDone => {}
         }
 
-        let items: Vec<ResourceItem> = cache.values().map(&converter).collect();
-        if tx.send(AppEvent::ResourcesUpdated(items)).is_err() {
-            break;
-        }
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(WATCH_BACKOFF_MAX);
+    }
+}
+
+fn object_key<T: Resource>(obj: &T) -> String {
+    let name = ResourceExt::name_any(obj);
+    let ns = ResourceExt::namespace(obj).unwrap_or_default();
+    format!("{}/{}", ns, name)
+}
+
+/// Errors that retrying can't fix: the credentials are missing, expired or
+/// not allowed to watch this resource, or the selector is invalid.
+fn is_fatal_watch_error(err: &watcher::Error) -> bool {
+    let client_err = match err {
+        watcher::Error::InitialListFailed(e)
+        | watcher::Error::WatchStartFailed(e)
+        | watcher::Error::WatchFailed(e) => e,
+        watcher::Error::WatchError(status) => return matches!(status.code, 400 | 401 | 403),
+        watcher::Error::NoResourceVersion => return true,
+    };
+    match client_err {
+        kube::Error::Auth(_) => true,
+        kube::Error::Api(status) => matches!(status.code, 400 | 401 | 403),
+        _ => false,
     }
+}
 
-    Ok(())
+/// `Api::namespaced`, or `Api::all` when the "<all>" namespace is selected.
+fn namespaced_or_all<K>(client: Client, namespace: &str) -> Api<K>
+where
+    K: Resource<Scope = k8s_openapi::NamespaceResourceScope, DynamicType = ()>,
+{
+    if namespace == ALL_NAMESPACES {
+        Api::all(client)
+    } else {
+        Api::namespaced(client, namespace)
+    }
 }
 
-async fn list_generic<T, F>(api: Api<T>, converter: F) -> Result<Vec<ResourceItem>>
+async fn list_generic<T, F>(
+    api: Api<T>,
+    params: &ListParams,
+    converter: F,
+) -> Result<Vec<ResourceItem>>
 where
     T: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + Sync + 'static,
     F: Fn(&T) -> ResourceItem,
 {
-    let list = api.list(&ListParams::default()).await?;
+    let list = api.list(params).await?;
     Ok(list.items.iter().map(converter).collect())
 }
 
+fn watcher_config(selector: &Selector) -> watcher::Config {
+    let mut config = watcher::Config::default();
+    if let Some(labels) = &selector.labels {
+        config = config.labels(labels);
+    }
+    if let Some(fields) = &selector.fields {
+        config = config.fields(fields);
+    }
+    config
+}
+
+fn list_params(selector: &Selector) -> ListParams {
+    let mut params = ListParams::default();
+    if let Some(labels) = &selector.labels {
+        params = params.labels(labels);
+    }
+    if let Some(fields) = &selector.fields {
+        params = params.fields(fields);
+    }
+    params
+}
+
 async fn describe_generic<T>(api: Api<T>, name: &str) -> Result<String>
 where
     T: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Serialize + Send + Sync + 'static,
 {
-    let obj = api.get(name).await?;
     let mut desc = String::new();
     desc.push_str("\n--- Full YAML ---\n");
-    if let Ok(yaml) = serde_yaml::to_string(&obj) {
-        desc.push_str(&yaml);
+    desc.push_str(&get_yaml_generic(api, name).await?);
+    Ok(desc)
+}
+
+/// Generic describe of a workload, listing the pods its selector matches
+/// above the YAML.
+async fn describe_workload<T>(
+    client: Client,
+    api: Api<T>,
+    namespace: &str,
+    name: &str,
+    selector: impl Fn(&T) -> Option<&LabelSelector>,
+) -> Result<String>
+where
+    T: Resource<DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + Debug
+        + Serialize
+        + Send
+        + Sync
+        + 'static,
+{
+    let obj = api.get(name).await?;
+    let mut desc = String::new();
+    if let Some(selector) = selector(&obj) {
+        desc.push_str(&selector_pods_section(client, namespace, selector).await);
     }
+    desc.push_str(
+        "
+--- Full YAML ---
+",
+    );
+    desc.push_str(&serde_yaml::to_string(&obj).context("Failed to serialize YAML")?);
     Ok(desc)
 }
 
+async fn get_yaml_generic<T>(api: Api<T>, name: &str) -> Result<String>
+where
+    T: Resource<DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + Debug
+        + Serialize
+        + Send
+        + Sync
+        + 'static,
+{
+    let obj = api.get(name).await?;
+    serde_yaml::to_string(&obj).context("Failed to serialize YAML")
+}
+
 // ---------------------------------------------------------------------------
 // Public dispatch functions
 // ---------------------------------------------------------------------------
 
+/// Watch a type in `namespace`, sending the whole list on every change.
+/// Only objects matching `selector` are sent by the server.
 pub async fn watch_resources(
     client: Client,
     namespace: &str,
     resource_type: ResourceType,
+    selector: &Selector,
     tx: mpsc::UnboundedSender<AppEvent>,
 ) -> Result<()> {
+    let config = watcher_config(selector);
     match resource_type {
         ResourceType::Pods => {
-            watch_generic(Api::<Pod>::namespaced(client, namespace), tx, pod_to_resource_item).await
+            // Metrics are polled alongside the watch and stop with it
+            let metrics = poll_pod_metrics(client.clone(), namespace.to_string(), tx.clone());
+            let api = namespaced_or_all::<Pod>(client, namespace);
+            let watch = watch_generic(api, config, tx, pod_to_resource_item);
+            tokio::select! {
+                result = watch => result,
+                _ = metrics => Ok(()),
+            }
         }
         ResourceType::Deployments => {
             watch_generic(
-                Api::<Deployment>::namespaced(client, namespace),
+                namespaced_or_all::<Deployment>(client, namespace),
+                config,
                 tx,
                 deployment_to_resource_item,
             )
@@ -120,7 +270,8 @@ pub async fn watch_resources(
         }
         ResourceType::StatefulSets => {
             watch_generic(
-                Api::<StatefulSet>::namespaced(client, namespace),
+                namespaced_or_all::<StatefulSet>(client, namespace),
+                config,
                 tx,
                 statefulset_to_resource_item,
             )
@@ -128,7 +279,8 @@ pub async fn watch_resources(
         }
         ResourceType::DaemonSets => {
             watch_generic(
-                Api::<DaemonSet>::namespaced(client, namespace),
+                namespaced_or_all::<DaemonSet>(client, namespace),
+                config,
                 tx,
                 daemonset_to_resource_item,
             )
@@ -136,7 +288,8 @@ pub async fn watch_resources(
         }
         ResourceType::ReplicaSets => {
             watch_generic(
-                Api::<ReplicaSet>::namespaced(client, namespace),
+                namespaced_or_all::<ReplicaSet>(client, namespace),
+                config,
                 tx,
                 replicaset_to_resource_item,
             )
@@ -144,18 +297,26 @@ pub async fn watch_resources(
         }
         ResourceType::ReplicationControllers => {
             watch_generic(
-                Api::<ReplicationController>::namespaced(client, namespace),
+                namespaced_or_all::<ReplicationController>(client, namespace),
+                config,
                 tx,
                 replication_controller_to_resource_item,
             )
             .await
         }
         ResourceType::Jobs => {
-            watch_generic(Api::<Job>::namespaced(client, namespace), tx, job_to_resource_item).await
+            watch_generic(
+                namespaced_or_all::<Job>(client, namespace),
+                config,
+                tx,
+                job_to_resource_item,
+            )
+            .await
         }
         ResourceType::CronJobs => {
             watch_generic(
-                Api::<CronJob>::namespaced(client, namespace),
+                namespaced_or_all::<CronJob>(client, namespace),
+                config,
                 tx,
                 cronjob_to_resource_item,
             )
@@ -163,7 +324,8 @@ pub async fn watch_resources(
         }
         ResourceType::HorizontalPodAutoscalers => {
             watch_generic(
-                Api::<HorizontalPodAutoscaler>::namespaced(client, namespace),
+                namespaced_or_all::<HorizontalPodAutoscaler>(client, namespace),
+                config,
                 tx,
                 hpa_to_resource_item,
             )
@@ -171,7 +333,8 @@ pub async fn watch_resources(
         }
         ResourceType::Services => {
             watch_generic(
-                Api::<Service>::namespaced(client, namespace),
+                namespaced_or_all::<Service>(client, namespace),
+                config,
                 tx,
                 service_to_resource_item,
             )
@@ -179,7 +342,8 @@ pub async fn watch_resources(
         }
         ResourceType::Endpoints => {
             watch_generic(
-                Api::<Endpoints>::namespaced(client, namespace),
+                namespaced_or_all::<Endpoints>(client, namespace),
+                config,
                 tx,
                 endpoints_to_resource_item,
             )
@@ -187,7 +351,8 @@ pub async fn watch_resources(
         }
         ResourceType::Ingresses => {
             watch_generic(
-                Api::<Ingress>::namespaced(client, namespace),
+                namespaced_or_all::<Ingress>(client, namespace),
+                config,
                 tx,
                 ingress_to_resource_item,
             )
@@ -195,7 +360,8 @@ pub async fn watch_resources(
         }
         ResourceType::NetworkPolicies => {
             watch_generic(
-                Api::<NetworkPolicy>::namespaced(client, namespace),
+                namespaced_or_all::<NetworkPolicy>(client, namespace),
+                config,
                 tx,
                 network_policy_to_resource_item,
             )
@@ -203,7 +369,8 @@ pub async fn watch_resources(
         }
         ResourceType::ConfigMaps => {
             watch_generic(
-                Api::<ConfigMap>::namespaced(client, namespace),
+                namespaced_or_all::<ConfigMap>(client, namespace),
+                config,
                 tx,
                 configmap_to_resource_item,
             )
@@ -211,7 +378,8 @@ pub async fn watch_resources(
         }
         ResourceType::Secrets => {
             watch_generic(
-                Api::<Secret>::namespaced(client, namespace),
+                namespaced_or_all::<Secret>(client, namespace),
+                config,
                 tx,
                 secret_to_resource_item,
             )
@@ -219,18 +387,26 @@ pub async fn watch_resources(
         }
         ResourceType::PersistentVolumeClaims => {
             watch_generic(
-                Api::<PersistentVolumeClaim>::namespaced(client, namespace),
+                namespaced_or_all::<PersistentVolumeClaim>(client, namespace),
+                config,
                 tx,
                 pvc_to_resource_item,
             )
             .await
         }
         ResourceType::PersistentVolumes => {
-            watch_generic(Api::<PersistentVolume>::all(client), tx, pv_to_resource_item).await
+            watch_generic(
+                Api::<PersistentVolume>::all(client),
+                config,
+                tx,
+                pv_to_resource_item,
+            )
+            .await
         }
         ResourceType::StorageClasses => {
             watch_generic(
                 Api::<StorageClass>::all(client),
+                config,
                 tx,
                 storageclass_to_resource_item,
             )
@@ -238,21 +414,29 @@ pub async fn watch_resources(
         }
         ResourceType::ServiceAccounts => {
             watch_generic(
-                Api::<ServiceAccount>::namespaced(client, namespace),
+                namespaced_or_all::<ServiceAccount>(client, namespace),
+                config,
                 tx,
                 serviceaccount_to_resource_item,
             )
             .await
         }
         ResourceType::Namespaces => {
-            watch_generic(Api::<Namespace>::all(client), tx, namespace_to_resource_item).await
+            watch_generic(
+                Api::<Namespace>::all(client),
+                config,
+                tx,
+                namespace_to_resource_item,
+            )
+            .await
         }
         ResourceType::Nodes => {
-            watch_generic(Api::<Node>::all(client), tx, node_to_resource_item).await
+            watch_generic(Api::<Node>::all(client), config, tx, node_to_resource_item).await
         }
         ResourceType::Events => {
             watch_generic(
-                Api::<Event>::namespaced(client, namespace),
+                namespaced_or_all::<Event>(client, namespace),
+                config,
                 tx,
                 event_to_resource_item,
             )
@@ -260,7 +444,8 @@ pub async fn watch_resources(
         }
         ResourceType::ResourceQuotas => {
             watch_generic(
-                Api::<ResourceQuota>::namespaced(client, namespace),
+                namespaced_or_all::<ResourceQuota>(client, namespace),
+                config,
                 tx,
                 resourcequota_to_resource_item,
             )
@@ -268,7 +453,8 @@ pub async fn watch_resources(
         }
         ResourceType::LimitRanges => {
             watch_generic(
-                Api::<LimitRange>::namespaced(client, namespace),
+                namespaced_or_all::<LimitRange>(client, namespace),
+                config,
                 tx,
                 limitrange_to_resource_item,
             )
@@ -276,7 +462,8 @@ pub async fn watch_resources(
         }
         ResourceType::PodDisruptionBudgets => {
             watch_generic(
-                Api::<PodDisruptionBudget>::namespaced(client, namespace),
+                namespaced_or_all::<PodDisruptionBudget>(client, namespace),
+                config,
                 tx,
                 pdb_to_resource_item,
             )
@@ -285,110 +472,314 @@ pub async fn watch_resources(
     }
 }
 
-pub async fn list_all_resources(
+// ---------------------------------------------------------------------------
+// Pod metrics (metrics.k8s.io)
+// ---------------------------------------------------------------------------
+
+/// How often pod usage is refreshed while the Pods list is open.
+const METRICS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+fn pod_metrics_resource() -> ApiResource {
+    ApiResource {
+        group: "metrics.k8s.io".to_string(),
+        version: "v1beta1".to_string(),
+        api_version: "metrics.k8s.io/v1beta1".to_string(),
+        kind: "PodMetrics".to_string(),
+        plural: "pods".to_string(),
+    }
+}
+
+/// Fetches CPU/memory usage for pods in `namespace`, keyed by
+/// "namespace/name". Fails if metrics-server isn't installed.
+pub async fn fetch_pod_metrics(client: Client, namespace: &str) -> Result<PodMetrics> {
+    let ar = pod_metrics_resource();
+    let api: Api<DynamicObject> = if namespace == ALL_NAMESPACES {
+        Api::all_with(client, &ar)
+    } else {
+        Api::namespaced_with(client, namespace, &ar)
+    };
+    let list = api
+        .list(&ListParams::default())
+        .await
+        .context("Failed to list pod metrics")?;
+
+    Ok(list
+        .items
+        .iter()
+        .map(|m| {
+            let key = format!(
+                "{}/{}",
+                m.metadata.namespace.as_deref().unwrap_or_default(),
+                m.metadata.name.as_deref().unwrap_or_default()
+            );
+            (key, pod_usage(m))
+        })
+        .collect())
+}
+
+/// Repeatedly fetches pod metrics and sends them to the app. When metrics
+/// are unavailable an empty map is sent so the columns fall back to `<none>`.
+async fn poll_pod_metrics(client: Client, namespace: String, tx: mpsc::UnboundedSender<AppEvent>) {
+    loop {
+        let metrics = fetch_pod_metrics(client.clone(), &namespace)
+            .await
+            .unwrap_or_default();
+        if tx.send(AppEvent::PodMetricsUpdated(metrics)).is_err() {
+            return;
+        }
+        tokio::time::sleep(METRICS_POLL_INTERVAL).await;
+    }
+}
+
+/// Sums container usage into display strings, e.g. ("12m", "64Mi").
+fn pod_usage(metrics: &DynamicObject) -> (String, String) {
+    let mut nanocores = 0f64;
+    let mut bytes = 0f64;
+    if let Some(containers) = metrics.data["containers"].as_array() {
+        for c in containers {
+            let usage = &c["usage"];
+            nanocores += usage["cpu"].as_str().and_then(parse_cpu).unwrap_or(0.0);
+            bytes += usage["memory"]
+                .as_str()
+                .and_then(parse_memory)
+                .unwrap_or(0.0);
+        }
+    }
+    (
+        format!("{}m", (nanocores / 1e6).round() as u64),
+        format!("{}Mi", (bytes / (1024.0 * 1024.0)).round() as u64),
+    )
+}
+
+/// Parses a CPU quantity ("250m", "12345n", "1") into nanocores.
+fn parse_cpu(quantity: &str) -> Option<f64> {
+    let (num, scale) = match quantity.char_indices().last()? {
+        (i, 'n') => (&quantity[..i], 1.0),
+        (i, 'u') => (&quantity[..i], 1e3),
+        (i, 'm') => (&quantity[..i], 1e6),
+        _ => (quantity, 1e9),
+    };
+    num.parse::<f64>().ok().map(|n| n * scale)
+}
+
+/// Parses a memory quantity ("128974848", "123Ki", "64Mi", "1G") into bytes.
+fn parse_memory(quantity: &str) -> Option<f64> {
+    const SUFFIXES: [(&str, f64); 8] = [
+        ("Ki", 1024.0),
+        ("Mi", 1048576.0),
+        ("Gi", 1073741824.0),
+        ("Ti", 1099511627776.0),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+    ];
+    for (suffix, scale) in SUFFIXES {
+        if let Some(num) = quantity.strip_suffix(suffix) {
+            return num.parse::<f64>().ok().map(|n| n * scale);
+        }
+    }
+    quantity.parse::<f64>().ok()
+}
+
+/// List every resource of a type in `namespace` (all namespaces for
+/// `ALL_NAMESPACES`) matching `selector`. Cluster-scoped types ignore the
+/// namespace.
+pub async fn list_resources(
     client: Client,
+    namespace: &str,
     resource_type: ResourceType,
+    selector: &Selector,
 ) -> Result<Vec<ResourceItem>> {
+    let params = list_params(selector);
     match resource_type {
-        ResourceType::Pods => list_generic(Api::<Pod>::all(client), pod_to_resource_item).await,
+        ResourceType::Pods => {
+            let api = namespaced_or_all::<Pod>(client, namespace);
+            list_generic(api, &params, pod_to_resource_item).await
+        }
         ResourceType::Deployments => {
-            list_generic(Api::<Deployment>::all(client), deployment_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<Deployment>(client, namespace),
+                &params,
+                deployment_to_resource_item,
+            )
+            .await
         }
         ResourceType::StatefulSets => {
-            list_generic(Api::<StatefulSet>::all(client), statefulset_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<StatefulSet>(client, namespace),
+                &params,
+                statefulset_to_resource_item,
+            )
+            .await
         }
         ResourceType::DaemonSets => {
-            list_generic(Api::<DaemonSet>::all(client), daemonset_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<DaemonSet>(client, namespace),
+                &params,
+                daemonset_to_resource_item,
+            )
+            .await
         }
         ResourceType::ReplicaSets => {
-            list_generic(Api::<ReplicaSet>::all(client), replicaset_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<ReplicaSet>(client, namespace),
+                &params,
+                replicaset_to_resource_item,
+            )
+            .await
         }
         ResourceType::ReplicationControllers => {
             list_generic(
-                Api::<ReplicationController>::all(client),
+                namespaced_or_all::<ReplicationController>(client, namespace),
+                &params,
                 replication_controller_to_resource_item,
             )
             .await
         }
-        ResourceType::Jobs => list_generic(Api::<Job>::all(client), job_to_resource_item).await,
+        ResourceType::Jobs => {
+            list_generic(
+                namespaced_or_all::<Job>(client, namespace),
+                &params,
+                job_to_resource_item,
+            )
+            .await
+        }
         ResourceType::CronJobs => {
-            list_generic(Api::<CronJob>::all(client), cronjob_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<CronJob>(client, namespace),
+                &params,
+                cronjob_to_resource_item,
+            )
+            .await
         }
         ResourceType::HorizontalPodAutoscalers => {
             list_generic(
-                Api::<HorizontalPodAutoscaler>::all(client),
+                namespaced_or_all::<HorizontalPodAutoscaler>(client, namespace),
+                &params,
                 hpa_to_resource_item,
             )
             .await
         }
         ResourceType::Services => {
-            list_generic(Api::<Service>::all(client), service_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<Service>(client, namespace),
+                &params,
+                service_to_resource_item,
+            )
+            .await
         }
         ResourceType::Endpoints => {
-            list_generic(Api::<Endpoints>::all(client), endpoints_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<Endpoints>(client, namespace),
+                &params,
+                endpoints_to_resource_item,
+            )
+            .await
         }
         ResourceType::Ingresses => {
-            list_generic(Api::<Ingress>::all(client), ingress_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<Ingress>(client, namespace),
+                &params,
+                ingress_to_resource_item,
+            )
+            .await
         }
         ResourceType::NetworkPolicies => {
             list_generic(
-                Api::<NetworkPolicy>::all(client),
+                namespaced_or_all::<NetworkPolicy>(client, namespace),
+                &params,
                 network_policy_to_resource_item,
             )
             .await
         }
         ResourceType::ConfigMaps => {
-            list_generic(Api::<ConfigMap>::all(client), configmap_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<ConfigMap>(client, namespace),
+                &params,
+                configmap_to_resource_item,
+            )
+            .await
         }
         ResourceType::Secrets => {
-            list_generic(Api::<Secret>::all(client), secret_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<Secret>(client, namespace),
+                &params,
+                secret_to_resource_item,
+            )
+            .await
         }
         ResourceType::PersistentVolumeClaims => {
             list_generic(
-                Api::<PersistentVolumeClaim>::all(client),
+                namespaced_or_all::<PersistentVolumeClaim>(client, namespace),
+                &params,
                 pvc_to_resource_item,
             )
             .await
         }
         ResourceType::PersistentVolumes => {
-            list_generic(Api::<PersistentVolume>::all(client), pv_to_resource_item).await
+            list_generic(
+                Api::<PersistentVolume>::all(client),
+                &params,
+                pv_to_resource_item,
+            )
+            .await
         }
         ResourceType::StorageClasses => {
             list_generic(
                 Api::<StorageClass>::all(client),
+                &params,
                 storageclass_to_resource_item,
             )
             .await
         }
         ResourceType::ServiceAccounts => {
             list_generic(
-                Api::<ServiceAccount>::all(client),
+                namespaced_or_all::<ServiceAccount>(client, namespace),
+                &params,
                 serviceaccount_to_resource_item,
             )
             .await
         }
         ResourceType::Namespaces => {
-            list_generic(Api::<Namespace>::all(client), namespace_to_resource_item).await
+            list_generic(
+                Api::<Namespace>::all(client),
+                &params,
+                namespace_to_resource_item,
+            )
+            .await
         }
         ResourceType::Nodes => {
-            list_generic(Api::<Node>::all(client), node_to_resource_item).await
+            list_generic(Api::<Node>::all(client), &params, node_to_resource_item).await
         }
         ResourceType::Events => {
-            list_generic(Api::<Event>::all(client), event_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<Event>(client, namespace),
+                &params,
+                event_to_resource_item,
+            )
+            .await
         }
         ResourceType::ResourceQuotas => {
             list_generic(
-                Api::<ResourceQuota>::all(client),
+                namespaced_or_all::<ResourceQuota>(client, namespace),
+                &params,
                 resourcequota_to_resource_item,
             )
             .await
         }
         ResourceType::LimitRanges => {
-            list_generic(Api::<LimitRange>::all(client), limitrange_to_resource_item).await
+            list_generic(
+                namespaced_or_all::<LimitRange>(client, namespace),
+                &params,
+                limitrange_to_resource_item,
+            )
+            .await
         }
         ResourceType::PodDisruptionBudgets => {
             list_generic(
-                Api::<PodDisruptionBudget>::all(client),
+                namespaced_or_all::<PodDisruptionBudget>(client, namespace),
+                &params,
                 pdb_to_resource_item,
             )
             .await
@@ -419,31 +810,52 @@ pub async fn count_all_resources(
         configmaps, secrets, pvcs, pvs, storageclasses, serviceaccounts,
         namespaces, nodes, events, resourcequotas, limitranges, pdbs,
     ) = join!(
-        count_generic(Api::<Pod>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<Deployment>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<StatefulSet>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<DaemonSet>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<ReplicaSet>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<ReplicationController>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<Job>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<CronJob>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<HorizontalPodAutoscaler>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<Service>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<Endpoints>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<Ingress>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<NetworkPolicy>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<ConfigMap>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<Secret>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<PersistentVolumeClaim>::namespaced(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<Pod>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<Deployment>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<StatefulSet>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<DaemonSet>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<ReplicaSet>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<ReplicationController>(
+            client.clone(),
+            namespace
+        )),
+        count_generic(namespaced_or_all::<Job>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<CronJob>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<HorizontalPodAutoscaler>(
+            client.clone(),
+            namespace
+        )),
+        count_generic(namespaced_or_all::<Service>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<Endpoints>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<Ingress>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<NetworkPolicy>(
+            client.clone(),
+            namespace
+        )),
+        count_generic(namespaced_or_all::<ConfigMap>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<Secret>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<PersistentVolumeClaim>(
+            client.clone(),
+            namespace
+        )),
         count_generic(Api::<PersistentVolume>::all(client.clone())),
         count_generic(Api::<StorageClass>::all(client.clone())),
-        count_generic(Api::<ServiceAccount>::namespaced(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<ServiceAccount>(
+            client.clone(),
+            namespace
+        )),
         count_generic(Api::<Namespace>::all(client.clone())),
         count_generic(Api::<Node>::all(client.clone())),
-        count_generic(Api::<Event>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<ResourceQuota>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<LimitRange>::namespaced(client.clone(), namespace)),
-        count_generic(Api::<PodDisruptionBudget>::namespaced(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<Event>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<ResourceQuota>(
+            client.clone(),
+            namespace
+        )),
+        count_generic(namespaced_or_all::<LimitRange>(client.clone(), namespace)),
+        count_generic(namespaced_or_all::<PodDisruptionBudget>(
+            client.clone(),
+            namespace
+        )),
     );
 
     let mut counts = HashMap::new();
@@ -487,18 +899,31 @@ pub async fn describe_resource(
     namespace: &str,
     name: &str,
     resource_type: ResourceType,
+    reveal_secrets: bool,
 ) -> Result<String> {
     match resource_type {
         // Specialized describe for common types
         ResourceType::Pods => describe_pod(client, namespace, name).await,
         ResourceType::PersistentVolumeClaims => describe_pvc(client, namespace, name).await,
         ResourceType::StatefulSets => describe_statefulset(client, namespace, name).await,
+        ResourceType::Services => describe_service(client, namespace, name).await,
+        ResourceType::Nodes => describe_node(client, name).await,
+        ResourceType::ConfigMaps => describe_configmap(client, namespace, name).await,
+        ResourceType::Secrets => describe_secret(client, namespace, name, reveal_secrets).await,
         // Generic describe (YAML) for the rest - namespaced
         ResourceType::Deployments => {
-            describe_generic(Api::<Deployment>::namespaced(client, namespace), name).await
+            let api = Api::<Deployment>::namespaced(client.clone(), namespace);
+            describe_workload(client, api, namespace, name, |d: &Deployment| {
+                d.spec.as_ref().map(|s| &s.selector)
+            })
+            .await
         }
         ResourceType::DaemonSets => {
-            describe_generic(Api::<DaemonSet>::namespaced(client, namespace), name).await
+            let api = Api::<DaemonSet>::namespaced(client.clone(), namespace);
+            describe_workload(client, api, namespace, name, |ds: &DaemonSet| {
+                ds.spec.as_ref().map(|s| &s.selector)
+            })
+            .await
         }
         ResourceType::ReplicaSets => {
             describe_generic(Api::<ReplicaSet>::namespaced(client, namespace), name).await
@@ -523,24 +948,13 @@ pub async fn describe_resource(
             )
             .await
         }
-        ResourceType::Services => {
-            describe_generic(Api::<Service>::namespaced(client, namespace), name).await
-        }
         ResourceType::Endpoints => {
             describe_generic(Api::<Endpoints>::namespaced(client, namespace), name).await
         }
-        ResourceType::Ingresses => {
-            describe_generic(Api::<Ingress>::namespaced(client, namespace), name).await
-        }
+        ResourceType::Ingresses => describe_ingress(client, namespace, name).await,
         ResourceType::NetworkPolicies => {
             describe_generic(Api::<NetworkPolicy>::namespaced(client, namespace), name).await
         }
-        ResourceType::ConfigMaps => {
-            describe_generic(Api::<ConfigMap>::namespaced(client, namespace), name).await
-        }
-        ResourceType::Secrets => {
-            describe_generic(Api::<Secret>::namespaced(client, namespace), name).await
-        }
         ResourceType::ServiceAccounts => {
             describe_generic(Api::<ServiceAccount>::namespaced(client, namespace), name).await
         }
@@ -570,7 +984,111 @@ pub async fn describe_resource(
         ResourceType::Namespaces => {
             describe_generic(Api::<Namespace>::all(client), name).await
         }
-        ResourceType::Nodes => describe_generic(Api::<Node>::all(client), name).await,
+    }
+}
+
+/// Fetch an object and render it as YAML, for the detail YAML view and the
+/// editor. Rows don't keep their YAML, so this is only done on demand.
+/// Secret values are masked unless `reveal_secrets` is set.
+pub async fn get_yaml(
+    client: Client,
+    namespace: &str,
+    name: &str,
+    resource_type: ResourceType,
+    reveal_secrets: bool,
+) -> Result<String> {
+    match resource_type {
+        ResourceType::Pods => {
+            get_yaml_generic(Api::<Pod>::namespaced(client, namespace), name).await
+        }
+        ResourceType::Deployments => {
+            get_yaml_generic(Api::<Deployment>::namespaced(client, namespace), name).await
+        }
+        ResourceType::StatefulSets => {
+            get_yaml_generic(Api::<StatefulSet>::namespaced(client, namespace), name).await
+        }
+        ResourceType::DaemonSets => {
+            get_yaml_generic(Api::<DaemonSet>::namespaced(client, namespace), name).await
+        }
+        ResourceType::ReplicaSets => {
+            get_yaml_generic(Api::<ReplicaSet>::namespaced(client, namespace), name).await
+        }
+        ResourceType::ReplicationControllers => {
+            get_yaml_generic(
+                Api::<ReplicationController>::namespaced(client, namespace),
+                name,
+            )
+            .await
+        }
+        ResourceType::Jobs => {
+            get_yaml_generic(Api::<Job>::namespaced(client, namespace), name).await
+        }
+        ResourceType::CronJobs => {
+            get_yaml_generic(Api::<CronJob>::namespaced(client, namespace), name).await
+        }
+        ResourceType::HorizontalPodAutoscalers => {
+            get_yaml_generic(
+                Api::<HorizontalPodAutoscaler>::namespaced(client, namespace),
+                name,
+            )
+            .await
+        }
+        ResourceType::Services => {
+            get_yaml_generic(Api::<Service>::namespaced(client, namespace), name).await
+        }
+        ResourceType::Endpoints => {
+            get_yaml_generic(Api::<Endpoints>::namespaced(client, namespace), name).await
+        }
+        ResourceType::Ingresses => {
+            get_yaml_generic(Api::<Ingress>::namespaced(client, namespace), name).await
+        }
+        ResourceType::NetworkPolicies => {
+            get_yaml_generic(Api::<NetworkPolicy>::namespaced(client, namespace), name).await
+        }
+        ResourceType::ConfigMaps => {
+            get_yaml_generic(Api::<ConfigMap>::namespaced(client, namespace), name).await
+        }
+        ResourceType::Secrets => {
+            let secret = Api::<Secret>::namespaced(client, namespace)
+                .get(name)
+                .await?;
+            secret_yaml(&secret, reveal_secrets)
+        }
+        ResourceType::PersistentVolumeClaims => {
+            get_yaml_generic(
+                Api::<PersistentVolumeClaim>::namespaced(client, namespace),
+                name,
+            )
+            .await
+        }
+        ResourceType::ServiceAccounts => {
+            get_yaml_generic(Api::<ServiceAccount>::namespaced(client, namespace), name).await
+        }
+        ResourceType::Events => {
+            get_yaml_generic(Api::<Event>::namespaced(client, namespace), name).await
+        }
+        ResourceType::ResourceQuotas => {
+            get_yaml_generic(Api::<ResourceQuota>::namespaced(client, namespace), name).await
+        }
+        ResourceType::LimitRanges => {
+            get_yaml_generic(Api::<LimitRange>::namespaced(client, namespace), name).await
+        }
+        ResourceType::PodDisruptionBudgets => {
+            get_yaml_generic(
+                Api::<PodDisruptionBudget>::namespaced(client, namespace),
+                name,
+            )
+            .await
+        }
+        // Cluster-scoped
+        ResourceType::PersistentVolumes => {
+            get_yaml_generic(Api::<PersistentVolume>::all(client), name).await
+        }
+        ResourceType::StorageClasses => {
+            get_yaml_generic(Api::<StorageClass>::all(client), name).await
+        }
+        ResourceType::Namespaces => get_yaml_generic(Api::<Namespace>::all(client), name).await,
+        ResourceType::Nodes => get_yaml_generic(Api::<Node>::all(client), name).await,
     }
 }
 
@@ -617,13 +1135,183 @@ async fn describe_pod(client: Client, namespace: &str, name: &str) -> Result<Str
         }
     }
 
-    if let Some(ref spec) = pod.spec {
-        if let Some(ref node_name) = spec.node_name {
-            desc.push_str(&format!("\nNode:         {}\n", node_name));
-        }
-    }
-
-    let events = fetch_events(client, namespace, name).await;
+    if let Some(ref spec) = pod.spec {
+        if let Some(ref node_name) = spec.node_name {
+            desc.push_str(&format!("\nNode:         {}\n", node_name));
+        }
+    }
+
+    let metrics_api: Api<DynamicObject> =
+        Api::namespaced_with(client.clone(), namespace, &pod_metrics_resource());
+    if let Ok(metrics) = metrics_api.get(name).await {
+        let (cpu, mem) = pod_usage(&metrics);
+        desc.push_str(&format!("\nUsage:        CPU {}, Memory {}\n", cpu, mem));
+    }
+
+    let events = fetch_events(client, namespace, name).await;
+    if !events.is_empty() {
+        desc.push_str("\nEvents:\n");
+        for event in &events {
+            desc.push_str(&format!("  {}\n", event));
+        }
+    }
+
+    desc.push_str("\n--- Full YAML ---\n");
+    if let Ok(yaml) = serde_yaml::to_string(&pod) {
+        desc.push_str(&yaml);
+    }
+
+    Ok(desc)
+}
+
+async fn describe_pvc(client: Client, namespace: &str, name: &str) -> Result<String> {
+    let api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), namespace);
+    let pvc = api.get(name).await?;
+
+    let mut desc = String::new();
+    desc.push_str(&format!("Name:         {}\n", name));
+    desc.push_str(&format!("Namespace:    {}\n", namespace));
+
+    if let Some(ref status) = pvc.status {
+        let phase = status.phase.as_deref().unwrap_or("Unknown");
+        desc.push_str(&format!("Status:       {}\n", phase));
+
+        if let Some(ref capacity) = status.capacity {
+            if let Some(storage) = capacity.get("storage") {
+                desc.push_str(&format!("Capacity:     {}\n", storage.0));
+            }
+        }
+    }
+
+    if let Some(ref spec) = pvc.spec {
+        if let Some(ref volume_name) = spec.volume_name {
+            desc.push_str(&format!("Volume:       {}\n", volume_name));
+        }
+        if let Some(ref sc) = spec.storage_class_name {
+            desc.push_str(&format!("StorageClass: {}\n", sc));
+        }
+        if let Some(ref access_modes) = spec.access_modes {
+            desc.push_str(&format!("AccessModes:  {:?}\n", access_modes));
+        }
+    }
+
+    let events = fetch_events(client, namespace, name).await;
+    if !events.is_empty() {
+        desc.push_str("\nEvents:\n");
+        for event in &events {
+            desc.push_str(&format!("  {}\n", event));
+        }
+    }
+
+    desc.push_str("\n--- Full YAML ---\n");
+    if let Ok(yaml) = serde_yaml::to_string(&pvc) {
+        desc.push_str(&yaml);
+    }
+
+    Ok(desc)
+}
+
+async fn describe_statefulset(client: Client, namespace: &str, name: &str) -> Result<String> {
+    let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let ss = api.get(name).await?;
+
+    let mut desc = String::new();
+    desc.push_str(&format!("Name:         {}\n", name));
+    desc.push_str(&format!("Namespace:    {}\n", namespace));
+
+    if let Some(ref status) = ss.status {
+        desc.push_str(&format!("Replicas:     {}\n", status.replicas));
+        desc.push_str(&format!(
+            "Ready:        {}\n",
+            status.ready_replicas.unwrap_or(0)
+        ));
+        desc.push_str(&format!(
+            "Updated:      {}\n",
+            status.updated_replicas.unwrap_or(0)
+        ));
+    }
+
+    if let Some(ref spec) = ss.spec {
+        if let Some(replicas) = spec.replicas {
+            desc.push_str(&format!("Desired:      {}\n", replicas));
+        }
+        desc.push_str(&format!(
+            "ServiceName:  {}\n",
+            spec.service_name.as_deref().unwrap_or("<none>")
+        ));
+
+        desc.push_str(&selector_pods_section(client.clone(), namespace, &spec.selector).await);
+    }
+
+    let events = fetch_events(client, namespace, name).await;
+    if !events.is_empty() {
+        desc.push_str("\nEvents:\n");
+        for event in &events {
+            desc.push_str(&format!("  {}\n", event));
+        }
+    }
+
+    desc.push_str("\n--- Full YAML ---\n");
+    if let Ok(yaml) = serde_yaml::to_string(&ss) {
+        desc.push_str(&yaml);
+    }
+
+    Ok(desc)
+}
+
+/// Nodes are cluster-scoped, so there is no namespace to describe them in.
+async fn describe_node(client: Client, name: &str) -> Result<String> {
+    let api: Api<Node> = Api::all(client.clone());
+    let node = api.get(name).await?;
+
+    let mut desc = String::new();
+    desc.push_str(&format!("Name:         {}\n", name));
+
+    if let Some(ref status) = node.status {
+        if let Some(ref info) = status.node_info {
+            desc.push_str(&format!("Kubelet:      {}\n", info.kubelet_version));
+            desc.push_str(&format!("OS Image:     {}\n", info.os_image));
+            desc.push_str(&format!(
+                "Runtime:      {}\n",
+                info.container_runtime_version
+            ));
+        }
+
+        desc.push_str("\nResources:    ALLOCATABLE / CAPACITY\n");
+        for resource in ["cpu", "memory", "pods"] {
+            let allocatable = status
+                .allocatable
+                .as_ref()
+                .and_then(|a| a.get(resource))
+                .map(|q| q.0.as_str())
+                .unwrap_or("<none>");
+            let capacity = status
+                .capacity
+                .as_ref()
+                .and_then(|c| c.get(resource))
+                .map(|q| q.0.as_str())
+                .unwrap_or("<none>");
+            desc.push_str(&format!(
+                "  {:<10} {} / {}\n",
+                resource, allocatable, capacity
+            ));
+        }
+
+        if let Some(ref conditions) = status.conditions {
+            desc.push_str("\nConditions:\n");
+            for cond in conditions {
+                desc.push_str(&format!(
+                    "  {:<20} {:<6} {}\n",
+                    cond.type_,
+                    cond.status,
+                    cond.message.as_deref().unwrap_or("")
+                ));
+            }
+        }
+    }
+
+    // Node events are recorded in the default namespace
+    let events = fetch_events(client, "default", name).await;
     if !events.is_empty() {
         desc.push_str("\nEvents:\n");
         for event in &events {
@@ -632,44 +1320,177 @@ async fn describe_pod(client: Client, namespace: &str, name: &str) -> Result<Str
     }
 
     desc.push_str("\n--- Full YAML ---\n");
-    if let Ok(yaml) = serde_yaml::to_string(&pod) {
+    if let Ok(yaml) = serde_yaml::to_string(&node) {
         desc.push_str(&yaml);
     }
 
     Ok(desc)
 }
 
-async fn describe_pvc(client: Client, namespace: &str, name: &str) -> Result<String> {
-    let api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), namespace);
-    let pvc = api.get(name).await?;
+async fn describe_configmap(client: Client, namespace: &str, name: &str) -> Result<String> {
+    let api: Api<ConfigMap> = Api::namespaced(client, namespace);
+    let cm = api.get(name).await?;
 
     let mut desc = String::new();
     desc.push_str(&format!("Name:         {}\n", name));
     desc.push_str(&format!("Namespace:    {}\n", namespace));
 
-    if let Some(ref status) = pvc.status {
-        let phase = status.phase.as_deref().unwrap_or("Unknown");
-        desc.push_str(&format!("Status:       {}\n", phase));
+    desc.push_str("\nData:\n");
+    for (key, value) in cm.data.iter().flatten() {
+        desc.push_str(&format!("{}:\n----\n{}\n", key, value.trim_end()));
+    }
+    for (key, value) in cm.binary_data.iter().flatten() {
+        desc.push_str(&format!("{}: {} bytes (binary)\n", key, value.0.len()));
+    }
 
-        if let Some(ref capacity) = status.capacity {
-            if let Some(storage) = capacity.get("storage") {
-                desc.push_str(&format!("Capacity:     {}\n", storage.0));
+    desc.push_str("\n--- Full YAML ---\n");
+    if let Ok(yaml) = serde_yaml::to_string(&cm) {
+        desc.push_str(&yaml);
+    }
+
+    Ok(desc)
+}
+
+/// Describe a Secret. Values stay masked unless `reveal` is set, and the
+/// full YAML section is left out so nothing decoded leaks by accident.
+async fn describe_secret(
+    client: Client,
+    namespace: &str,
+    name: &str,
+    reveal: bool,
+) -> Result<String> {
+    let api: Api<Secret> = Api::namespaced(client, namespace);
+    let secret = api.get(name).await?;
+
+    let mut desc = String::new();
+    desc.push_str(&format!("Name:         {}\n", name));
+    desc.push_str(&format!("Namespace:    {}\n", namespace));
+    desc.push_str(&format!(
+        "Type:         {}\n",
+        secret.type_.as_deref().unwrap_or("Opaque")
+    ));
+
+    desc.push_str("\nData:\n");
+    for (key, value) in secret.data.iter().flatten() {
+        desc.push_str(&format!("{}\n", secret_value_line(key, &value.0, reveal)));
+    }
+
+    Ok(desc)
+}
+
+/// Annotation `kubectl apply` keeps the last applied object in, values and all.
+const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// A Secret as YAML. Unless `reveal` is set its values are masked as in
+/// `describe_secret`, and so is the last applied configuration, which
+/// repeats them.
+pub fn secret_yaml(secret: &Secret, reveal: bool) -> Result<String> {
+    let mut value = serde_yaml::to_value(secret).context("Failed to serialize YAML")?;
+    if !reveal {
+        if let Some(data) = value.get_mut("data").and_then(|d| d.as_mapping_mut()) {
+            for (key, v) in data.iter_mut() {
+                let len = key
+                    .as_str()
+                    .and_then(|k| secret.data.as_ref()?.get(k))
+                    .map_or(0, |bytes| bytes.0.len());
+                *v = format!("<hidden, {} bytes>", len).into();
+            }
+        }
+        if let Some(data) = value.get_mut("stringData").and_then(|d| d.as_mapping_mut()) {
+            for (_, v) in data.iter_mut() {
+                let len = v.as_str().map_or(0, str::len);
+                *v = format!("<hidden, {} bytes>", len).into();
             }
         }
+        let last_applied = value
+            .get_mut("metadata")
+            .and_then(|m| m.get_mut("annotations"))
+            .and_then(|a| a.get_mut(LAST_APPLIED_ANNOTATION));
+        if let Some(last_applied) = last_applied {
+            *last_applied = "<hidden>".into();
+        }
     }
+    serde_yaml::to_string(&value).context("Failed to serialize YAML")
+}
 
-    if let Some(ref spec) = pvc.spec {
-        if let Some(ref volume_name) = spec.volume_name {
-            desc.push_str(&format!("Volume:       {}\n", volume_name));
-        }
-        if let Some(ref sc) = spec.storage_class_name {
-            desc.push_str(&format!("StorageClass: {}\n", sc));
+/// One `key: value` line of a Secret's data. `ByteString` has already been
+/// base64-decoded, so revealing is just a UTF-8 conversion.
+fn secret_value_line(key: &str, value: &[u8], reveal: bool) -> String {
+    if !reveal {
+        return format!("{}: <hidden, {} bytes>", key, value.len());
+    }
+    match std::str::from_utf8(value) {
+        Ok(text) if text.contains('\n') => {
+            format!("{}:\n  {}", key, text.trim_end().replace('\n', "\n  "))
         }
-        if let Some(ref access_modes) = spec.access_modes {
-            desc.push_str(&format!("AccessModes:  {:?}\n", access_modes));
+        Ok(text) => format!("{}: {}", key, text),
+        Err(_) => format!("{}: <binary, {} bytes>", key, value.len()),
+    }
+}
+
+async fn describe_service(client: Client, namespace: &str, name: &str) -> Result<String> {
+    let api: Api<Service> = Api::namespaced(client.clone(), namespace);
+    let svc = api.get(name).await?;
+
+    let mut desc = String::new();
+    desc.push_str(&format!("Name:         {}\n", name));
+    desc.push_str(&format!("Namespace:    {}\n", namespace));
+
+    if let Some(ref spec) = svc.spec {
+        desc.push_str(&format!(
+            "Type:         {}\n",
+            spec.type_.as_deref().unwrap_or("ClusterIP")
+        ));
+        desc.push_str(&format!(
+            "ClusterIP:    {}\n",
+            spec.cluster_ip.as_deref().unwrap_or("<none>")
+        ));
+        let selector = spec
+            .selector
+            .as_ref()
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .unwrap_or_else(|| "<none>".to_string());
+        desc.push_str(&format!("Selector:     {}\n", selector));
+        if let Some(ref ports) = spec.ports {
+            desc.push_str("Ports:\n");
+            for p in ports {
+                let target = p
+                    .target_port
+                    .as_ref()
+                    .map(|t| match t {
+                        IntOrString::Int(i) => i.to_string(),
+                        IntOrString::String(s) => s.clone(),
+                    })
+                    .unwrap_or_else(|| p.port.to_string());
+                desc.push_str(&format!(
+                    "  {} {}/{} -> {}\n",
+                    p.name.as_deref().unwrap_or("<unset>"),
+                    p.port,
+                    p.protocol.as_deref().unwrap_or("TCP"),
+                    target
+                ));
+            }
         }
     }
 
+    // Endpoints share the service's name; missing endpoints just mean none are ready
+    let endpoints_api: Api<Endpoints> = Api::namespaced(client.clone(), namespace);
+    let addresses = match endpoints_api.get_opt(name).await {
+        Ok(Some(ep)) => endpoint_addresses(&ep),
+        _ => Vec::new(),
+    };
+    if addresses.is_empty() {
+        desc.push_str("Endpoints:    <none>\n");
+    } else {
+        desc.push_str(&format!("Endpoints:    {}\n", addresses.join(",")));
+    }
+
     let events = fetch_events(client, namespace, name).await;
     if !events.is_empty() {
         desc.push_str("\nEvents:\n");
@@ -679,41 +1500,63 @@ async fn describe_pvc(client: Client, namespace: &str, name: &str) -> Result<Str
     }
 
     desc.push_str("\n--- Full YAML ---\n");
-    if let Ok(yaml) = serde_yaml::to_string(&pvc) {
+    if let Ok(yaml) = serde_yaml::to_string(&svc) {
         desc.push_str(&yaml);
     }
 
     Ok(desc)
 }
 
-async fn describe_statefulset(client: Client, namespace: &str, name: &str) -> Result<String> {
-    let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
-    let ss = api.get(name).await?;
+async fn describe_ingress(client: Client, namespace: &str, name: &str) -> Result<String> {
+    let api: Api<Ingress> = Api::namespaced(client.clone(), namespace);
+    let ing = api.get(name).await?;
 
     let mut desc = String::new();
     desc.push_str(&format!("Name:         {}\n", name));
     desc.push_str(&format!("Namespace:    {}\n", namespace));
 
-    if let Some(ref status) = ss.status {
-        desc.push_str(&format!("Replicas:     {}\n", status.replicas));
-        desc.push_str(&format!(
-            "Ready:        {}\n",
-            status.ready_replicas.unwrap_or(0)
-        ));
+    let addresses = ingress_addresses(&ing);
+    let address = if addresses.is_empty() {
+        "<none>".to_string()
+    } else {
+        addresses.join(",")
+    };
+    if let Some(ref spec) = ing.spec {
         desc.push_str(&format!(
-            "Updated:      {}\n",
-            status.updated_replicas.unwrap_or(0)
+            "Class:        {}\n",
+            spec.ingress_class_name.as_deref().unwrap_or("<none>")
         ));
-    }
-
-    if let Some(ref spec) = ss.spec {
-        if let Some(replicas) = spec.replicas {
-            desc.push_str(&format!("Desired:      {}\n", replicas));
+        desc.push_str(&format!("Address:      {}\n", address));
+        if let Some(ref backend) = spec.default_backend {
+            desc.push_str(&format!("Default:      {}\n", ingress_backend(backend)));
+        }
+        for tls in spec.tls.iter().flatten() {
+            desc.push_str(&format!(
+                "TLS:          {} terminates {}\n",
+                tls.secret_name.as_deref().unwrap_or("<none>"),
+                tls.hosts
+                    .as_ref()
+                    .map(|h| h.join(","))
+                    .unwrap_or_else(|| "*".to_string())
+            ));
+        }
+        // Host -> path -> backend, like `kubectl describe ingress`
+        desc.push_str("Rules:\n");
+        let rules = spec.rules.as_deref().unwrap_or_default();
+        if rules.is_empty() {
+            desc.push_str("  <none>\n");
+        }
+        for rule in rules {
+            desc.push_str(&format!("  {}\n", rule.host.as_deref().unwrap_or("*")));
+            for path in rule.http.iter().flat_map(|http| &http.paths) {
+                desc.push_str(&format!(
+                    "    {} ({}) -> {}\n",
+                    path.path.as_deref().unwrap_or("/"),
+                    path.path_type,
+                    ingress_backend(&path.backend)
+                ));
+            }
         }
-        desc.push_str(&format!(
-            "ServiceName:  {}\n",
-            spec.service_name.as_deref().unwrap_or("<none>")
-        ));
     }
 
     let events = fetch_events(client, namespace, name).await;
@@ -725,66 +1568,328 @@ async fn describe_statefulset(client: Client, namespace: &str, name: &str) -> Re
     }
 
     desc.push_str("\n--- Full YAML ---\n");
-    if let Ok(yaml) = serde_yaml::to_string(&ss) {
+    if let Ok(yaml) = serde_yaml::to_string(&ing) {
         desc.push_str(&yaml);
     }
 
     Ok(desc)
 }
 
+/// Load balancer IPs or hostnames the ingress controller published.
+fn ingress_addresses(ing: &Ingress) -> Vec<String> {
+    ing.status
+        .as_ref()
+        .and_then(|s| s.load_balancer.as_ref())
+        .and_then(|lb| lb.ingress.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|i| i.ip.clone().or_else(|| i.hostname.clone()))
+        .collect()
+}
+
+/// "service:port", or "Kind/name" for a resource backend.
+fn ingress_backend(backend: &IngressBackend) -> String {
+    if let Some(ref svc) = backend.service {
+        let port = svc.port.as_ref().and_then(|p| match (&p.name, p.number) {
+            (Some(name), _) => Some(name.clone()),
+            (None, Some(number)) => Some(number.to_string()),
+            (None, None) => None,
+        });
+        return match port {
+            Some(port) => format!("{}:{}", svc.name, port),
+            None => svc.name.clone(),
+        };
+    }
+    match backend.resource {
+        Some(ref res) => format!("{}/{}", res.kind, res.name),
+        None => "<none>".to_string(),
+    }
+}
+
+/// Ready endpoint addresses as "ip:port" pairs.
+fn endpoint_addresses(ep: &Endpoints) -> Vec<String> {
+    let mut out = Vec::new();
+    for subset in ep.subsets.iter().flatten() {
+        let ports: Vec<i32> = subset.ports.iter().flatten().map(|p| p.port).collect();
+        for addr in subset.addresses.iter().flatten() {
+            if ports.is_empty() {
+                out.push(addr.ip.clone());
+            }
+            for port in &ports {
+                out.push(format!("{}:{}", addr.ip, port));
+            }
+        }
+    }
+    out
+}
+
+/// Events about `resource_name`, newest first, in the style of `kubectl
+/// describe`: "Warning  BackOff  2m (x5 over 10m)  Back-off restarting...".
+/// Separate events with the same type, reason and message are merged into
+/// one line, and events without any timestamp sort last.
 async fn fetch_events(client: Client, namespace: &str, resource_name: &str) -> Vec<String> {
     let events_api: Api<Event> = Api::namespaced(client, namespace);
     let lp = ListParams::default().fields(&format!("involvedObject.name={}", resource_name));
 
-    match events_api.list(&lp).await {
-        Ok(event_list) => event_list
-            .items
-            .iter()
-            .map(|e| {
-                let type_ = e.type_.as_deref().unwrap_or("Normal");
-                let reason = e.reason.as_deref().unwrap_or("");
-                let message = e.message.as_deref().unwrap_or("");
-                format!("{} {} {}", type_, reason, message)
-            })
-            .collect(),
-        Err(_) => Vec::new(),
+    let Ok(event_list) = events_api.list(&lp).await else {
+        return Vec::new();
+    };
+    event_lines(&event_list.items)
+}
+
+/// One line per distinct (type, reason, message), newest first, with
+/// repeats merged into "(xN over M)". Events without a timestamp go last.
+pub fn event_lines(events: &[Event]) -> Vec<String> {
+    // (type, reason, message) -> (count, first seen, last seen)
+    type Seen = (i32, Option<i64>, Option<i64>);
+    let mut merged: Vec<((String, String, String), Seen)> = Vec::new();
+    for e in events {
+        let key = (
+            e.type_.clone().unwrap_or_else(|| "Normal".to_string()),
+            e.reason.clone().unwrap_or_default(),
+            e.message.clone().unwrap_or_default(),
+        );
+        let last = creation_time(e.last_timestamp.as_ref())
+            .or_else(|| e.event_time.as_ref().map(|t| t.0.as_second()))
+            .or_else(|| creation_time(e.metadata.creation_timestamp.as_ref()));
+        let first = creation_time(e.first_timestamp.as_ref()).or(last);
+        let count = e.count.unwrap_or(1).max(1);
+        match merged.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, seen)) => {
+                seen.0 += count;
+                seen.1 = seen.1.into_iter().chain(first).min();
+                seen.2 = seen.2.max(last);
+            }
+            None => merged.push((key, (count, first, last))),
+        }
+    }
+    // Newest first; `Reverse` keeps events without a timestamp (None) last
+    merged.sort_by_key(|(_, (_, _, last))| std::cmp::Reverse(*last));
+
+    merged
+        .into_iter()
+        .map(|((type_, reason, message), (count, first, last))| {
+            let age = if count > 1 {
+                format!(
+                    "{} (x{} over {})",
+                    format_age(last),
+                    count,
+                    format_age(first)
+                )
+            } else {
+                format_age(last)
+            };
+            format!("{:<8} {:<20} {:<20} {}", type_, reason, age, message)
+        })
+        .collect()
+}
+
+/// The "Pods:" section of a workload's description: the pods its selector
+/// matches, or why they couldn't be listed. An empty selector would match
+/// every pod in the namespace, so it lists none.
+async fn selector_pods_section(
+    client: Client,
+    namespace: &str,
+    selector: &LabelSelector,
+) -> String {
+    let lines = match label_selector_string(selector) {
+        Ok(labels) if labels.is_empty() => return String::new(),
+        Ok(labels) => {
+            let api: Api<Pod> = Api::namespaced(client, namespace);
+            match api.list(&ListParams::default().labels(&labels)).await {
+                Ok(list) => selector_pod_lines(&list.items),
+                Err(e) => vec![format!("<failed to list pods: {}>", e)],
+            }
+        }
+        Err(e) => vec![format!("<{}>", e)],
+    };
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut desc = String::from("\nPods:\n");
+    for line in &lines {
+        desc.push_str(&format!("  {}\n", line));
+    }
+    desc
+}
+
+/// One line per pod with status, restarts and node, sorted by name.
+pub fn selector_pod_lines(pods: &[Pod]) -> Vec<String> {
+    let mut items: Vec<ResourceItem> = pods.iter().map(pod_to_resource_item).collect();
+    items.sort_by(|a, b| a.name.cmp(&b.name));
+    let width = items.iter().map(|item| item.name.len()).max().unwrap_or(0);
+    items
+        .iter()
+        .map(|item| {
+            let extra = |key: &str| {
+                item.extra
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map_or("<none>", |(_, v)| v.as_str())
+            };
+            format!(
+                "{:<width$}  {:<18} restarts {:<4} {}",
+                item.name,
+                item.status,
+                extra("restarts"),
+                extra("node"),
+            )
+        })
+        .collect()
+}
+
+/// Only the events of a resource, for the quick events view. Cluster-scoped
+/// resources record theirs in the default namespace.
+pub async fn describe_events(
+    client: Client,
+    namespace: &str,
+    name: &str,
+    resource_type: ResourceType,
+) -> String {
+    let namespace = if resource_type.is_cluster_scoped() {
+        "default"
+    } else {
+        namespace
+    };
+    let events = fetch_events(client, namespace, name).await;
+    if events.is_empty() {
+        return format!("No events for {}/{}\n", resource_type, name);
     }
+    let mut desc = format!("Events for {}/{} (newest first):\n\n", resource_type, name);
+    for event in &events {
+        desc.push_str(&format!("  {}\n", event));
+    }
+    desc
 }
 
 // ---------------------------------------------------------------------------
 // Converter functions
 // ---------------------------------------------------------------------------
 
+/// The STATUS `kubectl get pods` shows: the init container being waited on
+/// (`Init:0/2`, `Init:CrashLoopBackOff`), else why a container is waiting or
+/// exited (`CrashLoopBackOff`, `OOMKilled`, `Completed`), else the pod's
+/// own reason (`Evicted`) or phase. Pods being deleted are `Terminating`.
+pub fn pod_status(pod: &Pod) -> String {
+    let Some(status) = pod.status.as_ref() else {
+        return "Unknown".to_string();
+    };
+    let mut reason = status
+        .reason
+        .clone()
+        .or_else(|| status.phase.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let init_total = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.init_containers.as_ref())
+        .map_or(0, Vec::len);
+    let mut initializing = false;
+    for (i, container) in status.init_container_statuses.iter().flatten().enumerate() {
+        let state = container.state.as_ref();
+        let terminated = state.and_then(|st| st.terminated.as_ref());
+        let waiting = state
+            .and_then(|st| st.waiting.as_ref())
+            .and_then(|w| w.reason.as_deref());
+        reason = match (terminated, waiting) {
+            (Some(t), _) if t.exit_code == 0 => continue,
+            (Some(t), _) => format!("Init:{}", exit_reason(t)),
+            (None, Some(waiting)) if waiting != "PodInitializing" => format!("Init:{}", waiting),
+            _ => format!("Init:{}/{}", i, init_total),
+        };
+        initializing = true;
+        break;
+    }
+    if !initializing {
+        // The first container with something to say wins, as in kubectl
+        let mut running = false;
+        for container in status.container_statuses.iter().flatten().rev() {
+            let Some(state) = container.state.as_ref() else {
+                continue;
+            };
+            if let Some(waiting) = state.waiting.as_ref().and_then(|w| w.reason.clone()) {
+                reason = waiting;
+            } else if let Some(terminated) = state.terminated.as_ref() {
+                reason = exit_reason(terminated);
+            } else if container.ready && state.running.is_some() {
+                running = true;
+            }
+        }
+        // A finished sidecar doesn't finish the pod while others still run
+        if reason == "Completed" && running {
+            let ready = status
+                .conditions
+                .iter()
+                .flatten()
+                .any(|c| c.type_ == "Ready" && c.status == "True");
+            reason = if ready { "Running" } else { "NotReady" }.to_string();
+        }
+    }
+
+    if pod.metadata.deletion_timestamp.is_some() {
+        reason = if status.reason.as_deref() == Some("NodeLost") {
+            "Unknown".to_string()
+        } else {
+            "Terminating".to_string()
+        };
+    }
+    reason
+}
+
+/// Why a container exited: its reason, or the signal or exit code.
+fn exit_reason(terminated: &ContainerStateTerminated) -> String {
+    match (&terminated.reason, terminated.signal) {
+        (Some(reason), _) if !reason.is_empty() => reason.clone(),
+        (_, Some(signal)) if signal != 0 => format!("Signal:{}", signal),
+        _ => format!("ExitCode:{}", terminated.exit_code),
+    }
+}
+
+/// How the first waiting or stopped container last exited, e.g. "Error: 137"
+/// for a crash-looping one, so the list says why without a describe.
+pub fn last_exit(statuses: &[ContainerStatus]) -> Option<String> {
+    statuses
+        .iter()
+        .find(|c| {
+            c.state
+                .as_ref()
+                .is_some_and(|st| st.waiting.is_some() || st.terminated.is_some())
+        })
+        .and_then(|c| c.last_state.as_ref()?.terminated.as_ref())
+        .map(|t| format!("{}: {}", t.reason.as_deref().unwrap_or("Exit"), t.exit_code))
+}
+
 fn pod_to_resource_item(pod: &Pod) -> ResourceItem {
     let name = ResourceExt::name_any(pod);
     let namespace = ResourceExt::namespace(pod).unwrap_or_default();
 
-    let (status, restarts, node) = if let Some(ref s) = pod.status {
-        let phase = s.phase.clone().unwrap_or_else(|| "Unknown".to_string());
+    let ip = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.pod_ip.clone())
+        .unwrap_or_else(|| "<none>".to_string());
+    let (status, ready, restarts, node) = if let Some(ref s) = pod.status {
+        let status = pod_status(pod);
 
-        let status = s
+        let restart_count: i32 = s
             .container_statuses
             .as_ref()
-            .and_then(|cs| {
-                cs.iter().find_map(|c| {
-                    c.state.as_ref().and_then(|state| {
-                        if let Some(ref w) = state.waiting {
-                            Some(w.reason.clone().unwrap_or_else(|| "Waiting".to_string()))
-                        } else if state.terminated.is_some() {
-                            Some("Terminated".to_string())
-                        } else {
-                            None
-                        }
-                    })
-                })
-            })
-            .unwrap_or(phase);
+            .map(|cs| cs.iter().map(|c| c.restart_count).sum())
+            .unwrap_or(0);
 
-        let restart_count: i32 = s
+        // Spec containers are the total, so pods not started yet show 0/N
+        let total = pod
+            .spec
+            .as_ref()
+            .map(|spec| spec.containers.len())
+            .unwrap_or(0);
+        let ready_count = s
             .container_statuses
             .as_ref()
-            .map(|cs| cs.iter().map(|c| c.restart_count).sum())
+            .map(|cs| cs.iter().filter(|c| c.ready).count())
             .unwrap_or(0);
+        let ready = format!("{}/{}", ready_count, total);
 
         let node_name = pod
             .spec
@@ -792,24 +1897,56 @@ fn pod_to_resource_item(pod: &Pod) -> ResourceItem {
             .and_then(|spec| spec.node_name.clone())
             .unwrap_or_else(|| "<none>".to_string());
 
-        (status, restart_count.to_string(), node_name)
+        (status, ready, restart_count.to_string(), node_name)
     } else {
-        ("Unknown".to_string(), "0".to_string(), "<none>".to_string())
+        let total = pod
+            .spec
+            .as_ref()
+            .map(|spec| spec.containers.len())
+            .unwrap_or(0);
+        (
+            "Unknown".to_string(),
+            format!("0/{}", total),
+            "0".to_string(),
+            "<none>".to_string(),
+        )
     };
 
-    let age = format_age(pod.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(pod).unwrap_or_default();
+    // The first container is the app container by convention; sidecars follow
+    let image = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.containers.first())
+        .and_then(|c| c.image.clone())
+        .unwrap_or_else(|| "<none>".to_string());
+
+    let last_exit = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.container_statuses.as_deref())
+        .and_then(last_exit);
+
+    let created = creation_time(pod.metadata.creation_timestamp.as_ref());
+    let spec = pod_spec_summary(pod);
+
+    let mut extra = vec![
+        ("restarts".to_string(), restarts),
+        ("node".to_string(), node),
+        ("ip".to_string(), ip),
+        ("ready".to_string(), ready),
+        ("image".to_string(), image),
+    ];
+    if let Some(last_exit) = last_exit {
+        extra.push(("last_exit".to_string(), last_exit));
+    }
 
     ResourceItem {
         name,
         namespace,
         status,
-        age,
-        extra: vec![
-            ("restarts".to_string(), restarts),
-            ("node".to_string(), node),
-        ],
-        raw_yaml,
+        created,
+        extra,
+        spec,
     }
 }
 
@@ -842,20 +1979,23 @@ fn deployment_to_resource_item(deploy: &Deployment) -> ResourceItem {
         )
     };
 
-    let age = format_age(deploy.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(deploy).unwrap_or_default();
+    let created = creation_time(deploy.metadata.creation_timestamp.as_ref());
+    let spec = workload_spec_summary(
+        deploy,
+        deploy.spec.as_ref().map(|s| (s.replicas, &s.template)),
+    );
 
     ResourceItem {
         name,
         namespace,
         status,
-        age,
+        created,
         extra: vec![
             ("ready".to_string(), ready),
             ("up-to-date".to_string(), up_to_date),
             ("available".to_string(), available),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -881,47 +2021,54 @@ fn statefulset_to_resource_item(ss: &StatefulSet) -> ResourceItem {
         ("Unknown".to_string(), "0/0".to_string())
     };
 
-    let age = format_age(ss.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(ss).unwrap_or_default();
+    let created = creation_time(ss.metadata.creation_timestamp.as_ref());
+    let spec = workload_spec_summary(ss, ss.spec.as_ref().map(|s| (s.replicas, &s.template)));
 
     ResourceItem {
         name,
         namespace,
         status,
-        age,
+        created,
         extra: vec![("ready".to_string(), ready)],
-        raw_yaml,
+        spec,
     }
 }
 
-fn daemonset_to_resource_item(ds: &DaemonSet) -> ResourceItem {
+pub fn daemonset_to_resource_item(ds: &DaemonSet) -> ResourceItem {
     let name = ResourceExt::name_any(ds);
     let namespace = ResourceExt::namespace(ds).unwrap_or_default();
 
-    let (desired, current, ready) = if let Some(ref s) = ds.status {
+    let (desired, current, ready, up_to_date) = if let Some(ref s) = ds.status {
         (
             s.desired_number_scheduled.to_string(),
             s.current_number_scheduled.to_string(),
             s.number_ready.to_string(),
+            s.updated_number_scheduled.unwrap_or(0).to_string(),
         )
     } else {
-        ("0".to_string(), "0".to_string(), "0".to_string())
+        (
+            "0".to_string(),
+            "0".to_string(),
+            "0".to_string(),
+            "0".to_string(),
+        )
     };
 
-    let age = format_age(ds.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(ds).unwrap_or_default();
+    let created = creation_time(ds.metadata.creation_timestamp.as_ref());
+    let spec = template_spec_summary(ds, ds.spec.as_ref().map(|s| &s.template));
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("desired".to_string(), desired),
             ("current".to_string(), current),
             ("ready".to_string(), ready),
+            ("up-to-date".to_string(), up_to_date),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -944,20 +2091,25 @@ fn replicaset_to_resource_item(rs: &ReplicaSet) -> ResourceItem {
         ("0".to_string(), "0".to_string(), "0".to_string())
     };
 
-    let age = format_age(rs.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(rs).unwrap_or_default();
+    let created = creation_time(rs.metadata.creation_timestamp.as_ref());
+    let spec = workload_spec_summary(
+        rs,
+        rs.spec
+            .as_ref()
+            .and_then(|s| Some((s.replicas, s.template.as_ref()?))),
+    );
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("desired".to_string(), desired),
             ("current".to_string(), current),
             ("ready".to_string(), ready),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -980,24 +2132,29 @@ fn replication_controller_to_resource_item(rc: &ReplicationController) -> Resour
         ("0".to_string(), "0".to_string(), "0".to_string())
     };
 
-    let age = format_age(rc.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(rc).unwrap_or_default();
+    let created = creation_time(rc.metadata.creation_timestamp.as_ref());
+    let spec = workload_spec_summary(
+        rc,
+        rc.spec
+            .as_ref()
+            .and_then(|s| Some((s.replicas, s.template.as_ref()?))),
+    );
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("desired".to_string(), desired),
             ("current".to_string(), current),
             ("ready".to_string(), ready),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
-fn job_to_resource_item(job: &Job) -> ResourceItem {
+pub fn job_to_resource_item(job: &Job) -> ResourceItem {
     let name = ResourceExt::name_any(job);
     let namespace = ResourceExt::namespace(job).unwrap_or_default();
 
@@ -1013,16 +2170,16 @@ fn job_to_resource_item(job: &Job) -> ResourceItem {
         "0/1".to_string()
     };
 
-    let age = format_age(job.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(job).unwrap_or_default();
+    let created = creation_time(job.metadata.creation_timestamp.as_ref());
+    let spec = template_spec_summary(job, job.spec.as_ref().map(|s| &s.template));
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![("completions".to_string(), completions)],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1048,20 +2205,20 @@ fn cronjob_to_resource_item(cj: &CronJob) -> ResourceItem {
         .map(|a| a.len().to_string())
         .unwrap_or_else(|| "0".to_string());
 
-    let age = format_age(cj.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(cj).unwrap_or_default();
+    let created = creation_time(cj.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(cj);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("schedule".to_string(), schedule),
             ("suspend".to_string(), suspend),
             ("active".to_string(), active),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1086,20 +2243,20 @@ fn hpa_to_resource_item(hpa: &HorizontalPodAutoscaler) -> ResourceItem {
         .map(|s| s.current_replicas.to_string())
         .unwrap_or_else(|| "0".to_string());
 
-    let age = format_age(hpa.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(hpa).unwrap_or_default();
+    let created = creation_time(hpa.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(hpa);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("minpods".to_string(), minpods),
             ("maxpods".to_string(), maxpods),
             ("replicas".to_string(), replicas),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1136,20 +2293,20 @@ fn service_to_resource_item(svc: &Service) -> ResourceItem {
         })
         .unwrap_or_else(|| "<none>".to_string());
 
-    let age = format_age(svc.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(svc).unwrap_or_default();
+    let created = creation_time(svc.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(svc);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("type".to_string(), svc_type),
             ("cluster-ip".to_string(), cluster_ip),
             ("ports".to_string(), ports),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1182,16 +2339,16 @@ fn endpoints_to_resource_item(ep: &Endpoints) -> ResourceItem {
         })
         .unwrap_or_else(|| "<none>".to_string());
 
-    let age = format_age(ep.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(ep).unwrap_or_default();
+    let created = creation_time(ep.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(ep);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![("endpoints".to_string(), endpoints)],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1216,20 +2373,27 @@ fn ingress_to_resource_item(ing: &Ingress) -> ResourceItem {
                 .join(",")
         })
         .unwrap_or_else(|| "*".to_string());
+    let address = ingress_addresses(ing);
+    let address = if address.is_empty() {
+        "<none>".to_string()
+    } else {
+        address.join(",")
+    };
 
-    let age = format_age(ing.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(ing).unwrap_or_default();
+    let created = creation_time(ing.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(ing);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("class".to_string(), class),
             ("hosts".to_string(), hosts),
+            ("address".to_string(), address),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1251,16 +2415,16 @@ fn network_policy_to_resource_item(np: &NetworkPolicy) -> ResourceItem {
         })
         .unwrap_or_else(|| "<all>".to_string());
 
-    let age = format_age(np.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(np).unwrap_or_default();
+    let created = creation_time(np.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(np);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![("pod-selector".to_string(), pod_selector)],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1271,16 +2435,16 @@ fn configmap_to_resource_item(cm: &ConfigMap) -> ResourceItem {
     let data_count = cm.data.as_ref().map(|d| d.len()).unwrap_or(0)
         + cm.binary_data.as_ref().map(|d| d.len()).unwrap_or(0);
 
-    let age = format_age(cm.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(cm).unwrap_or_default();
+    let created = creation_time(cm.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(cm);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![("data".to_string(), data_count.to_string())],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1294,19 +2458,19 @@ fn secret_to_resource_item(secret: &Secret) -> ResourceItem {
         .unwrap_or_else(|| "Opaque".to_string());
     let data_count = secret.data.as_ref().map(|d| d.len()).unwrap_or(0);
 
-    let age = format_age(secret.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(secret).unwrap_or_default();
+    let created = creation_time(secret.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(secret);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("type".to_string(), secret_type),
             ("data".to_string(), data_count.to_string()),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1336,19 +2500,19 @@ fn pvc_to_resource_item(pvc: &PersistentVolumeClaim) -> ResourceItem {
         )
     };
 
-    let age = format_age(pvc.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(pvc).unwrap_or_default();
+    let created = creation_time(pvc.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(pvc);
 
     ResourceItem {
         name,
         namespace,
         status,
-        age,
+        created,
         extra: vec![
             ("volume".to_string(), volume),
             ("capacity".to_string(), capacity),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1374,19 +2538,19 @@ fn pv_to_resource_item(pv: &PersistentVolume) -> ResourceItem {
         .and_then(|s| s.storage_class_name.clone())
         .unwrap_or_else(|| "<none>".to_string());
 
-    let age = format_age(pv.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(pv).unwrap_or_default();
+    let created = creation_time(pv.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(pv);
 
     ResourceItem {
         name,
         namespace,
         status,
-        age,
+        created,
         extra: vec![
             ("capacity".to_string(), capacity),
             ("storageclass".to_string(), storageclass),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1396,16 +2560,16 @@ fn storageclass_to_resource_item(sc: &StorageClass) -> ResourceItem {
 
     let provisioner = sc.provisioner.clone();
 
-    let age = format_age(sc.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(sc).unwrap_or_default();
+    let created = creation_time(sc.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(sc);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![("provisioner".to_string(), provisioner)],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1413,16 +2577,16 @@ fn serviceaccount_to_resource_item(sa: &ServiceAccount) -> ResourceItem {
     let name = ResourceExt::name_any(sa);
     let namespace = ResourceExt::namespace(sa).unwrap_or_default();
 
-    let age = format_age(sa.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(sa).unwrap_or_default();
+    let created = creation_time(sa.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(sa);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1435,16 +2599,16 @@ fn namespace_to_resource_item(ns: &Namespace) -> ResourceItem {
         .and_then(|s| s.phase.clone())
         .unwrap_or_else(|| "Unknown".to_string());
 
-    let age = format_age(ns.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(ns).unwrap_or_default();
+    let created = creation_time(ns.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(ns);
 
     ResourceItem {
         name,
         namespace: String::new(),
         status,
-        age,
+        created,
         extra: vec![],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1490,86 +2654,104 @@ fn node_to_resource_item(node: &Node) -> ResourceItem {
         roles
     };
 
-    let age = format_age(node.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(node).unwrap_or_default();
+    let version = node
+        .status
+        .as_ref()
+        .and_then(|s| s.node_info.as_ref())
+        .map(|info| info.kubelet_version.clone())
+        .unwrap_or_else(|| "<none>".to_string());
+
+    let created = creation_time(node.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(node);
 
     ResourceItem {
         name,
         namespace: String::new(),
         status,
-        age,
-        extra: vec![("roles".to_string(), roles)],
-        raw_yaml,
+        created,
+        extra: vec![
+            ("roles".to_string(), roles),
+            ("version".to_string(), version),
+        ],
+        spec,
     }
 }
 
+/// Rows are keyed by the event's own name so describe and YAML find it;
+/// OBJECT names what it is about. AGE counts from when it was last seen,
+/// since a repeating event keeps its first creation time.
 fn event_to_resource_item(ev: &Event) -> ResourceItem {
-    let name = ev
-        .involved_object
-        .name
-        .clone()
-        .unwrap_or_else(|| ResourceExt::name_any(ev));
+    let name = ResourceExt::name_any(ev);
     let namespace = ResourceExt::namespace(ev).unwrap_or_default();
 
-    let ev_type = ev
-        .type_
-        .clone()
-        .unwrap_or_else(|| "Normal".to_string());
-    let reason = ev
-        .reason
-        .clone()
-        .unwrap_or_else(|| "<none>".to_string());
-    let message = ev
-        .message
-        .clone()
-        .unwrap_or_else(|| "<none>".to_string());
+    let ev_type = ev.type_.clone().unwrap_or_else(|| "Normal".to_string());
+    let reason = ev.reason.clone().unwrap_or_else(|| "<none>".to_string());
+    let object = format!(
+        "{}/{}",
+        ev.involved_object
+            .kind
+            .as_deref()
+            .unwrap_or_default()
+            .to_lowercase(),
+        ev.involved_object.name.as_deref().unwrap_or_default()
+    );
+    let message = ev.message.clone().unwrap_or_else(|| "<none>".to_string());
 
-    let age = format_age(ev.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(ev).unwrap_or_default();
+    let last_seen = ev
+        .series
+        .as_ref()
+        .and_then(|s| s.last_observed_time.as_ref())
+        .map(|t| t.0.as_second())
+        .or_else(|| creation_time(ev.last_timestamp.as_ref()))
+        .or_else(|| ev.event_time.as_ref().map(|t| t.0.as_second()))
+        .or_else(|| creation_time(ev.metadata.creation_timestamp.as_ref()));
+    let spec = spec_summary(ev);
 
     ResourceItem {
         name,
         namespace,
-        status: String::new(),
-        age,
+        // Lets the problems toggle and `status:` filter pick out warnings
+        status: ev_type.clone(),
+        created: last_seen,
         extra: vec![
             ("type".to_string(), ev_type),
             ("reason".to_string(), reason),
+            ("object".to_string(), object),
             ("message".to_string(), message),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
 fn resourcequota_to_resource_item(rq: &ResourceQuota) -> ResourceItem {
     let name = ResourceExt::name_any(rq);
     let namespace = ResourceExt::namespace(rq).unwrap_or_default();
-    let age = format_age(rq.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(rq).unwrap_or_default();
+    let created = creation_time(rq.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(rq);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![],
-        raw_yaml,
+        spec,
     }
 }
 
 fn limitrange_to_resource_item(lr: &LimitRange) -> ResourceItem {
     let name = ResourceExt::name_any(lr);
     let namespace = ResourceExt::namespace(lr).unwrap_or_default();
-    let age = format_age(lr.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(lr).unwrap_or_default();
+    let created = creation_time(lr.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(lr);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1596,19 +2778,19 @@ fn pdb_to_resource_item(pdb: &PodDisruptionBudget) -> ResourceItem {
         })
         .unwrap_or_else(|| "N/A".to_string());
 
-    let age = format_age(pdb.metadata.creation_timestamp.as_ref());
-    let raw_yaml = serde_yaml::to_string(pdb).unwrap_or_default();
+    let created = creation_time(pdb.metadata.creation_timestamp.as_ref());
+    let spec = spec_summary(pdb);
 
     ResourceItem {
         name,
         namespace,
         status: String::new(),
-        age,
+        created,
         extra: vec![
             ("min-available".to_string(), min_available),
             ("max-unavailable".to_string(), max_unavailable),
         ],
-        raw_yaml,
+        spec,
     }
 }
 
@@ -1616,34 +2798,76 @@ fn pdb_to_resource_item(pdb: &PodDisruptionBudget) -> ResourceItem {
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn format_age(timestamp: Option<&Time>) -> String {
-    let Some(ts) = timestamp else {
-        return "<unknown>".to_string();
-    };
-
-    let now_secs = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs() as i64;
-    let ts_secs = ts.0.as_second();
-    let diff_secs = now_secs - ts_secs;
+/// Summary for types the list actions only need the owner of.
+fn spec_summary<T: Resource>(obj: &T) -> SpecSummary {
+    SpecSummary {
+        owner: owner_of(obj),
+        ..Default::default()
+    }
+}
 
-    if diff_secs < 0 {
-        return "0s".to_string();
+fn pod_spec_summary(pod: &Pod) -> SpecSummary {
+    let (containers, container_port) = containers_of(pod.spec.as_ref());
+    SpecSummary {
+        containers,
+        container_port,
+        replicas: None,
+        owner: owner_of(pod),
     }
+}
 
-    let days = diff_secs / 86400;
-    let hours = (diff_secs % 86400) / 3600;
-    let minutes = (diff_secs % 3600) / 60;
-    let seconds = diff_secs % 60;
+/// Summary for workloads with a replica count and a pod template, given as
+/// `(spec.replicas, spec.template)` when the object has a spec.
+fn workload_spec_summary<T: Resource>(
+    obj: &T,
+    spec: Option<(Option<i32>, &PodTemplateSpec)>,
+) -> SpecSummary {
+    let (containers, container_port) =
+        containers_of(spec.and_then(|(_, template)| template.spec.as_ref()));
+    SpecSummary {
+        containers,
+        container_port,
+        // Kubernetes defaults replicas to 1
+        replicas: spec.map(|(replicas, _)| i64::from(replicas.unwrap_or(1))),
+        owner: owner_of(obj),
+    }
+}
 
-    if days > 0 {
-        format!("{}d{}h", days, hours)
-    } else if hours > 0 {
-        format!("{}h{}m", hours, minutes)
-    } else if minutes > 0 {
-        format!("{}m", minutes)
-    } else {
-        format!("{}s", seconds)
+/// Summary for objects with a pod template but no replica count.
+fn template_spec_summary<T: Resource>(obj: &T, template: Option<&PodTemplateSpec>) -> SpecSummary {
+    let (containers, container_port) = containers_of(template.and_then(|t| t.spec.as_ref()));
+    SpecSummary {
+        containers,
+        container_port,
+        replicas: None,
+        owner: owner_of(obj),
     }
 }
+
+/// Container names in spec order and the first declared `containerPort`.
+fn containers_of(spec: Option<&PodSpec>) -> (Vec<String>, Option<u16>) {
+    let Some(spec) = spec else {
+        return (Vec::new(), None);
+    };
+    let names = spec.containers.iter().map(|c| c.name.clone()).collect();
+    let port = spec
+        .containers
+        .iter()
+        .flat_map(|c| c.ports.iter().flatten())
+        .find_map(|p| u16::try_from(p.container_port).ok());
+    (names, port)
+}
+
+/// `(kind, name)` of the owner, preferring the managing controller.
+fn owner_of<T: Resource>(obj: &T) -> Option<(String, String)> {
+    let refs = obj.meta().owner_references.as_ref()?;
+    let owner = refs
+        .iter()
+        .find(|r| r.controller == Some(true))
+        .or_else(|| refs.first())?;
+    Some((owner.kind.clone(), owner.name.clone()))
+}
+
+fn creation_time(timestamp: Option<&Time>) -> Option<i64> {
+    timestamp.map(|ts| ts.0.as_second())
+}
@@ -0,0 +1,236 @@
+use crate::types::ResourceType;
+
+/// Starting YAML for a new resource of `resource_type` in `namespace`, to be
+/// edited before it is created. `None` for types without one.
+pub fn template(resource_type: ResourceType, namespace: &str) -> Option<String> {
+    let yaml = match resource_type {
+        ResourceType::Pods => POD,
+        ResourceType::Deployments => DEPLOYMENT,
+        ResourceType::StatefulSets => STATEFUL_SET,
+        ResourceType::DaemonSets => DAEMON_SET,
+        ResourceType::Jobs => JOB,
+        ResourceType::CronJobs => CRON_JOB,
+        ResourceType::Services => SERVICE,
+        ResourceType::Ingresses => INGRESS,
+        ResourceType::ConfigMaps => CONFIG_MAP,
+        ResourceType::Secrets => SECRET,
+        ResourceType::PersistentVolumeClaims => PVC,
+        ResourceType::ServiceAccounts => SERVICE_ACCOUNT,
+        ResourceType::Namespaces => NAMESPACE,
+        _ => return None,
+    };
+    Some(yaml.replace("{namespace}", namespace))
+}
+
+// A pod that stays up for `kubectl exec`-style poking around
+const POD: &str = "\
+apiVersion: v1
+kind: Pod
+metadata:
+  name: debug
+  namespace: {namespace}
+spec:
+  containers:
+  - name: debug
+    image: busybox:1.36
+    command: [sleep, infinity]
+  restartPolicy: Never
+";
+
+const DEPLOYMENT: &str = "\
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  namespace: {namespace}
+spec:
+  replicas: 1
+  selector:
+    matchLabels:
+      app: web
+  template:
+    metadata:
+      labels:
+        app: web
+    spec:
+      containers:
+      - name: web
+        image: nginx:1.27
+        ports:
+        - containerPort: 80
+";
+
+const STATEFUL_SET: &str = "\
+apiVersion: apps/v1
+kind: StatefulSet
+metadata:
+  name: db
+  namespace: {namespace}
+spec:
+  serviceName: db
+  replicas: 1
+  selector:
+    matchLabels:
+      app: db
+  template:
+    metadata:
+      labels:
+        app: db
+    spec:
+      containers:
+      - name: db
+        image: busybox:1.36
+        command: [sleep, infinity]
+        volumeMounts:
+        - name: data
+          mountPath: /data
+  volumeClaimTemplates:
+  - metadata:
+      name: data
+    spec:
+      accessModes: [ReadWriteOnce]
+      resources:
+        requests:
+          storage: 1Gi
+";
+
+const DAEMON_SET: &str = "\
+apiVersion: apps/v1
+kind: DaemonSet
+metadata:
+  name: agent
+  namespace: {namespace}
+spec:
+  selector:
+    matchLabels:
+      app: agent
+  template:
+    metadata:
+      labels:
+        app: agent
+    spec:
+      containers:
+      - name: agent
+        image: busybox:1.36
+        command: [sleep, infinity]
+";
+
+const JOB: &str = "\
+apiVersion: batch/v1
+kind: Job
+metadata:
+  name: task
+  namespace: {namespace}
+spec:
+  backoffLimit: 0
+  template:
+    spec:
+      containers:
+      - name: task
+        image: busybox:1.36
+        command: [echo, done]
+      restartPolicy: Never
+";
+
+const CRON_JOB: &str = "\
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: task
+  namespace: {namespace}
+spec:
+  schedule: '0 * * * *'
+  jobTemplate:
+    spec:
+      template:
+        spec:
+          containers:
+          - name: task
+            image: busybox:1.36
+            command: [echo, done]
+          restartPolicy: Never
+";
+
+const SERVICE: &str = "\
+apiVersion: v1
+kind: Service
+metadata:
+  name: web
+  namespace: {namespace}
+spec:
+  selector:
+    app: web
+  ports:
+  - port: 80
+    targetPort: 80
+";
+
+const INGRESS: &str = "\
+apiVersion: networking.k8s.io/v1
+kind: Ingress
+metadata:
+  name: web
+  namespace: {namespace}
+spec:
+  rules:
+  - host: web.example.com
+    http:
+      paths:
+      - path: /
+        pathType: Prefix
+        backend:
+          service:
+            name: web
+            port:
+              number: 80
+";
+
+const CONFIG_MAP: &str = "\
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: config
+  namespace: {namespace}
+data:
+  key: value
+";
+
+// `stringData` takes plain text; the API server encodes it into `data`
+const SECRET: &str = "\
+apiVersion: v1
+kind: Secret
+metadata:
+  name: credentials
+  namespace: {namespace}
+type: Opaque
+stringData:
+  password: change-me
+";
+
+const PVC: &str = "\
+apiVersion: v1
+kind: PersistentVolumeClaim
+metadata:
+  name: data
+  namespace: {namespace}
+spec:
+  accessModes: [ReadWriteOnce]
+  resources:
+    requests:
+      storage: 1Gi
+";
+
+const SERVICE_ACCOUNT: &str = "\
+apiVersion: v1
+kind: ServiceAccount
+metadata:
+  name: app
+  namespace: {namespace}
+";
+
+const NAMESPACE: &str = "\
+apiVersion: v1
+kind: Namespace
+metadata:
+  name: sandbox
+";
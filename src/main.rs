@@ -1,19 +1,24 @@
 mod app;
 #[cfg(test)]
 mod app_test;
+mod cli;
+mod config;
 mod event;
 mod k8s;
+mod state;
+#[cfg(test)]
+mod subprocess_test;
+#[cfg(test)]
+mod test_support;
 mod types;
 mod ui;
 #[cfg(test)]
 mod ui_test;
-#[cfg(test)]
-mod subprocess_test;
 
 use std::io;
 
-use anyhow::Result;
-use crossterm::event::KeyEventKind;
+use anyhow::{Context, Result};
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture, KeyEventKind};
 use crossterm::execute;
 use crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
@@ -26,18 +31,32 @@ use event::{AppEvent, EventHandler};
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Bad arguments are reported before the terminal is taken over
+    let args = cli::parse(std::env::args().skip(1))?;
+    if args.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+    if let Some(context) = &args.context {
+        k8s::client::K8sManager::check_context(context)?;
+    }
+
     // Terminal setup
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = run_app(&mut terminal).await;
+    let result = run_app(&mut terminal, args).await;
 
     // Terminal teardown
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
 
     if let Err(e) = result {
@@ -48,8 +67,61 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+/// Load the user config. Problems with it are shown as a warning in the
+/// footer instead of stopping startup.
+fn apply_config(app: &mut App) {
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            app.set_warning(format!("Config warning: {:#}", e));
+            return;
+        }
+    };
+    let (theme, warnings) = ui::theme::Theme::from_config(&config.theme);
+    app.theme = theme;
+    app.wrap_navigation = config.wrap_navigation;
+    app.load_timeout = std::time::Duration::from_secs(config.load_timeout_secs.max(1));
+    app.search_concurrency = config.search_concurrency.max(1);
+    app.danger_contexts = config.danger_contexts;
+    app.log_ansi = config.log_ansi;
+    if !warnings.is_empty() {
+        app.set_warning(format!("Config warning: {}", warnings.join("; ")));
+    }
+}
+
+/// Write the remembered state. Failing to is only worth a warning.
+fn save_state(app: &mut App) {
+    if app.state_unreadable {
+        return;
+    }
+    let saved = state::State {
+        recent_namespaces: app.recent_namespaces.clone(),
+        favorite_contexts: app.favorite_contexts.clone(),
+        bookmarks: app.bookmarks.clone(),
+        resource_type: Some(app.resource_type),
+    };
+    if let Err(e) = state::save(&saved) {
+        app.set_warning(format!("Could not save state: {:#}", e));
+    }
+}
+
+async fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    args: cli::Args,
+) -> Result<()> {
     let mut app = App::new();
+    apply_config(&mut app);
+    let saved = state::load().unwrap_or_else(|e| {
+        app.state_unreadable = true;
+        app.set_warning(format!("State not loaded or saved this run: {:#}", e));
+        state::State::default()
+    });
+    app.recent_namespaces = saved.recent_namespaces;
+    app.favorite_contexts = saved.favorite_contexts;
+    app.bookmarks = saved.bookmarks;
+    if let Some(resource) = args.resource.or(saved.resource_type) {
+        app.resource_type = resource;
+    }
     let mut events = EventHandler::new();
     let tx = events.sender();
 
@@ -61,24 +133,47 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
     app.loading = true;
     let k8s_tx = tx.clone();
     let init_mgr = k8s_manager.clone();
+    let requested_ns = args.namespace.is_some();
     tokio::spawn(async move {
-        match k8s::client::K8sManager::new().await {
+        match k8s::client::K8sManager::new(args.context).await {
             Ok(manager) => {
                 let contexts = manager.context_names();
                 let current = manager.current_context.clone();
-                let current_namespace = manager.current_namespace();
+                let _ = k8s_tx.send(AppEvent::ServerUrlLoaded(manager.server_url.clone()));
+                let current_namespace = args
+                    .namespace
+                    .unwrap_or_else(|| manager.current_namespace());
 
                 // Load namespaces
                 match manager.list_namespaces().await {
+                    Ok(namespaces) if !namespaces.contains(&current_namespace) && requested_ns => {
+                        let _ = k8s_tx.send(AppEvent::StartupFailed(format!(
+                            "namespace '{}' not found in context '{}'",
+                            current_namespace, current
+                        )));
+                        return;
+                    }
                     Ok(namespaces) => {
-                        let _ = k8s_tx.send(AppEvent::NamespacesLoaded(namespaces));
+                        let _ = k8s_tx.send(AppEvent::NamespacesLoaded {
+                            namespaces,
+                            preferred: Some(current_namespace.clone()),
+                            listed: true,
+                        });
                     }
                     Err(e) => {
-                        let _ = k8s_tx.send(AppEvent::K8sError(format!(
-                            "Failed to list namespaces: {}",
-                            e
+                        let _ = k8s_tx.send(AppEvent::K8sError(k8s::errors::explain(
+                            "Failed to list namespaces",
+                            &e,
+                            "list",
+                            types::ResourceType::Namespaces,
+                            None,
+                            "",
                         )));
-                        let _ = k8s_tx.send(AppEvent::NamespacesLoaded(vec!["default".to_string()]));
+                        let _ = k8s_tx.send(AppEvent::NamespacesLoaded {
+                            namespaces: vec![current_namespace.clone()],
+                            preferred: Some(current_namespace.clone()),
+                            listed: false,
+                        });
                     }
                 }
 
@@ -92,13 +187,25 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                     "Failed to connect to Kubernetes: {}. Running in offline mode.",
                     e
                 )));
-                let _ = k8s_tx.send(AppEvent::NamespacesLoaded(vec!["default".to_string()]));
+                let _ = k8s_tx.send(AppEvent::NamespacesLoaded {
+                    namespaces: vec!["default".to_string()],
+                    preferred: None,
+                    listed: false,
+                });
             }
         }
     });
 
     // Track the current watcher task so we can abort it
     let mut watcher_handle: Option<tokio::task::JoinHandle<()>> = None;
+    // One-off re-list from `R`; aborted with the watcher so it can't
+    // overwrite rows of a newly selected namespace or type
+    let mut refresh_handle: Option<tokio::task::JoinHandle<()>> = None;
+    let mut port_forwards = k8s::portforward::PortForwardManager::default();
+    // The log stream feeding the Logs view, aborted when it is left or restarted
+    let mut log_handle: Option<tokio::task::JoinHandle<()>> = None;
+    // Per-context scan tasks of the running search, aborted on cancel
+    let mut search_handles: Vec<tokio::task::JoinHandle<()>> = Vec::new();
 
     loop {
         terminal.draw(|f| ui::render(f, &mut app))?;
@@ -121,16 +228,24 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                         let context_name = app.current_context().to_string();
                         let mgr = k8s_manager.clone();
                         let action_tx = tx.clone();
-                        let ns = app.current_namespace().to_string();
                         let rt = app.resource_type;
+                        let selector = app.watch_selector();
+                        // A bookmark names its namespace; otherwise open the
+                        // kubeconfig default
+                        let target_ns = app.pending_namespace.take();
 
                         // Abort current watcher
                         if let Some(h) = watcher_handle.take() {
                             h.abort();
                         }
+                        if let Some(h) = refresh_handle.take() {
+                            h.abort();
+                        }
 
                         app.loading = true;
-                        app.resources.clear();
+                        app.watch_reconnecting = false;
+                        app.server_url = None;
+                        app.set_resources(Vec::new());
 
                         let handle = tokio::spawn(async move {
                             let mut guard = mgr.lock().await;
@@ -142,19 +257,37 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                     )));
                                     return;
                                 }
-                                // Reload namespaces
-                                match manager.list_namespaces().await {
-                                    Ok(namespaces) => {
-                                        let _ = action_tx
-                                            .send(AppEvent::NamespacesLoaded(namespaces));
-                                    }
+                                let _ = action_tx
+                                    .send(AppEvent::ServerUrlLoaded(manager.server_url.clone()));
+                                // Reload namespaces and open the new context's
+                                // kubeconfig default, as the app will select it
+                                let preferred =
+                                    target_ns.unwrap_or_else(|| manager.current_namespace());
+                                let result = manager.list_namespaces().await;
+                                let listed = result.is_ok();
+                                let namespaces = match result {
+                                    Ok(namespaces) => namespaces,
                                     Err(e) => {
-                                        let _ = action_tx.send(AppEvent::K8sError(format!(
-                                            "Failed to list namespaces: {}",
-                                            e
-                                        )));
+                                        let message = k8s::errors::explain(
+                                            "Failed to list namespaces",
+                                            &e,
+                                            "list",
+                                            types::ResourceType::Namespaces,
+                                            None,
+                                            "",
+                                        );
+                                        let _ = action_tx.send(AppEvent::K8sError(message));
+                                        vec![preferred.clone()]
                                     }
-                                }
+                                };
+                                let ns = types::initial_namespace(&namespaces, &preferred)
+                                    .unwrap_or(&preferred)
+                                    .to_string();
+                                let _ = action_tx.send(AppEvent::NamespacesLoaded {
+                                    namespaces,
+                                    preferred: Some(preferred),
+                                    listed,
+                                });
                                 // Start watching in same task (handle is tracked)
                                 let client = manager.client.clone();
                                 drop(guard);
@@ -170,27 +303,40 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                     client,
                                     &ns,
                                     rt,
+                                    &selector,
                                     action_tx.clone(),
                                 )
                                 .await
                                 {
-                                    let _ = action_tx.send(AppEvent::K8sError(format!(
-                                        "Watch error: {}",
-                                        e
-                                    )));
+                                    let _ =
+                                        action_tx.send(AppEvent::K8sError(k8s::errors::explain(
+                                            "Watch error",
+                                            &e,
+                                            "list",
+                                            rt,
+                                            None,
+                                            &ns,
+                                        )));
                                 }
                             }
                         });
                         watcher_handle = Some(handle);
                     }
+                    InputAction::SaveState => save_state(&mut app),
                     InputAction::NamespaceChanged => {
+                        save_state(&mut app);
+
                         // Abort current watcher and start new one
                         if let Some(h) = watcher_handle.take() {
                             h.abort();
                         }
+                        if let Some(h) = refresh_handle.take() {
+                            h.abort();
+                        }
 
                         app.loading = true;
-                        app.resources.clear();
+                        app.watch_reconnecting = false;
+                        app.set_resources(Vec::new());
                         app.resource_counts.clear();
                         app.table_state.select(Some(0));
 
@@ -198,6 +344,7 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                         let action_tx = tx.clone();
                         let ns = app.current_namespace().to_string();
                         let rt = app.resource_type;
+                        let selector = app.watch_selector();
 
                         let handle = tokio::spawn(async move {
                             let guard = mgr.lock().await;
@@ -216,33 +363,46 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                     client,
                                     &ns,
                                     rt,
+                                    &selector,
                                     action_tx.clone(),
                                 )
                                 .await
                                 {
-                                    let _ = action_tx.send(AppEvent::K8sError(format!(
-                                        "Watch error: {}",
-                                        e
-                                    )));
+                                    let _ =
+                                        action_tx.send(AppEvent::K8sError(k8s::errors::explain(
+                                            "Watch error",
+                                            &e,
+                                            "list",
+                                            rt,
+                                            None,
+                                            &ns,
+                                        )));
                                 }
                             }
                         });
                         watcher_handle = Some(handle);
                     }
                     InputAction::ResourceTypeChanged => {
+                        save_state(&mut app);
+
                         // Abort current watcher and start new one
                         if let Some(h) = watcher_handle.take() {
                             h.abort();
                         }
+                        if let Some(h) = refresh_handle.take() {
+                            h.abort();
+                        }
 
                         app.loading = true;
-                        app.resources.clear();
+                        app.watch_reconnecting = false;
+                        app.set_resources(Vec::new());
                         app.table_state.select(Some(0));
 
                         let mgr = k8s_manager.clone();
                         let action_tx = tx.clone();
                         let ns = app.current_namespace().to_string();
                         let rt = app.resource_type;
+                        let selector = app.watch_selector();
 
                         let handle = tokio::spawn(async move {
                             let guard = mgr.lock().await;
@@ -253,72 +413,223 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                     client,
                                     &ns,
                                     rt,
+                                    &selector,
                                     action_tx.clone(),
                                 )
                                 .await
                                 {
-                                    let _ = action_tx.send(AppEvent::K8sError(format!(
-                                        "Watch error: {}",
-                                        e
-                                    )));
+                                    let _ =
+                                        action_tx.send(AppEvent::K8sError(k8s::errors::explain(
+                                            "Watch error",
+                                            &e,
+                                            "list",
+                                            rt,
+                                            None,
+                                            &ns,
+                                        )));
                                 }
                             }
                         });
                         watcher_handle = Some(handle);
                     }
-                    InputAction::Describe => {
-                        let name = app.selected_resource_name().unwrap_or_default();
+                    InputAction::Refresh => {
                         let ns = app.current_namespace().to_string();
                         let rt = app.resource_type;
+                        let selector = app.watch_selector();
                         let mgr = k8s_manager.clone();
                         let action_tx = tx.clone();
 
-                        app.loading = true;
-                        app.detail_text.clear();
-
-                        tokio::spawn(async move {
+                        if let Some(h) = refresh_handle.take() {
+                            h.abort();
+                        }
+                        let handle = tokio::spawn(async move {
                             let guard = mgr.lock().await;
                             if let Some(ref manager) = *guard {
                                 let client = manager.client.clone();
                                 drop(guard);
-                                match k8s::resources::describe_resource(client, &ns, &name, rt)
-                                    .await
-                                {
-                                    Ok(desc) => {
-                                        let _ = action_tx.send(AppEvent::DetailLoaded(desc));
+                                let list = k8s::errors::retry(|| {
+                                    k8s::resources::list_resources(
+                                        client.clone(),
+                                        &ns,
+                                        rt,
+                                        &selector,
+                                    )
+                                });
+                                match list.await {
+                                    Ok(items) => {
+                                        let _ = action_tx.send(AppEvent::ResourcesUpdated(items));
                                     }
                                     Err(e) => {
-                                        let _ = action_tx.send(AppEvent::K8sError(format!(
-                                            "Describe error: {}",
-                                            e
-                                        )));
+                                        let message = k8s::errors::explain(
+                                            "Refresh error",
+                                            &e,
+                                            "list",
+                                            rt,
+                                            None,
+                                            &ns,
+                                        );
+                                        let _ = action_tx.send(AppEvent::K8sError(message));
                                     }
                                 }
+                            } else {
+                                // Offline: say so rather than spin forever
+                                let message = "Refresh error: not connected".to_string();
+                                let _ = action_tx.send(AppEvent::K8sError(message));
                             }
                         });
+                        refresh_handle = Some(handle);
+                    }
+                    InputAction::Describe | InputAction::DescribeEvents => {
+                        let name = app.selected_resource_name().unwrap_or_default();
+                        let ns = app.selected_resource_namespace();
+                        app.loading = true;
+                        app.detail_text.clear();
+                        let target = (ns, name, app.resource_type);
+                        app.detail_target = Some(target.clone());
+                        spawn_describe(
+                            k8s_manager.clone(),
+                            tx.clone(),
+                            target,
+                            app.secret_revealed,
+                            action == InputAction::DescribeEvents,
+                            app.load_timeout,
+                        );
+                    }
+                    InputAction::CopyYaml if !app.detail_raw_yaml.is_empty() => {
+                        copy_pending_yaml(&mut app);
+                    }
+                    // Copying YAML that isn't loaded yet loads it as for `v`
+                    InputAction::LoadYaml | InputAction::CopyYaml => {
+                        let action_tx = tx.clone();
+                        // Search results may come from another context
+                        let target = if app.entered_from_search {
+                            app.selected_search_result().map(|r| {
+                                (
+                                    Some(r.context.clone()),
+                                    r.resource.namespace.clone(),
+                                    r.resource.name.clone(),
+                                    r.resource_type,
+                                )
+                            })
+                        } else {
+                            app.selected_resource_name().map(|name| {
+                                (
+                                    None,
+                                    app.selected_resource_namespace(),
+                                    name,
+                                    app.resource_type,
+                                )
+                            })
+                        };
+                        // Search results' detail has no `x` to reveal with
+                        let reveal = app.secret_revealed && !app.entered_from_search;
+                        let mgr = k8s_manager.clone();
+                        let limit = app.load_timeout;
+
+                        if let Some((context, ns, name, rt)) = target {
+                            tokio::spawn(async move {
+                                let client = match context {
+                                    Some(ref context) => {
+                                        k8s::client::K8sManager::client_for_context(context).await
+                                    }
+                                    None => {
+                                        let guard = mgr.lock().await;
+                                        match *guard {
+                                            Some(ref manager) => Ok(manager.client.clone()),
+                                            None => return,
+                                        }
+                                    }
+                                };
+                                let yaml = match client {
+                                    Ok(client) => {
+                                        let yaml = k8s::errors::retry(|| {
+                                            k8s::resources::get_yaml(
+                                                client.clone(),
+                                                &ns,
+                                                &name,
+                                                rt,
+                                                reveal,
+                                            )
+                                        });
+                                        with_timeout(limit, "loading YAML", yaml).await
+                                    }
+                                    Err(e) => Err(e),
+                                };
+                                let event = match yaml {
+                                    Ok(yaml) => AppEvent::YamlLoaded(yaml),
+                                    Err(e) => AppEvent::K8sError(k8s::errors::explain(
+                                        "YAML error",
+                                        &e,
+                                        "get",
+                                        rt,
+                                        Some(&name),
+                                        &ns,
+                                    )),
+                                };
+                                let _ = action_tx.send(event);
+                            });
+                        } else {
+                            app.loading = false;
+                        }
                     }
                     InputAction::StreamLogs => {
                         let name = app.selected_resource_name().unwrap_or_default();
-                        let ns = app.current_namespace().to_string();
+                        let ns = app.selected_resource_namespace();
+                        let container = app.log_container.clone();
+                        let options = app.log_options();
+                        let workload = app.log_aggregate.then_some(app.resource_type);
+                        let selector = app.log_selector.clone();
+                        let selector_ns =
+                            (!app.all_namespaces()).then(|| app.current_namespace().to_string());
                         let mgr = k8s_manager.clone();
                         let action_tx = tx.clone();
 
+                        if let Some(h) = log_handle.take() {
+                            h.abort();
+                        }
                         app.loading = true;
 
-                        tokio::spawn(async move {
+                        let handle = tokio::spawn(async move {
                             let guard = mgr.lock().await;
                             if let Some(ref manager) = *guard {
                                 let client = manager.client.clone();
                                 drop(guard);
-                                if let Err(e) = k8s::logs::stream_pod_logs(
-                                    client,
-                                    &ns,
-                                    &name,
-                                    None,
-                                    action_tx.clone(),
-                                )
-                                .await
-                                {
+                                let result = match (selector, workload) {
+                                    (Some(selector), _) => {
+                                        k8s::logs::stream_selector_logs(
+                                            client,
+                                            selector_ns.as_deref(),
+                                            &selector,
+                                            options,
+                                            action_tx.clone(),
+                                        )
+                                        .await
+                                    }
+                                    (None, Some(rt)) => {
+                                        k8s::logs::stream_workload_logs(
+                                            client,
+                                            &ns,
+                                            rt,
+                                            &name,
+                                            container.as_deref(),
+                                            options,
+                                            action_tx.clone(),
+                                        )
+                                        .await
+                                    }
+                                    (None, None) => {
+                                        k8s::logs::stream_pod_logs(
+                                            client,
+                                            &ns,
+                                            &name,
+                                            container.as_deref(),
+                                            options,
+                                            action_tx.clone(),
+                                        )
+                                        .await
+                                    }
+                                };
+                                if let Err(e) = result {
                                     let _ = action_tx.send(AppEvent::K8sError(format!(
                                         "Log stream error: {}",
                                         e
@@ -326,13 +637,79 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                 }
                             }
                         });
+                        log_handle = Some(handle);
                     }
                     InputAction::StopLogs => {
-                        // Log streaming will stop when the sender is dropped
+                        if let Some(h) = log_handle.take() {
+                            h.abort();
+                        }
+                    }
+                    InputAction::Delete | InputAction::ForceDelete => {
+                        let targets = app.take_delete_targets();
+                        let rt = app.resource_type;
+                        let force = action == InputAction::ForceDelete;
+                        let mgr = k8s_manager.clone();
+                        let action_tx = tx.clone();
+                        let operation = app.in_flight.start();
+
+                        tokio::spawn(async move {
+                            let _operation = operation;
+                            let guard = mgr.lock().await;
+                            if let Some(ref manager) = *guard {
+                                let client = manager.client.clone();
+                                drop(guard);
+                                let total = targets.len();
+                                let mut failures = Vec::new();
+                                for (ns, name) in targets {
+                                    if let Err(e) = k8s::actions::delete_resource(
+                                        client.clone(),
+                                        &ns,
+                                        &name,
+                                        rt,
+                                        force,
+                                    )
+                                    .await
+                                    {
+                                        failures.push((ns, name, e));
+                                    }
+                                }
+                                let explain =
+                                    |prefix: &str, (ns, name, e): &(String, String, _)| {
+                                        k8s::errors::explain(
+                                            prefix,
+                                            e,
+                                            "delete",
+                                            rt,
+                                            Some(name),
+                                            ns,
+                                        )
+                                    };
+                                let event = if failures.is_empty() {
+                                    (total > 1).then(|| {
+                                        AppEvent::K8sInfo(format!("Deleted {} resources", total))
+                                    })
+                                } else if total == 1 {
+                                    let prefix = format!("Delete error: {}", failures[0].1);
+                                    Some(AppEvent::K8sError(explain(&prefix, &failures[0])))
+                                } else {
+                                    let failed: Vec<String> =
+                                        failures.iter().map(|f| explain(&f.1, f)).collect();
+                                    Some(AppEvent::K8sError(format!(
+                                        "Deleted {}/{} resources; failed: {}",
+                                        total - failures.len(),
+                                        total,
+                                        failed.join("; ")
+                                    )))
+                                };
+                                if let Some(event) = event {
+                                    let _ = action_tx.send(event);
+                                }
+                            }
+                        });
                     }
-                    InputAction::Delete => {
+                    InputAction::PreviewDelete => {
                         let name = app.selected_resource_name().unwrap_or_default();
-                        let ns = app.current_namespace().to_string();
+                        let ns = app.selected_resource_namespace();
                         let rt = app.resource_type;
                         let mgr = k8s_manager.clone();
                         let action_tx = tx.clone();
@@ -342,25 +719,85 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                             if let Some(ref manager) = *guard {
                                 let client = manager.client.clone();
                                 drop(guard);
-                                if let Err(e) =
-                                    k8s::actions::delete_resource(client, &ns, &name, rt).await
+                                let lines = match k8s::actions::delete_preview(
+                                    client, &ns, &name, rt,
+                                )
+                                .await
                                 {
-                                    let _ = action_tx.send(AppEvent::K8sError(format!(
-                                        "Delete error: {}",
+                                    Ok(lines) => lines,
+                                    Err(e) => vec![format!(
+                                        "Could not determine affected resources: {}",
                                         e
-                                    )));
-                                }
+                                    )],
+                                };
+                                let _ = action_tx.send(AppEvent::DeletePreviewLoaded(lines));
                             }
                         });
                     }
+                    InputAction::Scale => {
+                        if let Some(replicas) = app.pending_replicas.take() {
+                            let name = app.selected_resource_name().unwrap_or_default();
+                            let ns = app.selected_resource_namespace();
+                            let rt = app.resource_type;
+                            let mgr = k8s_manager.clone();
+                            let action_tx = tx.clone();
+                            let operation = app.in_flight.start();
+
+                            tokio::spawn(async move {
+                                let _operation = operation;
+                                let guard = mgr.lock().await;
+                                if let Some(ref manager) = *guard {
+                                    let client = manager.client.clone();
+                                    drop(guard);
+                                    if let Err(e) = k8s::actions::scale_resource(
+                                        client, &ns, &name, rt, replicas,
+                                    )
+                                    .await
+                                    {
+                                        let _ = action_tx.send(AppEvent::K8sError(format!(
+                                            "Scale error: {:#}",
+                                            e
+                                        )));
+                                    }
+                                }
+                            });
+                        }
+                    }
+                    InputAction::PortForward => {
+                        if let Some(forward) = app.pending_port_forward.take() {
+                            let guard = k8s_manager.lock().await;
+                            if let Some(ref manager) = *guard {
+                                port_forwards.start(manager.client.clone(), forward, tx.clone());
+                            }
+                        }
+                    }
+                    InputAction::StopPortForward => {
+                        if let Some(forward) = app.pending_port_forward.take() {
+                            port_forwards.stop(&forward);
+                            app.set_info(format!(
+                                "Stopped port-forward localhost:{} -> {}",
+                                forward.local_port, forward.name
+                            ));
+                        }
+                    }
+                    InputAction::CopyName => {
+                        if let Some(text) = app.pending_clipboard.take() {
+                            match copy_to_clipboard(&text) {
+                                Ok(()) => app.set_info(format!("Copied {} to clipboard", text)),
+                                Err(e) => app.set_error(format!("Copy failed: {:#}", e)),
+                            }
+                        }
+                    }
                     InputAction::Restart => {
                         let name = app.selected_resource_name().unwrap_or_default();
-                        let ns = app.current_namespace().to_string();
+                        let ns = app.selected_resource_namespace();
                         let rt = app.resource_type;
                         let mgr = k8s_manager.clone();
                         let action_tx = tx.clone();
+                        let operation = app.in_flight.start();
 
                         tokio::spawn(async move {
+                            let _operation = operation;
                             let guard = mgr.lock().await;
                             if let Some(ref manager) = *guard {
                                 let client = manager.client.clone();
@@ -368,26 +805,36 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                 if let Err(e) =
                                     k8s::actions::restart_resource(client, &ns, &name, rt).await
                                 {
-                                    let _ = action_tx.send(AppEvent::K8sError(format!(
-                                        "Restart error: {}",
-                                        e
-                                    )));
+                                    let _ =
+                                        action_tx.send(AppEvent::K8sError(k8s::errors::explain(
+                                            "Restart error",
+                                            &e,
+                                            "restart",
+                                            rt,
+                                            Some(&name),
+                                            &ns,
+                                        )));
                                 }
                             }
                         });
                     }
                     InputAction::OpenLogsInEditor => {
                         if !app.log_lines.is_empty() {
-                            events.suspend();
-                            disable_raw_mode()?;
-                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-
-                            let _ = open_logs_in_editor(&app.log_lines);
-
-                            enable_raw_mode()?;
-                            execute!(terminal.backend_mut(), EnterAlternateScreen)?;
-                            terminal.clear()?;
-                            events.resume();
+                            let _ = with_suspended_terminal(terminal, &mut events, || {
+                                open_logs_in_editor(&app.log_lines)
+                            })?;
+                        }
+                    }
+                    InputAction::SaveLogs => {
+                        if let Some(path) = app.pending_log_path.take() {
+                            match save_logs(&path, &app.log_lines) {
+                                Ok(path) => app.set_info(format!(
+                                    "Saved {} lines to {}",
+                                    app.log_lines.len(),
+                                    path.display()
+                                )),
+                                Err(e) => app.set_error(format!("Save error: {:#}", e)),
+                            }
                         }
                     }
                     InputAction::OpenLogsInLess => {
@@ -410,32 +857,16 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                 guard.as_ref().map(|mgr| {
                                     (
                                         mgr.client.clone(),
-                                        app.current_namespace().to_string(),
+                                        app.selected_resource_namespace(),
                                         app.selected_resource_name().unwrap_or_default(),
                                     )
                                 })
                             };
 
-                            events.suspend();
-                            disable_raw_mode()?;
-                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-
-                            let cleanup = if let Some((client, ns, pod_name)) = client_and_pod {
-                                open_logs_in_less(
-                                    &app.log_lines,
-                                    client,
-                                    ns,
-                                    pod_name,
-                                    None,
-                                ).ok()
-                            } else {
-                                None
-                            };
-
-                            enable_raw_mode()?;
-                            execute!(terminal.backend_mut(), EnterAlternateScreen)?;
-                            terminal.clear()?;
-                            events.resume();
+                            let cleanup = with_suspended_terminal(terminal, &mut events, || {
+                                let (client, ns, pod_name) = client_and_pod?;
+                                open_logs_in_less(&app.log_lines, client, ns, pod_name, None).ok()
+                            })?;
 
                             if let Some(c) = cleanup {
                                 c.finish_in_background();
@@ -444,64 +875,205 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                     }
                     InputAction::Edit => {
                         if let Some(resource) = app.selected_resource() {
-                            let yaml = resource.raw_yaml.clone();
                             let name = resource.name.clone();
-                            let ns = app.current_namespace().to_string();
+                            let ns = app.selected_resource_namespace();
                             let rt = app.resource_type;
-                            let mgr = k8s_manager.clone();
-                            let action_tx = tx.clone();
-
-                            events.suspend();
-                            disable_raw_mode()?;
-                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
 
-                            let edited = edit_yaml_in_editor(&yaml);
+                            // Edit the live object rather than a possibly stale copy
+                            let client =
+                                k8s_manager.lock().await.as_ref().map(|m| m.client.clone());
+                            let Some(client) = client else {
+                                continue;
+                            };
+                            // Masked values would be applied as the new values
+                            let yaml = k8s::errors::retry(|| {
+                                k8s::resources::get_yaml(client.clone(), &ns, &name, rt, true)
+                            });
+                            let yaml = match yaml.await {
+                                Ok(yaml) => yaml,
+                                Err(e) => {
+                                    app.set_error(k8s::errors::explain(
+                                        "Edit error",
+                                        &e,
+                                        "get",
+                                        rt,
+                                        Some(&name),
+                                        &ns,
+                                    ));
+                                    continue;
+                                }
+                            };
 
-                            enable_raw_mode()?;
-                            execute!(terminal.backend_mut(), EnterAlternateScreen)?;
-                            terminal.clear()?;
-                            events.resume();
+                            let edited = with_suspended_terminal(terminal, &mut events, || {
+                                edit_yaml_in_editor(&yaml)
+                            })?;
 
+                            // Applied only once the diff is confirmed
                             if let Ok(Some(new_yaml)) = edited {
-                                tokio::spawn(async move {
-                                    let guard = mgr.lock().await;
-                                    if let Some(ref manager) = *guard {
-                                        let client = manager.client.clone();
-                                        drop(guard);
-                                        if let Err(e) = k8s::actions::apply_yaml(
-                                            client, &ns, &name, rt, &new_yaml,
-                                        )
-                                        .await
-                                        {
-                                            let _ = action_tx.send(AppEvent::K8sError(format!(
-                                                "Apply error: {}",
-                                                e
-                                            )));
-                                        }
+                                let edit = types::PendingEdit::new(ns, name, rt, &yaml, new_yaml);
+                                match edit.target_mismatch() {
+                                    Some(reason) => {
+                                        app.set_warning(format!("Edit rejected: {}", reason))
                                     }
-                                });
+                                    None => app.open_edit_preview(edit),
+                                }
+                            }
+                        }
+                    }
+                    InputAction::Create => {
+                        let rt = app.resource_type;
+                        // With "<all>" the template names the default namespace
+                        let ns = if app.all_namespaces() {
+                            "default".to_string()
+                        } else {
+                            app.current_namespace().to_string()
+                        };
+                        let Some(template) = k8s::templates::template(rt, &ns) else {
+                            continue;
+                        };
+                        let written = with_suspended_terminal(terminal, &mut events, || {
+                            run_editor(&template)
+                        })?;
+                        if let Ok(Some(yaml)) = written {
+                            match types::PendingEdit::create(ns, rt, yaml) {
+                                Err(e) => {
+                                    app.set_warning(format!("Create rejected: invalid YAML: {}", e))
+                                }
+                                Ok(create) => match create.target_mismatch() {
+                                    Some(reason) => {
+                                        app.set_warning(format!("Create rejected: {}", reason))
+                                    }
+                                    None => app.open_edit_preview(create),
+                                },
                             }
                         }
                     }
+                    InputAction::ApplyEdit => {
+                        if let Some(edit) = app.take_pending_edit() {
+                            let mgr = k8s_manager.clone();
+                            let action_tx = tx.clone();
+                            let operation = app.in_flight.start();
+                            tokio::spawn(async move {
+                                let _operation = operation;
+                                let guard = mgr.lock().await;
+                                if let Some(ref manager) = *guard {
+                                    let client = manager.client.clone();
+                                    drop(guard);
+                                    if let Err(e) = k8s::actions::apply_yaml(
+                                        client,
+                                        &edit.namespace,
+                                        edit.target(),
+                                        edit.resource_type,
+                                        &edit.yaml,
+                                        false,
+                                    )
+                                    .await
+                                    {
+                                        let (prefix, verb) = if edit.create {
+                                            ("Create error", "create")
+                                        } else {
+                                            ("Apply error", "update")
+                                        };
+                                        let message = k8s::errors::explain(
+                                            prefix,
+                                            &e,
+                                            verb,
+                                            edit.resource_type,
+                                            Some(&edit.name),
+                                            &edit.namespace,
+                                        );
+                                        let _ = action_tx.send(AppEvent::K8sError(message));
+                                    }
+                                }
+                            });
+                        }
+                    }
+                    InputAction::DryRunEdit => {
+                        if let Some(edit) = app.pending_edit.clone() {
+                            let mgr = k8s_manager.clone();
+                            let action_tx = tx.clone();
+                            tokio::spawn(async move {
+                                let guard = mgr.lock().await;
+                                if let Some(ref manager) = *guard {
+                                    let client = manager.client.clone();
+                                    drop(guard);
+                                    let result = k8s::actions::apply_yaml(
+                                        client,
+                                        &edit.namespace,
+                                        edit.target(),
+                                        edit.resource_type,
+                                        &edit.yaml,
+                                        true,
+                                    )
+                                    .await
+                                    .map_err(|e| format!("{:#}", e));
+                                    let _ = action_tx.send(AppEvent::EditDryRun(result));
+                                }
+                            });
+                        }
+                    }
                     InputAction::StartSearch => {
-                        let contexts = app.contexts.clone();
+                        // A scoped search stays in one context and namespace and
+                        // skips cluster-scoped types
+                        let (contexts, namespace) = match &app.search_scope {
+                            Some(scope) => (vec![scope.context.clone()], scope.namespace.clone()),
+                            None => (app.search_contexts(), types::ALL_NAMESPACES.to_string()),
+                        };
+                        let scoped = app.search_scope.is_some();
+                        let limit = app.load_timeout;
                         app.search_contexts_total = contexts.len();
                         app.search_contexts_done = 0;
+                        // Only a few contexts are scanned at once; the rest wait
+                        // for a permit
+                        let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(
+                            app.search_concurrency,
+                        ));
+                        for h in search_handles.drain(..) {
+                            h.abort();
+                        }
 
                         for context in contexts {
                             let ctx = context.clone();
+                            let ns = namespace.clone();
                             let search_tx = tx.clone();
-                            tokio::spawn(async move {
-                                match k8s::client::K8sManager::client_for_context(&ctx).await {
+                            let permits = permits.clone();
+                            let handle = tokio::spawn(async move {
+                                let Ok(_permit) = permits.acquire_owned().await else {
+                                    return;
+                                };
+                                let connect = k8s::client::K8sManager::client_for_context(&ctx);
+                                match with_timeout(limit, "connecting", connect).await {
                                     Ok(client) => {
+                                        let everything = types::Selector::default();
                                         for rt in types::ResourceType::ALL.iter() {
                                             let rt = *rt;
-                                            match k8s::resources::list_all_resources(
+                                            if scoped && rt.is_cluster_scoped() {
+                                                continue;
+                                            }
+                                            let list = k8s::resources::list_resources(
                                                 client.clone(),
+                                                &ns,
                                                 rt,
-                                            )
-                                            .await
-                                            {
+                                                &everything,
+                                            );
+                                            // A hung context would stall every remaining
+                                            // type, so one timeout gives up on it
+                                            let Ok(listed) =
+                                                tokio::time::timeout(limit, list).await
+                                            else {
+                                                let error = format!(
+                                                    "timed out after {}s listing {}",
+                                                    limit.as_secs(),
+                                                    rt
+                                                );
+                                                let _ =
+                                                    search_tx.send(AppEvent::SearchContextFailed {
+                                                        context: ctx.clone(),
+                                                        error,
+                                                    });
+                                                break;
+                                            };
+                                            match listed {
                                                 Ok(items) => {
                                                     let _ = search_tx.send(
                                                         AppEvent::SearchResultsBatch {
@@ -523,55 +1095,75 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                         }
                                     }
                                     Err(e) => {
-                                        let _ = search_tx.send(AppEvent::K8sError(format!(
-                                            "Connect to {}: {}",
-                                            ctx, e
-                                        )));
+                                        let _ = search_tx.send(AppEvent::SearchContextFailed {
+                                            context: ctx.clone(),
+                                            error: format!("{}", e),
+                                        });
                                     }
                                 }
                                 let _ =
                                     search_tx.send(AppEvent::SearchScanComplete(ctx));
                             });
+                            search_handles.push(handle);
+                        }
+                    }
+                    InputAction::CancelSearch => {
+                        for h in search_handles.drain(..) {
+                            h.abort();
                         }
                     }
                     InputAction::SearchDescribe => {
                         if let Some(result) = app.selected_search_result().cloned() {
                             let action_tx = tx.clone();
+                            let limit = app.load_timeout;
+                            let target = (
+                                result.resource.namespace.clone(),
+                                result.resource.name.clone(),
+                                result.resource_type,
+                            );
                             app.loading = true;
+                            app.detail_target = Some(target.clone());
 
                             tokio::spawn(async move {
-                                match k8s::client::K8sManager::client_for_context(
-                                    &result.context,
-                                )
-                                .await
-                                {
+                                let connect =
+                                    k8s::client::K8sManager::client_for_context(&result.context);
+                                match with_timeout(limit, "connecting", connect).await {
                                     Ok(client) => {
-                                        match k8s::resources::describe_resource(
-                                            client,
-                                            &result.resource.namespace,
-                                            &result.resource.name,
-                                            result.resource_type,
-                                        )
-                                        .await
-                                        {
-                                            Ok(desc) => {
+                                        let describe = k8s::errors::retry(|| {
+                                            k8s::resources::describe_resource(
+                                                client.clone(),
+                                                &result.resource.namespace,
+                                                &result.resource.name,
+                                                result.resource_type,
+                                                false,
+                                            )
+                                        });
+                                        match with_timeout(limit, "describing", describe).await {
+                                            Ok(text) => {
                                                 let _ = action_tx
-                                                    .send(AppEvent::DetailLoaded(desc));
+                                                    .send(AppEvent::DetailLoaded { target, text });
                                             }
                                             Err(e) => {
-                                                let _ =
-                                                    action_tx.send(AppEvent::K8sError(format!(
-                                                        "Describe error: {}",
-                                                        e
-                                                    )));
+                                                let message = k8s::errors::explain(
+                                                    "Describe error",
+                                                    &e,
+                                                    "get",
+                                                    result.resource_type,
+                                                    Some(&result.resource.name),
+                                                    &result.resource.namespace,
+                                                );
+                                                let _ = action_tx.send(AppEvent::DetailFailed {
+                                                    target,
+                                                    message,
+                                                });
                                             }
                                         }
                                     }
                                     Err(e) => {
-                                        let _ = action_tx.send(AppEvent::K8sError(format!(
-                                            "Connect to {}: {}",
-                                            result.context, e
-                                        )));
+                                        let message =
+                                            format!("Connect to {}: {}", result.context, e);
+                                        let _ = action_tx
+                                            .send(AppEvent::DetailFailed { target, message });
                                     }
                                 }
                             });
@@ -579,21 +1171,26 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                     }
                     InputAction::SearchStreamLogs => {
                         if let Some(result) = app.selected_search_result().cloned() {
+                            let container = app.log_container.clone();
+                            let options = app.log_options();
                             let action_tx = tx.clone();
+                            if let Some(h) = log_handle.take() {
+                                h.abort();
+                            }
                             app.loading = true;
 
-                            tokio::spawn(async move {
-                                match k8s::client::K8sManager::client_for_context(
-                                    &result.context,
-                                )
-                                .await
-                                {
+                            let handle = tokio::spawn(async move {
+                                let connect =
+                                    k8s::client::K8sManager::client_for_context(&result.context);
+                                let limit = options.open_timeout;
+                                match with_timeout(limit, "connecting", connect).await {
                                     Ok(client) => {
                                         if let Err(e) = k8s::logs::stream_pod_logs(
                                             client,
                                             &result.resource.namespace,
                                             &result.resource.name,
-                                            None,
+                                            container.as_deref(),
+                                            options,
                                             action_tx.clone(),
                                         )
                                         .await
@@ -613,22 +1210,48 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                                     }
                                 }
                             });
+                            log_handle = Some(handle);
                         }
                     }
                     InputAction::None => {}
                 }
             }
+            AppEvent::Mouse(mouse) => app.handle_mouse(mouse),
             AppEvent::Tick => {
                 app.handle_tick();
+                spawn_context_probes(
+                    app.contexts_to_probe(false),
+                    app.search_concurrency,
+                    tx.clone(),
+                );
+                // Live detail: re-describe in place, keeping text and scroll
+                if app.detail_refresh_due() {
+                    if let Some(target) = app.detail_target.clone() {
+                        app.detail_refresh_in_flight = true;
+                        spawn_describe(
+                            k8s_manager.clone(),
+                            tx.clone(),
+                            target,
+                            app.secret_revealed,
+                            app.detail_events,
+                            app.load_timeout,
+                        );
+                    }
+                }
             }
             AppEvent::Resize(_, _) => {
                 // Terminal will re-draw on next loop
             }
+            AppEvent::WatchReconnecting => {
+                app.watch_reconnecting = true;
+            }
             AppEvent::ResourcesUpdated(items) => {
-                app.resources = items;
+                app.set_resources(items);
+                app.watch_reconnecting = false;
                 app.loading = false;
+                app.apply_pending_select();
                 // Ensure selection stays in bounds
-                let len = app.filtered_resources().len();
+                let len = app.filtered_len();
                 if len > 0 {
                     if let Some(selected) = app.table_state.selected() {
                         if selected >= len {
@@ -637,34 +1260,40 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                     }
                 }
             }
-            AppEvent::NamespacesLoaded(namespaces) => {
-                app.namespaces = namespaces;
-                // Try to select the preferred namespace from kubeconfig
-                if let Some(ref pref) = app.preferred_namespace {
-                    if let Some(idx) = app.namespaces.iter().position(|n| n == pref) {
-                        app.selected_namespace = idx;
-                    } else {
-                        app.selected_namespace = 0;
-                    }
-                } else {
-                    app.selected_namespace = 0;
-                }
-                app.loading = false;
-                if app.focus == types::Focus::NamespaceSelector {
-                    app.update_dropdown_filter();
+            AppEvent::StartupFailed(message) => anyhow::bail!(message),
+            AppEvent::NamespacesLoaded {
+                namespaces,
+                preferred,
+                listed,
+            } => {
+                if preferred.is_some() {
+                    app.preferred_namespace = preferred;
                 }
+                app.set_namespaces(namespaces);
+                app.namespaces_unlisted = !listed;
             }
-            AppEvent::DetailLoaded(text) => {
-                app.detail_text = text;
+            AppEvent::DetailLoaded { target, text } => app.detail_loaded(target, text),
+            AppEvent::DetailFailed { target, message } => app.detail_failed(target, message),
+            AppEvent::YamlLoaded(yaml) => {
+                app.detail_raw_yaml = yaml;
                 app.loading = false;
+                copy_pending_yaml(&mut app);
+            }
+            AppEvent::DeletePreviewLoaded(lines) => {
+                // Ignore results that arrive after the dialog was dismissed
+                if app.view_mode == types::ViewMode::Confirm(types::ConfirmAction::Delete) {
+                    app.delete_preview = Some(lines);
+                }
             }
+            AppEvent::EditDryRun(result) => app.finish_edit_dry_run(result),
             AppEvent::LogLine(line) => {
-                app.log_lines.push(line);
+                app.push_log_line(line);
                 app.loading = false;
             }
             AppEvent::LogStreamEnded => {
                 app.loading = false;
             }
+            AppEvent::ServerUrlLoaded(url) => app.server_url = Some(url),
             AppEvent::ContextsLoaded { contexts, current, current_namespace } => {
                 app.contexts = contexts;
                 if let Some(idx) = app.contexts.iter().position(|c| c == &current) {
@@ -673,6 +1302,11 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                 if app.focus == types::Focus::ContextSelector {
                     app.update_dropdown_filter();
                 }
+                spawn_context_probes(
+                    app.contexts_to_probe(true),
+                    app.search_concurrency,
+                    tx.clone(),
+                );
                 // Store preferred namespace for when namespaces load
                 app.preferred_namespace = Some(current_namespace.clone());
                 // Pre-select if namespaces already loaded
@@ -688,6 +1322,7 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                 let action_tx = tx.clone();
                 let ns = app.current_namespace().to_string();
                 let rt = app.resource_type;
+                let selector = app.watch_selector();
                 let handle = tokio::spawn(async move {
                     let guard = mgr.lock().await;
                     if let Some(ref manager) = *guard {
@@ -705,13 +1340,18 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                             client,
                             &ns,
                             rt,
+                            &selector,
                             action_tx.clone(),
                         )
                         .await
                         {
-                            let _ = action_tx.send(AppEvent::K8sError(format!(
-                                "Watch error: {}",
-                                e
+                            let _ = action_tx.send(AppEvent::K8sError(k8s::errors::explain(
+                                "Watch error",
+                                &e,
+                                "list",
+                                rt,
+                                None,
+                                &ns,
                             )));
                         }
                     }
@@ -728,13 +1368,17 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
             AppEvent::K8sError(msg) => {
                 app.set_error(msg);
                 app.loading = false;
+                // A failed YAML fetch shouldn't copy whatever loads next
+                app.yaml_to_clipboard = None;
             }
+            AppEvent::K8sInfo(msg) => app.set_info(msg),
             AppEvent::SearchResultsBatch {
                 context,
                 resource_type,
                 items,
             } => {
-                if app.view_mode == types::ViewMode::Search {
+                // Batches still queued from a cancelled scan are dropped
+                if app.view_mode == types::ViewMode::Search && app.search_loading {
                     for item in items {
                         app.search_results.push(types::SearchResult {
                             resource: item,
@@ -745,8 +1389,31 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
                     app.update_search_filter();
                 }
             }
+            AppEvent::PodMetricsUpdated(metrics) => {
+                app.pod_metrics = metrics;
+                app.apply_pod_metrics();
+            }
+            AppEvent::ContextProbed { context, health } => {
+                app.set_context_health(context, health);
+            }
+            AppEvent::PortForwardStarted(forward) => {
+                if !app.port_forwards.contains(&forward) {
+                    app.port_forwards.push(forward);
+                }
+            }
+            AppEvent::PortForwardFailed { forward, error } => {
+                app.port_forwards.retain(|f| f != &forward);
+                port_forwards.stop(&forward);
+                app.set_error(format!("Port-forward error: {}", error));
+            }
+            AppEvent::SearchContextFailed { context, error } => {
+                // Failures of a cancelled scan are dropped like its results
+                if app.view_mode == types::ViewMode::Search && app.search_loading {
+                    app.search_failures.push((context, error));
+                }
+            }
             AppEvent::SearchScanComplete(_context) => {
-                if app.view_mode == types::ViewMode::Search {
+                if app.view_mode == types::ViewMode::Search && app.search_loading {
                     app.search_contexts_done += 1;
                     if app.search_contexts_done >= app.search_contexts_total {
                         app.search_loading = false;
@@ -763,21 +1430,237 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
     Ok(())
 }
 
-fn write_logs_to_tempfile(log_lines: &[String]) -> Result<std::path::PathBuf> {
-    use std::io::Write;
+/// Describe a resource, or list just its events, in the background and
+/// send the text as `DetailLoaded`.
+fn spawn_describe(
+    mgr: std::sync::Arc<tokio::sync::Mutex<Option<k8s::client::K8sManager>>>,
+    tx: tokio::sync::mpsc::UnboundedSender<AppEvent>,
+    target: types::DetailTarget,
+    reveal: bool,
+    events: bool,
+    limit: std::time::Duration,
+) {
+    tokio::spawn(async move {
+        let guard = mgr.lock().await;
+        let Some(ref manager) = *guard else {
+            let message = "Describe error: not connected".to_string();
+            let _ = tx.send(AppEvent::DetailFailed { target, message });
+            return;
+        };
+        let client = manager.client.clone();
+        drop(guard);
+        let (ns, name, rt) = &target;
+        let (ns, name, rt) = (ns.as_str(), name.as_str(), *rt);
+        let event = if events {
+            let events = async { Ok(k8s::resources::describe_events(client, ns, name, rt).await) };
+            with_timeout(limit, "loading events", events)
+                .await
+                .map_err(|e| format!("Describe error: {}", e))
+        } else {
+            let describe = k8s::errors::retry(|| {
+                k8s::resources::describe_resource(client.clone(), ns, name, rt, reveal)
+            });
+            with_timeout(limit, "describing", describe)
+                .await
+                .map_err(|e| k8s::errors::explain("Describe error", &e, "get", rt, Some(name), ns))
+        };
+        let _ = tx.send(match event {
+            Ok(text) => AppEvent::DetailLoaded { target, text },
+            Err(message) => AppEvent::DetailFailed { target, message },
+        });
+    });
+}
+
+/// Hand the terminal to a subprocess for the duration of `run`: stop
+/// reading input, leave raw mode and the alternate screen, then restore
+/// them and redraw from scratch. Keys typed into the subprocess are drained
+/// rather than replayed into the app.
+fn with_suspended_terminal<T>(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    events: &mut EventHandler,
+    run: impl FnOnce() -> T,
+) -> Result<T> {
+    events.suspend();
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+
+    let result = run();
+
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.clear()?;
+    events.resume();
+    Ok(result)
+}
+
+/// Await a load, giving up after `limit` so a hung API server ends in an
+/// error instead of an endless spinner.
+async fn with_timeout<T>(
+    limit: std::time::Duration,
+    what: &str,
+    load: impl std::future::Future<Output = Result<T>>,
+) -> Result<T> {
+    match tokio::time::timeout(limit, load).await {
+        Ok(result) => result,
+        Err(_) => Err(k8s::errors::timed_out(limit, what)),
+    }
+}
 
+/// Probe each context's API server in the background, `concurrency` at a
+/// time, and report each result as `ContextProbed`.
+fn spawn_context_probes(
+    contexts: Vec<String>,
+    concurrency: usize,
+    tx: tokio::sync::mpsc::UnboundedSender<AppEvent>,
+) {
+    if contexts.is_empty() {
+        return;
+    }
+    let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency));
+    for context in contexts {
+        let tx = tx.clone();
+        let permits = permits.clone();
+        tokio::spawn(async move {
+            let Ok(_permit) = permits.acquire_owned().await else {
+                return;
+            };
+            let health = match k8s::client::K8sManager::probe_context(&context).await {
+                Ok(()) => types::ContextHealth::Reachable,
+                Err(_) => types::ContextHealth::Unreachable,
+            };
+            let _ = tx.send(AppEvent::ContextProbed { context, health });
+        });
+    }
+}
+
+fn write_log_lines(out: &mut impl std::io::Write, log_lines: &[String]) -> std::io::Result<()> {
+    for line in log_lines {
+        writeln!(out, "{}", line)?;
+    }
+    out.flush()
+}
+
+fn write_logs_to_tempfile(log_lines: &[String]) -> Result<std::path::PathBuf> {
     let mut tmp = tempfile::Builder::new()
         .prefix("kterm-logs-")
         .suffix(".log")
         .tempfile()?;
-    for line in log_lines {
-        writeln!(tmp, "{}", line)?;
-    }
-    tmp.flush()?;
+    write_log_lines(&mut tmp, log_lines)?;
     let (_, path) = tmp.keep()?;
     Ok(path)
 }
 
+/// Write the log buffer to `path`, expanding a leading `~/` to `$HOME`.
+/// An existing file is left alone and the logs go to the first free
+/// `name-1.log`, `name-2.log`, ... beside it. Returns the path written.
+fn save_logs(path: &str, log_lines: &[String]) -> Result<std::path::PathBuf> {
+    let path = match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => std::path::PathBuf::from(home).join(rest),
+        _ => std::path::PathBuf::from(path),
+    };
+    let (mut file, path) = create_new_numbered(&path)?;
+    write_log_lines(&mut file, log_lines)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Most `-N` suffixes tried before giving up on finding a free name.
+const MAX_NUMBERED_FILES: u32 = 1000;
+
+/// Create `path` if nothing is there yet, else the first free numbered
+/// variant of it, without ever truncating an existing file.
+fn create_new_numbered(path: &std::path::Path) -> Result<(std::fs::File, std::path::PathBuf)> {
+    for n in 0..MAX_NUMBERED_FILES {
+        let candidate = match n {
+            0 => path.to_path_buf(),
+            n => numbered_path(path, n),
+        };
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+        {
+            Ok(file) => return Ok((file, candidate)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to create {}", candidate.display()))
+            }
+        }
+    }
+    anyhow::bail!(
+        "Failed to create {}: every numbered name is taken",
+        path.display()
+    )
+}
+
+/// `dir/name-N.ext` for `dir/name.ext`.
+fn numbered_path(path: &std::path::Path, n: u32) -> std::path::PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!("{}-{}.{}", stem, n, ext.to_string_lossy()),
+        None => format!("{}-{}", stem, n),
+    };
+    path.with_file_name(name)
+}
+
+/// Clipboard commands tried in order; the first one that runs wins.
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip.exe", &[]),
+];
+
+/// Copy the YAML `c` asked for in the detail view, now that it is loaded.
+fn copy_pending_yaml(app: &mut App) {
+    let Some(what) = app.yaml_to_clipboard.take() else {
+        return;
+    };
+    match copy_to_clipboard(&app.detail_raw_yaml) {
+        Ok(()) => app.set_info(format!("Copied {} to clipboard", what)),
+        Err(e) => app.set_error(format!("Copy failed: {:#}", e)),
+    }
+}
+
+/// Copy `text` to the system clipboard by piping it into the first
+/// available clipboard tool. Fails (rather than panicking) on headless
+/// machines where none of them can run.
+fn copy_to_clipboard(text: &str) -> Result<()> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    for (program, args) in CLIPBOARD_COMMANDS {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        // A tool can be installed yet unusable (e.g. xclip without a
+        // display), so a failed write or exit status moves on to the next
+        let written = child
+            .stdin
+            .take()
+            .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+        if child.wait().is_ok_and(|s| s.success()) && written {
+            return Ok(());
+        }
+    }
+    anyhow::bail!("no clipboard available (install pbcopy, wl-copy, xclip or xsel)")
+}
+
 fn open_logs_in_editor(log_lines: &[String]) -> Result<()> {
     let path = write_logs_to_tempfile(log_lines)?;
     let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
@@ -894,6 +1777,14 @@ fn open_logs_in_less(
 }
 
 fn edit_yaml_in_editor(yaml: &str) -> Result<Option<String>> {
+    let new_content = run_editor(yaml)?;
+    // No changes
+    Ok(new_content.filter(|content| content != yaml))
+}
+
+/// Open `yaml` in `$EDITOR` and read back what was saved; `None` when the
+/// editor exited with an error.
+fn run_editor(yaml: &str) -> Result<Option<String>> {
     use std::io::Write;
 
     let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
@@ -911,10 +1802,5 @@ fn edit_yaml_in_editor(yaml: &str) -> Result<Option<String>> {
         return Ok(None);
     }
 
-    let new_content = std::fs::read_to_string(&path)?;
-    if new_content == yaml {
-        return Ok(None); // No changes
-    }
-
-    Ok(Some(new_content))
+    Ok(Some(std::fs::read_to_string(&path)?))
 }
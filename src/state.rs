@@ -0,0 +1,80 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::types::{Bookmark, ResourceType};
+
+/// What kterm remembers between runs, kept in
+/// `$XDG_STATE_HOME/kterm/state.yaml` (or `~/.local/state/kterm/state.yaml`).
+/// Unlike the config it is written by kterm itself.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct State {
+    /// Namespaces picked in the dropdown per context, most recent first.
+    pub recent_namespaces: BTreeMap<String, Vec<String>>,
+    /// Contexts starred in the context dropdown.
+    pub favorite_contexts: BTreeSet<String>,
+    /// Resources bookmarked with `b`, oldest first.
+    #[serde(deserialize_with = "skip_unreadable_bookmarks")]
+    pub bookmarks: Vec<Bookmark>,
+    /// Type last listed, opened again at startup unless `--resource` is given.
+    pub resource_type: Option<ResourceType>,
+}
+
+/// Bookmarks that parse, dropping any that don't (say, of a type a newer
+/// kterm added) instead of failing the whole state over one entry.
+fn skip_unreadable_bookmarks<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<Bookmark>, D::Error> {
+    let entries = Vec::<serde_yaml::Value>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| serde_yaml::from_value(entry).ok())
+        .collect())
+}
+
+pub fn state_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_STATE_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local").join("state"))
+        })?;
+    Some(base.join("kterm").join("state.yaml"))
+}
+
+/// Load the saved state. A missing file starts afresh; one that can't be
+/// read or parsed is an error, so the caller can warn and leave it alone
+/// rather than overwrite the bookmarks in it.
+pub fn load() -> Result<State> {
+    let Some(path) = state_path() else {
+        return Ok(State::default());
+    };
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(State::default()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    parse(&text).with_context(|| format!("Invalid state {}", path.display()))
+}
+
+pub fn parse(text: &str) -> Result<State> {
+    if text.trim().is_empty() {
+        return Ok(State::default());
+    }
+    Ok(serde_yaml::from_str(text)?)
+}
+
+pub fn save(state: &State) -> Result<()> {
+    let Some(path) = state_path() else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    let text = serde_yaml::to_string(state)?;
+    std::fs::write(&path, text).with_context(|| format!("Failed to write {}", path.display()))
+}
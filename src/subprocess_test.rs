@@ -5,7 +5,8 @@ mod tests {
 
     use crate::app::{App, InputAction};
     use crate::event::{AppEvent, EventHandler};
-    use crate::types::{ResourceItem, ResourceType, ViewMode};
+    use crate::test_support::ago;
+    use crate::types::{ResourceItem, ResourceType, SpecSummary, ViewMode};
 
     fn key(code: KeyCode) -> KeyEvent {
         KeyEvent {
@@ -21,18 +22,18 @@ mod tests {
             name: name.to_string(),
             namespace: "default".to_string(),
             status: "Running".to_string(),
-            age: "1h".to_string(),
+            created: ago(3600),
             extra: vec![
                 ("restarts".to_string(), "0".to_string()),
                 ("node".to_string(), "node-a".to_string()),
             ],
-            raw_yaml: "---\napiVersion: v1\nkind: Pod".to_string(),
+            spec: SpecSummary::default(),
         }
     }
 
     fn app_in_logs_view() -> App {
         let mut app = App::new();
-        app.resources = vec![fake_pod("pod-0")];
+        app.set_resources(vec![fake_pod("pod-0")]);
         app.resource_type = ResourceType::Pods;
         app.view_mode = ViewMode::Logs;
         app.log_lines = vec![
@@ -108,8 +109,15 @@ mod tests {
 
         // Events arriving after suspend but before resume
         tx.send(AppEvent::Key(key(KeyCode::Char('x')))).unwrap();
-        tx.send(AppEvent::DetailLoaded("detail".to_string()))
-            .unwrap();
+        tx.send(AppEvent::DetailLoaded {
+            target: (
+                "default".to_string(),
+                "pod-0".to_string(),
+                ResourceType::Pods,
+            ),
+            text: "detail".to_string(),
+        })
+        .unwrap();
         tx.send(AppEvent::Key(key(KeyCode::Char('y')))).unwrap();
 
         // Resume should drain stale key events
@@ -121,7 +129,7 @@ mod tests {
         let mut found_stale_key = false;
         while let Ok(event) = handler.try_recv() {
             match event {
-                AppEvent::DetailLoaded(_) => found_detail = true,
+                AppEvent::DetailLoaded { .. } => found_detail = true,
                 AppEvent::Key(_) => found_stale_key = true,
                 _ => {} // Tick events are fine
             }
@@ -156,6 +164,31 @@ mod tests {
     // Verify subprocess launch actions still route correctly
     // -----------------------------------------------------------------------
 
+    /// Saving logs never overwrites: a taken name gets a numeric suffix.
+    #[test]
+    fn test_save_logs_keeps_existing_files() {
+        let dir = std::env::temp_dir().join(format!("kterm-save-logs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("pod-0.log");
+        std::fs::write(&path, "earlier\n").unwrap();
+        let target = path.to_str().unwrap();
+
+        let written = crate::save_logs(target, &["first".to_string()]).unwrap();
+        assert_eq!(written, dir.join("pod-0-1.log"));
+        let written = crate::save_logs(target, &["second".to_string()]).unwrap();
+        assert_eq!(written, dir.join("pod-0-2.log"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "earlier\n");
+        assert!(std::fs::read_to_string(&written)
+            .unwrap()
+            .contains("second"));
+
+        let bare = dir.join("notes");
+        std::fs::write(&bare, "").unwrap();
+        let written = crate::save_logs(bare.to_str().unwrap(), &[]).unwrap();
+        assert_eq!(written, dir.join("notes-1"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     /// Pressing 'o' in Logs view returns OpenLogsInEditor.
     #[test]
     fn test_open_in_editor_action() {
@@ -177,7 +210,7 @@ mod tests {
     fn test_edit_yaml_action() {
         let mut app = App::new();
         app.focus = crate::types::Focus::ResourceList;
-        app.resources = vec![fake_pod("pod-0")];
+        app.set_resources(vec![fake_pod("pod-0")]);
         app.resource_type = ResourceType::Pods;
         let action = app.handle_input(key(KeyCode::Char('e')));
         assert_eq!(action, InputAction::Edit);
@@ -187,7 +220,7 @@ mod tests {
     #[test]
     fn test_edit_from_detail_view() {
         let mut app = App::new();
-        app.resources = vec![fake_pod("pod-0")];
+        app.set_resources(vec![fake_pod("pod-0")]);
         app.resource_type = ResourceType::Pods;
         app.view_mode = ViewMode::Detail;
         let action = app.handle_input(key(KeyCode::Char('e')));
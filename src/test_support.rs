@@ -0,0 +1,25 @@
+//! Fixtures shared by the app, UI and subprocess tests.
+
+use crate::types::{ResourceItem, SpecSummary};
+
+/// Creation time `secs` seconds ago.
+pub fn ago(secs: i64) -> Option<i64> {
+    Some(crate::types::unix_now() - secs)
+}
+
+/// An Events row for `pod/web-0`, last seen `last_seen` seconds ago.
+pub fn fake_event(name: &str, type_: &str, reason: &str, last_seen: i64) -> ResourceItem {
+    ResourceItem {
+        name: name.to_string(),
+        namespace: "default".to_string(),
+        status: type_.to_string(),
+        created: ago(last_seen),
+        extra: vec![
+            ("type".to_string(), type_.to_string()),
+            ("reason".to_string(), reason.to_string()),
+            ("object".to_string(), "pod/web-0".to_string()),
+            ("message".to_string(), "Back-off pulling image".to_string()),
+        ],
+        spec: SpecSummary::default(),
+    }
+}
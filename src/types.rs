@@ -1,4 +1,86 @@
 use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Pod CPU/memory usage display strings keyed by "namespace/name".
+pub type PodMetrics = std::collections::HashMap<String, (String, String)>;
+
+/// Synthetic namespace entry that lists namespaced resources across the
+/// whole cluster.
+pub const ALL_NAMESPACES: &str = "<all>";
+
+/// Namespace to open in a freshly listed cluster: the context's kubeconfig
+/// default if it exists there, otherwise the first one listed.
+pub fn initial_namespace<'a>(namespaces: &'a [String], preferred: &str) -> Option<&'a str> {
+    namespaces
+        .iter()
+        .find(|n| *n == preferred)
+        .or_else(|| namespaces.first())
+        .map(|n| n.as_str())
+}
+
+/// Label and field selectors the API server filters a watch or list by,
+/// each a comma-separated `kubectl --selector` / `--field-selector` string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selector {
+    pub labels: Option<String>,
+    pub fields: Option<String>,
+}
+
+impl Selector {
+    /// The `label:<selector>` and `field:<selector>` terms of a list filter.
+    /// Several terms of a kind are ANDed, as commas do in one selector.
+    pub fn from_filter(filter: &str) -> Selector {
+        let terms = |prefix: &str| {
+            let terms: Vec<&str> = filter
+                .split_whitespace()
+                .filter_map(|t| t.strip_prefix(prefix))
+                .filter(|t| !t.is_empty())
+                .collect();
+            (!terms.is_empty()).then(|| terms.join(","))
+        };
+        Selector {
+            labels: terms("label:"),
+            fields: terms("field:"),
+        }
+    }
+
+    /// Whether the filter term `term` is a selector rather than a name match.
+    pub fn is_term(term: &str) -> bool {
+        term.starts_with("label:") || term.starts_with("field:")
+    }
+
+    /// This selector also requiring the field selector `fields`.
+    pub fn with_fields(mut self, fields: &str) -> Selector {
+        self.fields = Some(match self.fields {
+            Some(existing) => format!("{},{}", fields, existing),
+            None => fields.to_string(),
+        });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_none() && self.fields.is_none()
+    }
+}
+
+/// Field selector for the pods scheduled on `node`.
+pub fn node_pods_selector(node: &str) -> String {
+    format!("spec.nodeName={}", node)
+}
+
+/// `(namespace, name, type)` of the resource a detail view describes, so
+/// a description that arrives late can be told apart from the open one.
+pub type DetailTarget = (String, String, ResourceType);
+
+/// Log lines fetched when a stream starts, unless changed with `T`.
+pub const DEFAULT_LOG_TAIL: i64 = 100;
+
+/// Windows `S` cycles the Logs view through, in seconds (None = no limit).
+pub const LOG_SINCE_WINDOWS: [Option<i64>; 4] = [Some(300), Some(900), Some(3600), None];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ResourceType {
@@ -67,14 +149,28 @@ impl ResourceType {
         ResourceType::PodDisruptionBudgets,
     ];
 
-    pub fn column_headers(&self) -> Vec<&'static str> {
+    /// Headers of the type's columns in `layout`. Only pods have compact and
+    /// wide layouts; other types always use their regular columns.
+    pub fn column_headers(&self, layout: ColumnLayout) -> Vec<&'static str> {
         match self {
-            ResourceType::Pods => vec!["NAME", "STATUS", "AGE", "RESTARTS", "NODE"],
+            ResourceType::Pods => match layout {
+                ColumnLayout::Compact => vec!["NAME", "STATUS", "AGE"],
+                ColumnLayout::Regular => {
+                    vec![
+                        "NAME", "READY", "STATUS", "AGE", "RESTARTS", "NODE", "CPU", "MEM",
+                    ]
+                }
+                ColumnLayout::Wide => vec![
+                    "NAME", "READY", "STATUS", "AGE", "RESTARTS", "IP", "NODE", "CPU", "MEM",
+                ],
+            },
             ResourceType::Deployments => {
                 vec!["NAME", "READY", "UP-TO-DATE", "AVAILABLE", "AGE"]
             }
             ResourceType::StatefulSets => vec!["NAME", "READY", "AGE"],
-            ResourceType::DaemonSets => vec!["NAME", "DESIRED", "CURRENT", "READY", "AGE"],
+            ResourceType::DaemonSets => {
+                vec!["NAME", "DESIRED", "CURRENT", "READY", "UP-TO-DATE", "AGE"]
+            }
             ResourceType::ReplicaSets => vec!["NAME", "DESIRED", "CURRENT", "READY", "AGE"],
             ResourceType::ReplicationControllers => {
                 vec!["NAME", "DESIRED", "CURRENT", "READY", "AGE"]
@@ -86,7 +182,7 @@ impl ResourceType {
             }
             ResourceType::Services => vec!["NAME", "TYPE", "CLUSTER-IP", "PORTS", "AGE"],
             ResourceType::Endpoints => vec!["NAME", "ENDPOINTS", "AGE"],
-            ResourceType::Ingresses => vec!["NAME", "CLASS", "HOSTS", "AGE"],
+            ResourceType::Ingresses => vec!["NAME", "CLASS", "HOSTS", "ADDRESS", "AGE"],
             ResourceType::NetworkPolicies => vec!["NAME", "POD-SELECTOR", "AGE"],
             ResourceType::ConfigMaps => vec!["NAME", "DATA", "AGE"],
             ResourceType::Secrets => vec!["NAME", "TYPE", "DATA", "AGE"],
@@ -99,8 +195,8 @@ impl ResourceType {
             ResourceType::StorageClasses => vec!["NAME", "PROVISIONER", "AGE"],
             ResourceType::ServiceAccounts => vec!["NAME", "AGE"],
             ResourceType::Namespaces => vec!["NAME", "STATUS", "AGE"],
-            ResourceType::Nodes => vec!["NAME", "STATUS", "ROLES", "AGE"],
-            ResourceType::Events => vec!["NAME", "TYPE", "REASON", "MESSAGE", "AGE"],
+            ResourceType::Nodes => vec!["NAME", "STATUS", "ROLES", "VERSION", "AGE"],
+            ResourceType::Events => vec!["TYPE", "REASON", "OBJECT", "MESSAGE", "AGE"],
             ResourceType::ResourceQuotas => vec!["NAME", "AGE"],
             ResourceType::LimitRanges => vec!["NAME", "AGE"],
             ResourceType::PodDisruptionBudgets => {
@@ -109,9 +205,55 @@ impl ResourceType {
         }
     }
 
+    /// Alternate headers shown when the image layout is toggled on, swapping
+    /// columns for the container image. `None` for types without one.
+    pub fn image_column_headers(&self) -> Option<Vec<&'static str>> {
+        match self {
+            ResourceType::Pods => Some(vec![
+                "NAME", "READY", "STATUS", "AGE", "IMAGE", "CPU", "MEM",
+            ]),
+            _ => None,
+        }
+    }
+
+    /// The type named `name`, as listed in the type selector or in the
+    /// singular ("deployment"), ignoring case.
+    pub fn from_name(name: &str) -> Option<ResourceType> {
+        let name = name.to_lowercase();
+        ResourceType::ALL.into_iter().find(|rt| {
+            let plural = rt.to_string();
+            let singular = if let Some(stem) = plural.strip_suffix("ies") {
+                format!("{}y", stem)
+            } else if let Some(stem) = plural.strip_suffix("sses") {
+                format!("{}ss", stem)
+            } else {
+                plural.strip_suffix('s').unwrap_or(&plural).to_string()
+            };
+            name == plural || name == singular
+        })
+    }
+
+    /// Returns true if this resource type has compact and wide layouts.
+    pub fn supports_column_layouts(&self) -> bool {
+        matches!(self, ResourceType::Pods)
+    }
+
+    /// Returns true if this resource type has an image column layout.
+    pub fn supports_image_column(&self) -> bool {
+        self.image_column_headers().is_some()
+    }
+
     /// Returns true if this resource type supports viewing logs.
     pub fn supports_logs(&self) -> bool {
-        matches!(self, ResourceType::Pods)
+        matches!(self, ResourceType::Pods) || self.aggregates_logs()
+    }
+
+    /// Returns true if logs are merged from every pod the workload selects.
+    pub fn aggregates_logs(&self) -> bool {
+        matches!(
+            self,
+            ResourceType::Deployments | ResourceType::StatefulSets | ResourceType::DaemonSets
+        )
     }
 
     /// Returns true if this resource type supports restart.
@@ -125,7 +267,77 @@ impl ResourceType {
         )
     }
 
-    #[allow(dead_code)]
+    /// Returns true if the detail view can reveal decoded values.
+    pub fn supports_reveal(&self) -> bool {
+        matches!(self, ResourceType::Secrets)
+    }
+
+    /// Returns true if `D` can delete without graceful termination, for
+    /// pods stuck terminating on an unreachable node.
+    pub fn supports_force_delete(&self) -> bool {
+        matches!(self, ResourceType::Pods)
+    }
+
+    /// Returns true if deleting this resource cascades to owned objects
+    /// worth previewing before confirming.
+    pub fn has_dependents(&self) -> bool {
+        matches!(self, ResourceType::Deployments | ResourceType::StatefulSets)
+    }
+
+    /// Returns true if this resource type can be port-forwarded to.
+    pub fn supports_port_forward(&self) -> bool {
+        matches!(self, ResourceType::Pods | ResourceType::StatefulSets)
+    }
+
+    /// Returns true if this resource type has a replica count that can be scaled.
+    pub fn supports_scale(&self) -> bool {
+        matches!(self, ResourceType::Deployments | ResourceType::StatefulSets)
+    }
+
+    /// Returns true if `n` can start a new resource of this type from a
+    /// template (see `k8s::templates`).
+    pub fn supports_create(&self) -> bool {
+        crate::k8s::templates::template(*self, "").is_some()
+    }
+
+    /// Returns true if `P` can list the pods on the selected row's node.
+    pub fn supports_node_pods(&self) -> bool {
+        matches!(self, ResourceType::Pods | ResourceType::Nodes)
+    }
+
+    /// The resource type for an object `kind` (e.g. from an owner reference).
+    pub fn from_kind(kind: &str) -> Option<ResourceType> {
+        let rt = match kind {
+            "Pod" => ResourceType::Pods,
+            "Deployment" => ResourceType::Deployments,
+            "StatefulSet" => ResourceType::StatefulSets,
+            "DaemonSet" => ResourceType::DaemonSets,
+            "ReplicaSet" => ResourceType::ReplicaSets,
+            "ReplicationController" => ResourceType::ReplicationControllers,
+            "Job" => ResourceType::Jobs,
+            "CronJob" => ResourceType::CronJobs,
+            "HorizontalPodAutoscaler" => ResourceType::HorizontalPodAutoscalers,
+            "Service" => ResourceType::Services,
+            "Endpoints" => ResourceType::Endpoints,
+            "Ingress" => ResourceType::Ingresses,
+            "NetworkPolicy" => ResourceType::NetworkPolicies,
+            "ConfigMap" => ResourceType::ConfigMaps,
+            "Secret" => ResourceType::Secrets,
+            "PersistentVolumeClaim" => ResourceType::PersistentVolumeClaims,
+            "PersistentVolume" => ResourceType::PersistentVolumes,
+            "StorageClass" => ResourceType::StorageClasses,
+            "ServiceAccount" => ResourceType::ServiceAccounts,
+            "Namespace" => ResourceType::Namespaces,
+            "Node" => ResourceType::Nodes,
+            "Event" => ResourceType::Events,
+            "ResourceQuota" => ResourceType::ResourceQuotas,
+            "LimitRange" => ResourceType::LimitRanges,
+            "PodDisruptionBudget" => ResourceType::PodDisruptionBudgets,
+            _ => return None,
+        };
+        Some(rt)
+    }
+
     /// Returns true for cluster-scoped resources (not namespaced).
     pub fn is_cluster_scoped(&self) -> bool {
         matches!(
@@ -170,6 +382,53 @@ impl fmt::Display for ResourceType {
     }
 }
 
+/// Saved (bookmarks, last listed type) by its selector name ("deployments").
+impl Serialize for ResourceType {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResourceType {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(d)?;
+        ResourceType::from_name(&name)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown resource type '{}'", name)))
+    }
+}
+
+/// How many columns the list shows, cycled with `w` for types that
+/// support it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnLayout {
+    /// Just NAME, STATUS and AGE, for narrow terminals.
+    Compact,
+    #[default]
+    Regular,
+    /// Everything, including the pod IP.
+    Wide,
+}
+
+impl ColumnLayout {
+    pub fn next(self) -> Self {
+        match self {
+            ColumnLayout::Regular => ColumnLayout::Wide,
+            ColumnLayout::Wide => ColumnLayout::Compact,
+            ColumnLayout::Compact => ColumnLayout::Regular,
+        }
+    }
+}
+
+impl fmt::Display for ColumnLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnLayout::Compact => write!(f, "compact"),
+            ColumnLayout::Regular => write!(f, "regular"),
+            ColumnLayout::Wide => write!(f, "wide"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ViewMode {
     List,
@@ -182,18 +441,58 @@ pub enum ViewMode {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConfirmAction {
     Delete,
+    ForceDelete,
     Restart,
+    ApplyEdit,
+    /// `q` while actions are still in flight.
+    Quit,
 }
 
 impl fmt::Display for ConfirmAction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ConfirmAction::Delete => write!(f, "Delete"),
+            ConfirmAction::ForceDelete => write!(f, "Force delete"),
             ConfirmAction::Restart => write!(f, "Restart"),
+            ConfirmAction::ApplyEdit => write!(f, "Apply edit"),
+            ConfirmAction::Quit => write!(f, "Quit"),
         }
     }
 }
 
+/// Number of actions (delete, restart, scale, apply) still running in
+/// spawned tasks, so `q` can ask before abandoning them. Each task holds
+/// the guard from `start`, so the count drops however the task ends.
+#[derive(Debug, Clone, Default)]
+pub struct InFlight(Arc<AtomicUsize>);
+
+pub struct InFlightGuard(Arc<AtomicUsize>);
+
+impl InFlight {
+    pub fn start(&self) -> InFlightGuard {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        InFlightGuard(Arc::clone(&self.0))
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Result of the background reachability probe of a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextHealth {
+    Unknown,
+    Reachable,
+    Unreachable,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
     ContextSelector,
@@ -227,23 +526,75 @@ pub struct ResourceItem {
     pub name: String,
     pub namespace: String,
     pub status: String,
-    pub age: String,
+    pub created: Option<i64>, // creation time in Unix seconds; AGE is derived from it
     pub extra: Vec<(String, String)>,
-    pub raw_yaml: String,
+    pub spec: SpecSummary,
+}
+
+/// The parts of an object the list actions need, taken from it during
+/// conversion. The full YAML is fetched on demand instead of being kept
+/// for every row.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecSummary {
+    /// Container names of the pod spec, or of the pod template for
+    /// workloads, in spec order.
+    pub containers: Vec<String>,
+    /// First `containerPort` declared by those containers.
+    pub container_port: Option<u16>,
+    /// Desired `spec.replicas` (Kubernetes defaults it to 1), for workloads.
+    pub replicas: Option<i64>,
+    /// `(kind, name)` of the owner, preferring the managing controller.
+    pub owner: Option<(String, String)>,
 }
 
 impl ResourceItem {
-    /// Returns column values matching the headers for the given resource type.
-    pub fn columns(&self, resource_type: ResourceType) -> Vec<String> {
-        resource_type
-            .column_headers()
+    /// Whether the status needs no attention. Types without a status count
+    /// as healthy, so the problems filter hides them.
+    pub fn is_healthy(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "" | "Running"
+                | "Bound"
+                | "Active"
+                | "Ready"
+                | "Available"
+                | "Succeeded"
+                | "Completed"
+                | "Normal"
+        )
+    }
+
+    /// Readiness for types that have one: pods must be Running or finished,
+    /// workloads need every desired replica ready. `None` for other types.
+    pub fn is_ready(&self, resource_type: ResourceType) -> Option<bool> {
+        match resource_type {
+            ResourceType::Pods => Some(matches!(
+                self.status.as_str(),
+                "Running" | "Succeeded" | "Completed"
+            )),
+            ResourceType::Deployments | ResourceType::StatefulSets => {
+                let ready = self.extra_val("ready");
+                let (have, want) = ready.split_once('/')?;
+                Some(have == want)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns column values matching `headers`, a resource type's
+    /// `column_headers(layout)` or its image layout.
+    pub fn columns(&self, headers: &[&str]) -> Vec<String> {
+        headers
             .iter()
             .map(|h| {
                 let key = h.to_lowercase();
                 match key.as_str() {
                     "name" => self.name.clone(),
-                    "status" | "phase" => self.status.clone(),
-                    "age" => self.age.clone(),
+                    "status" | "phase" => match self.extra.iter().find(|(k, _)| k == "last_exit") {
+                        Some((_, last_exit)) => format!("{} ({})", self.status, last_exit),
+                        None => self.status.clone(),
+                    },
+                    "age" => format_age(self.created),
                     "namespace" => self.namespace.clone(),
                     _ => self.extra_val(&key),
                 }
@@ -260,6 +611,565 @@ impl ResourceItem {
     }
 }
 
+/// Seconds since the Unix epoch.
+pub fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+/// Compact age of something created at `created` (Unix seconds), e.g.
+/// "3d2h", "5h10m", "7m" or "12s". Computed against the current time, so
+/// ages keep counting up on every render.
+pub fn format_age(created: Option<i64>) -> String {
+    let Some(created) = created else {
+        return "<unknown>".to_string();
+    };
+    let diff_secs = unix_now() - created;
+
+    if diff_secs < 0 {
+        return "0s".to_string();
+    }
+
+    let days = diff_secs / 86400;
+    let hours = (diff_secs % 86400) / 3600;
+    let minutes = (diff_secs % 3600) / 60;
+    let seconds = diff_secs % 60;
+
+    if days > 0 {
+        format!("{}d{}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h{}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+/// Parses an age produced by `format_age` (e.g. "2d3h", "10m", "45s") back
+/// to seconds. Returns None for values like "<unknown>".
+pub fn parse_age(age: &str) -> Option<u64> {
+    let mut total = 0u64;
+    let mut num = String::new();
+    for c in age.chars() {
+        if c.is_ascii_digit() {
+            num.push(c);
+            continue;
+        }
+        let unit = match c {
+            'd' => 86400,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        total += num.parse::<u64>().ok()? * unit;
+        num.clear();
+    }
+    (num.is_empty() && !age.is_empty()).then_some(total)
+}
+
+/// Orders two column values: ages by duration, numbers numerically, and
+/// everything else lexically.
+pub fn compare_column(header: &str, a: &str, b: &str) -> std::cmp::Ordering {
+    if header == "AGE" {
+        return parse_age(a).cmp(&parse_age(b));
+    }
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.total_cmp(&y),
+        _ => a.cmp(b),
+    }
+}
+
+/// An active (or starting) port-forward from localhost to a resource.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PortForward {
+    pub namespace: String,
+    pub name: String,
+    pub resource_type: ResourceType,
+    pub local_port: u16,
+    pub remote_port: u16,
+}
+
+impl PortForward {
+    pub fn targets(&self, resource_type: ResourceType, item: &ResourceItem) -> bool {
+        self.resource_type == resource_type
+            && self.name == item.name
+            && self.namespace == item.namespace
+    }
+}
+
+/// Parses `localPort:remotePort`, or a single port used for both.
+pub fn parse_port_mapping(input: &str) -> Option<(u16, u16)> {
+    let input = input.trim();
+    let (local, remote) = input.split_once(':').unwrap_or((input, input));
+    let local: u16 = local.trim().parse().ok()?;
+    let remote: u16 = remote.trim().parse().ok()?;
+    (local != 0 && remote != 0).then_some((local, remote))
+}
+
+/// Options a log stream is opened with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogOptions {
+    pub tail_lines: Option<i64>,    // None = whole log
+    pub previous: bool,             // logs of the previous (crashed) container instance
+    pub timestamps: bool,           // prefix each line with its RFC3339 timestamp
+    pub since_seconds: Option<i64>, // only lines newer than this; None = no limit
+    pub open_timeout: Duration,     // give up if the stream (or pod list) hasn't answered by then
+}
+
+/// Parses a log tail length: a positive line count, or `0`/`all` for the
+/// whole log (`Some(None)`). Returns `None` for anything else.
+pub fn parse_tail_lines(input: &str) -> Option<Option<i64>> {
+    let input = input.trim();
+    if input.eq_ignore_ascii_case("all") {
+        return Some(None);
+    }
+    match input.parse::<i64>().ok()? {
+        0 => Some(None),
+        n if n > 0 => Some(Some(n)),
+        _ => None,
+    }
+}
+
+/// Label for a tail length, as shown in the Logs title and the prompt.
+pub fn tail_label(tail: Option<i64>) -> String {
+    tail.map(|n| n.to_string())
+        .unwrap_or_else(|| "all".to_string())
+}
+
+/// Label for a since-window, as shown in the Logs title: `5m`, `1h`, `all`.
+pub fn since_label(since: Option<i64>) -> String {
+    match since {
+        None => "all".to_string(),
+        Some(secs) if secs % 3600 == 0 => format!("{}h", secs / 3600),
+        Some(secs) if secs % 60 == 0 => format!("{}m", secs / 60),
+        Some(secs) => format!("{}s", secs),
+    }
+}
+
+/// What a single-line text prompt is collecting input for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    PortForward,
+    Scale,
+    TailLines,
+    SaveLogs,
+    LogSelector,
+}
+
+impl PromptKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            PromptKind::PortForward => "Port-forward (localPort:remotePort)",
+            PromptKind::Scale => "Scale to replicas",
+            PromptKind::TailLines => "Log tail lines (number, or 0/all for everything)",
+            PromptKind::SaveLogs => "Save logs to file",
+            PromptKind::LogSelector => "Stream logs of pods matching labels (e.g. app=nginx)",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Prompt {
+    pub kind: PromptKind,
+    pub input: String,
+}
+
+/// What a command palette entry does when run: press a list key, or move
+/// focus to a selector and open its dropdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteCommand {
+    Key(char),
+    Ctrl(char),
+    Enter,
+    Focus(Focus),
+}
+
+impl fmt::Display for PaletteCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaletteCommand::Key(' ') => write!(f, "Space"),
+            PaletteCommand::Key(c) => write!(f, "{}", c),
+            PaletteCommand::Ctrl(c) => write!(f, "Ctrl+{}", c.to_ascii_uppercase()),
+            PaletteCommand::Enter => write!(f, "Enter"),
+            PaletteCommand::Focus(_) => write!(f, "Tab"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PaletteEntry {
+    pub label: &'static str,
+    pub command: PaletteCommand,
+    /// Whether the entry is offered for the current resource type.
+    pub applies: fn(&ResourceType) -> bool,
+}
+
+fn any_type(_: &ResourceType) -> bool {
+    true
+}
+
+const fn entry(label: &'static str, command: PaletteCommand) -> PaletteEntry {
+    PaletteEntry {
+        label,
+        command,
+        applies: any_type,
+    }
+}
+
+impl PaletteEntry {
+    const fn when(self, applies: fn(&ResourceType) -> bool) -> PaletteEntry {
+        PaletteEntry { applies, ..self }
+    }
+}
+
+/// Every action the Ctrl+P palette offers, acting on the resource list.
+pub const PALETTE_ENTRIES: &[PaletteEntry] = &[
+    entry("Describe", PaletteCommand::Enter),
+    entry("Show events", PaletteCommand::Key('E')),
+    entry("View logs", PaletteCommand::Key('l')).when(ResourceType::supports_logs),
+    entry("Logs by label selector", PaletteCommand::Key('L')),
+    entry("Edit YAML", PaletteCommand::Key('e')),
+    entry("Create from template", PaletteCommand::Key('n')).when(ResourceType::supports_create),
+    entry("Delete", PaletteCommand::Key('d')),
+    entry("Force delete", PaletteCommand::Key('D')).when(ResourceType::supports_force_delete),
+    entry("Restart", PaletteCommand::Key('r')).when(ResourceType::supports_restart),
+    entry("Scale replicas", PaletteCommand::Key('=')).when(ResourceType::supports_scale),
+    entry("Port-forward", PaletteCommand::Key('p')).when(ResourceType::supports_port_forward),
+    entry("Mark for batch delete", PaletteCommand::Key(' ')),
+    entry("Copy name", PaletteCommand::Key('y')),
+    entry("Copy namespace/name", PaletteCommand::Key('Y')),
+    entry("Refresh", PaletteCommand::Key('R')),
+    entry("Sort by next column", PaletteCommand::Key('s')),
+    entry("Filter by name", PaletteCommand::Key('/')),
+    entry("Toggle problems only", PaletteCommand::Key('!')),
+    entry("Toggle image columns", PaletteCommand::Key('i'))
+        .when(ResourceType::supports_image_column),
+    entry("Cycle column layout", PaletteCommand::Key('w'))
+        .when(ResourceType::supports_column_layouts),
+    entry("Bookmark resource", PaletteCommand::Key('b')),
+    entry("Show bookmarks", PaletteCommand::Key('B')),
+    entry("Pods on this node", PaletteCommand::Key('P')).when(ResourceType::supports_node_pods),
+    entry(
+        "Switch context",
+        PaletteCommand::Focus(Focus::ContextSelector),
+    ),
+    entry(
+        "Switch namespace",
+        PaletteCommand::Focus(Focus::NamespaceSelector),
+    ),
+    entry(
+        "Switch resource type",
+        PaletteCommand::Focus(Focus::ResourceTypeSelector),
+    ),
+    entry("Search all clusters", PaletteCommand::Ctrl('f')),
+    entry("Search this namespace", PaletteCommand::Ctrl('g')),
+    entry("Help", PaletteCommand::Key('?')),
+    entry("Message history", PaletteCommand::Key('M')),
+    entry("Quit", PaletteCommand::Key('q')),
+];
+
+/// Ctrl+P command palette: a query and the `PALETTE_ENTRIES` indices it
+/// matches, best first.
+#[derive(Debug, Clone, Default)]
+pub struct Palette {
+    pub query: String,
+    pub filtered: Vec<usize>,
+    pub selected: usize,
+}
+
+/// A resource saved with `b`, listed in the `B` overlay to jump back to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub context: String,
+    pub namespace: String,
+    #[serde(rename = "type")]
+    pub resource_type: ResourceType,
+    pub name: String,
+}
+
+/// How serious a footer message is; sets its color and how long it stays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Confirmation of something that worked ("Copied ... to clipboard").
+    Info,
+    /// Nothing failed, but the request wasn't carried out as asked.
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Ticks the message stays in the footer (250ms each).
+    pub fn dismiss_ticks(self) -> u8 {
+        match self {
+            Severity::Info => 12,
+            Severity::Warning | Severity::Error => 20,
+        }
+    }
+}
+
+/// YAML edited in `$EDITOR`, held back until its diff is confirmed.
+#[derive(Debug, Clone)]
+pub struct PendingEdit {
+    pub namespace: String,
+    pub name: String,
+    pub resource_type: ResourceType,
+    pub yaml: String,
+    /// A new resource from a template, created rather than replaced.
+    pub create: bool,
+    /// Unified diff from the live YAML to `yaml`.
+    pub diff: Vec<String>,
+    /// The edit changes nothing but whitespace.
+    pub whitespace_only: bool,
+    /// Outcome of the last server-side dry run, if one was requested.
+    pub dry_run: Option<DryRun>,
+}
+
+/// State of a server-side dry run of a pending edit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DryRun {
+    Running,
+    Passed,
+    /// The API server's rejection (schema error, admission webhook, ...).
+    Failed(String),
+}
+
+impl PendingEdit {
+    pub fn new(
+        namespace: String,
+        name: String,
+        resource_type: ResourceType,
+        original: &str,
+        yaml: String,
+    ) -> Self {
+        let squash = |s: &str| s.split_whitespace().collect::<String>();
+        Self {
+            diff: unified_diff(original, &yaml, 3),
+            whitespace_only: squash(original) == squash(&yaml),
+            namespace,
+            name,
+            resource_type,
+            yaml,
+            create: false,
+            dry_run: None,
+        }
+    }
+
+    /// A resource to create from `yaml`, named by its metadata. Namespaced
+    /// types go to the namespace it gives, or `namespace` when it gives none.
+    /// YAML that doesn't parse is an error.
+    pub fn create(
+        namespace: String,
+        resource_type: ResourceType,
+        yaml: String,
+    ) -> Result<Self, serde_yaml::Error> {
+        let doc: serde_yaml::Value = serde_yaml::from_str(&yaml)?;
+        let field = |key: &str| {
+            doc.get("metadata")
+                .and_then(|m| m.get(key))
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+        let namespace = if resource_type.is_cluster_scoped() {
+            String::new()
+        } else {
+            field("namespace").unwrap_or(namespace)
+        };
+        let name = field("name").unwrap_or_default();
+        Ok(Self {
+            create: true,
+            ..Self::new(namespace, name, resource_type, "", yaml)
+        })
+    }
+
+    /// The object a replace writes to; `None` when creating.
+    pub fn target(&self) -> Option<&str> {
+        (!self.create).then_some(self.name.as_str())
+    }
+
+    /// Why the edited YAML can't replace the resource it was opened for:
+    /// a changed `metadata.name` or `metadata.namespace` would make the
+    /// replace target one object with a body naming another. A missing
+    /// namespace is fine, the API fills it in. A new resource only needs a
+    /// name.
+    pub fn target_mismatch(&self) -> Option<String> {
+        let doc: serde_yaml::Value = match serde_yaml::from_str(&self.yaml) {
+            Ok(doc) => doc,
+            Err(e) => return Some(format!("invalid YAML: {}", e)),
+        };
+        if self.create {
+            return self
+                .name
+                .is_empty()
+                .then(|| "metadata.name is missing".to_string());
+        }
+        let field = |key: &str| {
+            doc.get("metadata")
+                .and_then(|m| m.get(key))
+                .and_then(|v| v.as_str())
+        };
+        let name = field("name").unwrap_or_default();
+        if name != self.name {
+            return Some(format!(
+                "metadata.name changed from '{}' to '{}'",
+                self.name, name
+            ));
+        }
+        match field("namespace") {
+            Some(ns) if !self.resource_type.is_cluster_scoped() && ns != self.namespace => {
+                Some(format!(
+                    "metadata.namespace changed from '{}' to '{}'",
+                    self.namespace, ns
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Lines added and removed by the edit.
+    pub fn counts(&self) -> (usize, usize) {
+        let changed = |prefix: char| self.diff.iter().filter(|l| l.starts_with(prefix)).count();
+        (changed('+'), changed('-'))
+    }
+}
+
+/// Largest LCS table `diff_ops` builds (16MB of u32s); past it the changed
+/// middle is shown removed and re-added whole.
+const DIFF_MAX_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    Same,
+    Removed,
+    Added,
+}
+
+/// Line diff of `old` against `new` in unified format: `@@ -a,b +c,d @@`
+/// hunk headers, then lines prefixed with ' ', '-' or '+', keeping
+/// `context` unchanged lines around each change.
+pub fn unified_diff(old: &str, new: &str, context: usize) -> Vec<String> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let ops = diff_ops(&a, &b);
+
+    // Old and new line numbers reached before each op
+    let mut old_at = vec![0; ops.len() + 1];
+    let mut new_at = vec![0; ops.len() + 1];
+    for (i, (op, _)) in ops.iter().enumerate() {
+        old_at[i + 1] = old_at[i] + usize::from(*op != DiffOp::Added);
+        new_at[i + 1] = new_at[i] + usize::from(*op != DiffOp::Removed);
+    }
+
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (i, _) in ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != DiffOp::Same)
+    {
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let range = |at: &[usize], start: usize, end: usize| {
+        let len = at[end] - at[start];
+        // An empty side is numbered by the line before it, as diff(1) does
+        let first = if len == 0 { at[start] } else { at[start] + 1 };
+        format!("{},{}", first, len)
+    };
+    let mut lines = Vec::new();
+    for (start, end) in hunks {
+        lines.push(format!(
+            "@@ -{} +{} @@",
+            range(&old_at, start, end),
+            range(&new_at, start, end)
+        ));
+        for (op, text) in &ops[start..end] {
+            let prefix = match op {
+                DiffOp::Same => ' ',
+                DiffOp::Removed => '-',
+                DiffOp::Added => '+',
+            };
+            lines.push(format!("{}{}", prefix, text));
+        }
+    }
+    lines
+}
+
+/// Longest-common-subsequence edit script from `a` to `b`. The common
+/// prefix and suffix are split off first so typical small edits of a
+/// large manifest stay cheap; a changed middle too big for the table
+/// becomes one block of removals then additions.
+fn diff_ops<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<(DiffOp, &'a str)> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (mid_a, mid_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut ops: Vec<(DiffOp, &str)> = a[..prefix].iter().map(|l| (DiffOp::Same, *l)).collect();
+    let (n, m) = (mid_a.len(), mid_b.len());
+    if n.saturating_mul(m) > DIFF_MAX_CELLS {
+        ops.extend(mid_a.iter().map(|l| (DiffOp::Removed, *l)));
+        ops.extend(mid_b.iter().map(|l| (DiffOp::Added, *l)));
+        ops.extend(a[a.len() - suffix..].iter().map(|l| (DiffOp::Same, *l)));
+        return ops;
+    }
+
+    // lcs[i][j]: common subsequence length of mid_a[i..] and mid_b[j..]
+    let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[at(i, j)] = if mid_a[i] == mid_b[j] {
+                lcs[at(i + 1, j + 1)] + 1
+            } else {
+                lcs[at(i + 1, j)].max(lcs[at(i, j + 1)])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && mid_a[i] == mid_b[j] {
+            ops.push((DiffOp::Same, mid_a[i]));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[at(i + 1, j)] >= lcs[at(i, j + 1)]) {
+            ops.push((DiffOp::Removed, mid_a[i]));
+            i += 1;
+        } else {
+            ops.push((DiffOp::Added, mid_b[j]));
+            j += 1;
+        }
+    }
+    ops.extend(a[a.len() - suffix..].iter().map(|l| (DiffOp::Same, *l)));
+    ops
+}
+
+/// Restricts a search to one context and namespace instead of scanning
+/// every cluster.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchScope {
+    pub context: String,
+    pub namespace: String,
+}
+
+impl fmt::Display for SearchScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.context, self.namespace)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub resource: ResourceItem,
@@ -270,19 +1180,57 @@ pub struct SearchResult {
 /// Fuzzy subsequence match. Returns a score if all characters in `query`
 /// appear in order within `target`, or None if they don't.
 pub fn fuzzy_match(query: &str, target: &str) -> Option<i64> {
+    fuzzy_match_positions(query, target).map(|(score, _)| score)
+}
+
+/// `fuzzy_match` that also returns the char indices of `target` that
+/// matched, for highlighting.
+///
+/// A query that isn't a subsequence of `target` gets one more try with a
+/// pair of adjacent characters swapped, so "gtehnode" still finds
+/// "geth-node". Such typo matches score half, ranking below clean ones.
+pub fn fuzzy_match_positions(query: &str, target: &str) -> Option<(i64, Vec<usize>)> {
     let query_lower: Vec<char> = query.to_lowercase().chars().collect();
     let target_lower: Vec<char> = target.to_lowercase().chars().collect();
 
     if query_lower.is_empty() {
-        return Some(0);
+        return Some((0, Vec::new()));
+    }
+    if let Some(found) = subsequence_match(&query_lower, &target_lower) {
+        return Some(found);
+    }
+    // Two-letter queries would match almost anything once swapped
+    if query_lower.len() < 3 {
+        return None;
     }
 
+    let mut swapped = query_lower.clone();
+    let mut best: Option<(i64, Vec<usize>)> = None;
+    for i in 0..swapped.len() - 1 {
+        if swapped[i] == swapped[i + 1] {
+            continue;
+        }
+        swapped.swap(i, i + 1);
+        if let Some((score, positions)) = subsequence_match(&swapped, &target_lower) {
+            if best.as_ref().is_none_or(|(b, _)| score / 2 > *b) {
+                best = Some((score / 2, positions));
+            }
+        }
+        swapped.swap(i, i + 1);
+    }
+    best
+}
+
+/// Scores `query` as an in-order subsequence of `target`: consecutive and
+/// word-boundary matches earn bonuses, as do shorter targets.
+fn subsequence_match(query: &[char], target: &[char]) -> Option<(i64, Vec<usize>)> {
     let mut qi = 0;
     let mut score: i64 = 0;
     let mut prev_matched = false;
+    let mut positions = Vec::with_capacity(query.len());
 
-    for (ti, &tc) in target_lower.iter().enumerate() {
-        if qi < query_lower.len() && tc == query_lower[qi] {
+    for (ti, &tc) in target.iter().enumerate() {
+        if qi < query.len() && tc == query[qi] {
             score += 1;
             // Consecutive match bonus
             if prev_matched {
@@ -291,24 +1239,59 @@ pub fn fuzzy_match(query: &str, target: &str) -> Option<i64> {
             // Word boundary bonus (start of string, after - or _ or /)
             if ti == 0
                 || matches!(
-                    target_lower.get(ti.wrapping_sub(1)),
+                    target.get(ti.wrapping_sub(1)),
                     Some('-') | Some('_') | Some('/')
                 )
             {
                 score += 3;
             }
             prev_matched = true;
+            positions.push(ti);
             qi += 1;
         } else {
             prev_matched = false;
         }
     }
 
-    if qi == query_lower.len() {
+    if qi == query.len() {
         // Bonus for shorter targets (more precise match)
-        score += (100 - target_lower.len() as i64).max(0);
-        Some(score)
+        score += (100 - target.len() as i64).max(0);
+        Some((score, positions))
     } else {
         None
     }
 }
+
+/// A search query split into its `ns:` and `ctx:` terms and the rest,
+/// which matches the resource name.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub name: String,
+    pub namespace: String,
+    pub context: String,
+}
+
+impl SearchQuery {
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = Self::default();
+        for term in query.split_whitespace() {
+            if let Some(ns) = term.strip_prefix("ns:") {
+                parsed.namespace.push_str(ns);
+            } else if let Some(ctx) = term.strip_prefix("ctx:") {
+                parsed.context.push_str(ctx);
+            } else {
+                parsed.name.push_str(term);
+            }
+        }
+        parsed
+    }
+
+    /// Combined fuzzy score of a result, or None if any term misses.
+    pub fn score(&self, result: &SearchResult) -> Option<i64> {
+        Some(
+            fuzzy_match(&self.name, &result.resource.name)?
+                + fuzzy_match(&self.namespace, &result.resource.namespace)?
+                + fuzzy_match(&self.context, &result.context)?,
+        )
+    }
+}
@@ -0,0 +1,164 @@
+use std::borrow::Cow;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+
+/// A piece of a line with ANSI escape sequences in it.
+enum Token<'a> {
+    Text(&'a str),
+    /// Parameters of a Select Graphic Rendition sequence (`ESC [ ... m`).
+    Sgr(&'a str),
+    /// Any other escape sequence: cursor movement, titles, hyperlinks.
+    Control,
+}
+
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        if !self.rest.starts_with('\x1b') {
+            let end = self.rest.find('\x1b').unwrap_or(self.rest.len());
+            let (text, rest) = self.rest.split_at(end);
+            self.rest = rest;
+            return Some(Token::Text(text));
+        }
+        let bytes = self.rest.as_bytes();
+        let (token, len) = match bytes.get(1) {
+            // CSI: parameter bytes up to a final byte in `@`..=`~`
+            Some(b'[') => match bytes[2..].iter().position(|b| (0x40..=0x7e).contains(b)) {
+                Some(i) if bytes[2 + i] == b'm' => (Token::Sgr(&self.rest[2..2 + i]), 3 + i),
+                Some(i) => (Token::Control, 3 + i),
+                None => (Token::Control, bytes.len()),
+            },
+            // OSC: ends at BEL or `ESC \`
+            Some(b']') => {
+                let body = &self.rest[2..];
+                let bel = body.find('\x07').map(|i| i + 1);
+                let st = body.find("\x1b\\").map(|i| i + 2);
+                let end = bel.into_iter().chain(st).min().unwrap_or(body.len());
+                (Token::Control, 2 + end)
+            }
+            _ => {
+                let next = self.rest[1..].chars().next().map_or(0, char::len_utf8);
+                (Token::Control, 1 + next)
+            }
+        };
+        self.rest = &self.rest[len..];
+        Some(token)
+    }
+}
+
+fn tokens(line: &str) -> Tokens<'_> {
+    Tokens { rest: line }
+}
+
+/// `line` without its escape sequences.
+pub fn strip(line: &str) -> Cow<'_, str> {
+    if !line.contains('\x1b') {
+        return Cow::Borrowed(line);
+    }
+    let text: String = tokens(line)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect();
+    Cow::Owned(text)
+}
+
+/// `line` as spans styled by its SGR sequences on top of `base`, so text
+/// the app didn't color keeps the base (log level) style.
+pub fn spans(line: &str, base: Style) -> Vec<Span<'_>> {
+    let mut style = Style::default();
+    let mut spans = Vec::new();
+    for token in tokens(line) {
+        match token {
+            Token::Text(text) => spans.push(Span::styled(text, base.patch(style))),
+            Token::Sgr(params) => style = apply_sgr(style, params),
+            Token::Control => {}
+        }
+    }
+    spans
+}
+
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    // An empty or unparsable parameter counts as 0, a reset
+    let mut codes = params
+        .split([';', ':'])
+        .map(|p| p.parse::<u16>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        style = match code {
+            0 => Style::default(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            5 | 6 => style.add_modifier(Modifier::SLOW_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            8 => style.add_modifier(Modifier::HIDDEN),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            21 | 22 => style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style.remove_modifier(Modifier::ITALIC),
+            24 => style.remove_modifier(Modifier::UNDERLINED),
+            25 => style.remove_modifier(Modifier::SLOW_BLINK),
+            27 => style.remove_modifier(Modifier::REVERSED),
+            28 => style.remove_modifier(Modifier::HIDDEN),
+            29 => style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37 => style.fg(basic_color(code - 30)),
+            40..=47 => style.bg(basic_color(code - 40)),
+            90..=97 => style.fg(bright_color(code - 90)),
+            100..=107 => style.bg(bright_color(code - 100)),
+            38 => extended_color(&mut codes).map_or(style, |c| style.fg(c)),
+            48 => extended_color(&mut codes).map_or(style, |c| style.bg(c)),
+            39 => Style { fg: None, ..style },
+            49 => Style { bg: None, ..style },
+            _ => style,
+        };
+    }
+    style
+}
+
+/// The color of a `38;5;n` or `38;2;r;g;b` sequence (or `48;...`).
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    let mut component = || codes.next().and_then(|c| u8::try_from(c).ok());
+    match component()? {
+        5 => Some(Color::Indexed(component()?)),
+        2 => Some(Color::Rgb(component()?, component()?, component()?)),
+        _ => None,
+    }
+}
+
+fn basic_color(index: u16) -> Color {
+    const COLORS: [Color; 8] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+    ];
+    COLORS[usize::from(index)]
+}
+
+fn bright_color(index: u16) -> Color {
+    const COLORS: [Color; 8] = [
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+    COLORS[usize::from(index)]
+}
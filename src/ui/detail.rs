@@ -1,35 +1,57 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::ui::pane_border;
 
-pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.areas.content = area;
+    let view = if app.detail_yaml {
+        " [YAML]"
+    } else if app.secret_revealed {
+        " [revealed]"
+    } else if app.detail_events {
+        " [events]"
+    } else {
+        ""
+    };
+    // Only listed resources refresh, so search results never show it
+    let frozen = if !app.detail_live && !app.entered_from_search {
+        " [frozen]"
+    } else {
+        ""
+    };
     let title = app
         .selected_resource()
-        .map(|r| format!(" {} ", r.name))
-        .unwrap_or_else(|| " Detail ".to_string());
+        .map(|r| format!(" {}{}{} ", r.name, view, frozen))
+        .unwrap_or_else(|| format!(" Detail{}{} ", view, frozen));
 
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(pane_border(app, false));
 
-    let text = if app.detail_text.is_empty() {
-        if app.loading {
-            "Loading...".to_string()
+    let content = app.detail_content();
+    let text = if content.is_empty() {
+        if let Some(indicator) = app.loading_indicator() {
+            format!("Loading... {}", indicator)
         } else {
             "Press Enter on a resource to view details".to_string()
         }
     } else {
-        app.detail_text.clone()
+        content.to_string()
     };
 
-    let paragraph = Paragraph::new(text)
-        .block(block)
-        .wrap(Wrap { trim: false })
-        .scroll((app.detail_scroll, 0));
+    let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: false });
+    // Clamp to the last screenful of wrapped rows; `G` relies on this to
+    // land on the bottom with the real viewport size
+    let rows = paragraph.line_count(area.width.saturating_sub(2));
+    let max_scroll = rows
+        .saturating_sub(area.height as usize)
+        .min(u16::MAX as usize) as u16;
+    app.detail_scroll = app.detail_scroll.min(max_scroll);
+    let paragraph = paragraph.scroll((app.detail_scroll, 0));
 
     frame.render_widget(paragraph, area);
 }
@@ -5,15 +5,18 @@ use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
 use ratatui::Frame;
 
 use crate::app::App;
-use crate::types::Focus;
+use crate::types::{ContextHealth, Focus};
+use crate::ui::fuzzy_highlight;
 
-pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::horizontal([
         Constraint::Percentage(33),
         Constraint::Percentage(34),
         Constraint::Percentage(33),
     ])
     .split(area);
+    app.areas.selectors = [chunks[0], chunks[1], chunks[2]];
+    let app = &*app;
 
     let show_query_for = |focus: Focus| -> Option<&str> {
         if app.focus == focus && app.dropdown_visible && !app.dropdown_query.is_empty() {
@@ -23,24 +26,40 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         }
     };
 
+    // Danger mode is flagged where the context is picked
+    let danger = app.danger_mode();
     render_selector(
         frame,
-        "Context",
+        app,
+        if danger {
+            "Context ⚠ DANGER"
+        } else {
+            "Context"
+        },
         &app.contexts,
         app.selected_context,
         app.focus == Focus::ContextSelector,
         show_query_for(Focus::ContextSelector),
         chunks[0],
+        danger,
     );
 
+    // Cluster-scoped types ignore the namespace, so say so on the selector
+    let namespace_title = if app.resource_type.is_cluster_scoped() {
+        "Namespace (cluster-scoped)"
+    } else {
+        "Namespace"
+    };
     render_selector(
         frame,
-        "Namespace",
+        app,
+        namespace_title,
         &app.namespaces,
         app.selected_namespace,
         app.focus == Focus::NamespaceSelector,
         show_query_for(Focus::NamespaceSelector),
         chunks[1],
+        false,
     );
 
     let type_names: Vec<String> = crate::types::ResourceType::ALL
@@ -54,28 +73,79 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
 
     render_selector(
         frame,
+        app,
         "Type",
         &type_names,
         type_idx,
         app.focus == Focus::ResourceTypeSelector,
         show_query_for(Focus::ResourceTypeSelector),
         chunks[2],
+        false,
+    );
+
+    // Which API server the context points at, for telling look-alike
+    // contexts apart
+    if let Some(url) = &app.server_url {
+        let area = chunks[0];
+        let width = (url.chars().count() as u16 + 2).min(area.width.saturating_sub(2));
+        if width > 0 && area.height > 0 {
+            let rect = Rect {
+                x: area.x + 1,
+                y: area.y + area.height - 1,
+                width,
+                height: 1,
+            };
+            let span = Span::styled(format!(" {} ", url), app.theme.border(false));
+            frame.render_widget(Paragraph::new(Line::from(span)), rect);
+        }
+    }
+
+    if app.watch_reconnecting {
+        render_border_label(frame, " reconnecting\u{2026} ", chunks[2]);
+    } else if let Some(indicator) = app.loading_indicator() {
+        render_border_label(frame, &format!(" {} loading ", indicator), chunks[2]);
+    }
+}
+
+/// Draw `label` over the bottom-right border of `area`.
+fn render_border_label(frame: &mut Frame, label: &str, area: Rect) {
+    let width = (label.chars().count() as u16).min(area.width.saturating_sub(2));
+    if width == 0 || area.height == 0 {
+        return;
+    }
+    let rect = Rect {
+        x: area.x + area.width - 1 - width,
+        y: area.y + area.height - 1,
+        width,
+        height: 1,
+    };
+    let span = Span::styled(
+        label,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
     );
+    frame.render_widget(Paragraph::new(Line::from(span)), rect);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_selector(
     frame: &mut Frame,
+    app: &App,
     title: &str,
     items: &[String],
     selected: usize,
     focused: bool,
     query: Option<&str>,
     area: Rect,
+    danger: bool,
 ) {
-    let border_style = if focused {
-        Style::default().fg(Color::Cyan)
+    let border_style = if danger {
+        Style::default()
+            .fg(app.theme.status_error)
+            .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        app.theme.border(focused)
     };
 
     let block = Block::default()
@@ -99,7 +169,7 @@ fn render_selector(
                 .fg(Color::White)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(app.theme.border_unfocused)
         };
 
         let line = Line::from(vec![Span::styled(value, text_style)]);
@@ -113,16 +183,35 @@ pub fn render_dropdown(frame: &mut Frame, app: &App, area: Rect) {
     let items = app.dropdown_items();
 
     // Build the list items from the filtered indices
+    let matched = app.theme.match_style();
+    let contexts = app.focus == Focus::ContextSelector;
     let list_items: Vec<ListItem> = app
         .dropdown_filtered
         .iter()
         .map(|&idx| {
             let name = items.get(idx).map(|s| s.as_str()).unwrap_or("?");
-            ListItem::new(name.to_string())
+            let mut line = fuzzy_highlight(name, &app.dropdown_query, matched);
+            if contexts {
+                let dot = health_dot(app, app.context_health(name));
+                line.spans.insert(0, dot);
+                if app.favorite_contexts.contains(name) {
+                    line.spans
+                        .push(Span::styled(" ★", Style::default().fg(Color::Yellow)));
+                }
+            }
+            ListItem::new(line)
         })
         .collect();
 
-    let title = if app.dropdown_query.is_empty() {
+    let namespaces = app.focus == Focus::NamespaceSelector;
+    let typed = app.dropdown_query.trim();
+    let title = if contexts && app.favorites_only {
+        format!(" {} favorites ", app.dropdown_filtered.len())
+    } else if namespaces && app.dropdown_filtered.is_empty() && !typed.is_empty() {
+        format!(" Enter to open '{}' ", typed)
+    } else if namespaces && app.namespaces_unlisted {
+        " not listed, type a namespace ".to_string()
+    } else if app.dropdown_query.is_empty() {
         format!(" {} items ", app.dropdown_filtered.len())
     } else {
         format!(
@@ -134,11 +223,9 @@ pub fn render_dropdown(frame: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(app.theme.border(true));
 
-    let highlight_style = Style::default()
-        .bg(Color::DarkGray)
-        .add_modifier(Modifier::BOLD);
+    let highlight_style = app.theme.highlight_style();
 
     let list = List::new(list_items)
         .block(block)
@@ -152,3 +239,16 @@ pub fn render_dropdown(frame: &mut Frame, app: &App, area: Rect) {
 
     frame.render_stateful_widget(list, area, &mut state);
 }
+
+/// "● " colored by probe result; a hollow dot while unknown.
+fn health_dot(app: &App, health: ContextHealth) -> Span<'static> {
+    match health {
+        ContextHealth::Reachable => Span::styled("● ", Style::default().fg(app.theme.status_ok)),
+        ContextHealth::Unreachable => {
+            Span::styled("● ", Style::default().fg(app.theme.status_error))
+        }
+        ContextHealth::Unknown => {
+            Span::styled("○ ", Style::default().fg(app.theme.border_unfocused))
+        }
+    }
+}
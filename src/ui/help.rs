@@ -1,131 +1,937 @@
 use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
 use ratatui::Frame;
 
 use crate::app::App;
-use crate::types::{ConfirmAction, Focus, ViewMode};
+use crate::types::{
+    format_age, ConfirmAction, DryRun, Focus, Palette, Prompt, ResourceItem, ResourceType,
+    Severity, ViewMode, PALETTE_ENTRIES,
+};
+use crate::ui::fuzzy_highlight;
 
-fn resource_list_bindings(app: &App) -> String {
-    let mut parts = vec!["q:Quit", "Tab:Selector", "j/k:Nav", "Enter:Detail"];
-    if app.resource_type.supports_logs() {
-        parts.push("l:Logs");
-    }
-    parts.push("d:Delete");
-    if app.resource_type.supports_restart() {
-        parts.push("r:Restart");
+/// A keybinding, the single source for both the footer hints and the `?`
+/// help overlay.
+struct Binding {
+    key: &'static str,
+    label: &'static str,
+    /// Shown in the footer; the overlay always lists every binding.
+    footer: bool,
+    /// Whether the binding applies to the current resource type.
+    applies: fn(&ResourceType) -> bool,
+}
+
+fn always(_: &ResourceType) -> bool {
+    true
+}
+
+const fn bind(key: &'static str, label: &'static str) -> Binding {
+    Binding {
+        key,
+        label,
+        footer: true,
+        applies: always,
     }
-    parts.push("e:Edit");
-    parts.push("/:Filter");
-    parts.push("Ctrl+F:Search");
-    parts.join("  ")
 }
 
-fn detail_bindings(app: &App) -> String {
-    let mut parts = vec!["Esc:Back", "j/k:Scroll", "e:Edit"];
-    if app.resource_type.supports_logs() {
-        parts.push("l:Logs");
+impl Binding {
+    /// Only offer the binding for resource types where `applies` holds.
+    const fn when(self, applies: fn(&ResourceType) -> bool) -> Binding {
+        Binding { applies, ..self }
     }
-    parts.push("d:Delete");
-    if app.resource_type.supports_restart() {
-        parts.push("r:Restart");
+
+    /// List the binding in the help overlay but not in the footer.
+    const fn help_only(self) -> Binding {
+        Binding {
+            footer: false,
+            ..self
+        }
+    }
+}
+
+/// Key-handling contexts, each with its own set of bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    ResourceList,
+    Selector,
+    Dropdown,
+    Filter,
+    Detail,
+    SearchDetail,
+    Logs,
+    SearchLogs,
+    LogSearch,
+    ContainerPicker,
+    Search,
+    Confirm,
+    EditDiff,
+    Prompt,
+    Palette,
+    MessageLog,
+    Bookmarks,
+    Help,
+}
+
+/// Sections in the order the help overlay lists them.
+const HELP_SECTIONS: [(Section, &str); 17] = [
+    (Section::ResourceList, "Resource list"),
+    (Section::Selector, "Selectors (Context / Namespace / Type)"),
+    (Section::Dropdown, "Selector dropdown"),
+    (Section::Filter, "Filter"),
+    (Section::Detail, "Detail view"),
+    (Section::Logs, "Logs view"),
+    (Section::LogSearch, "Log search input"),
+    (Section::ContainerPicker, "Container picker"),
+    (Section::Search, "Fuzzy search"),
+    (Section::SearchDetail, "Search result detail"),
+    (Section::SearchLogs, "Search result logs"),
+    (Section::Confirm, "Confirmation dialog"),
+    (Section::EditDiff, "Edit diff"),
+    (Section::Prompt, "Input prompt"),
+    (Section::Palette, "Command palette"),
+    (Section::MessageLog, "Message history"),
+    (Section::Bookmarks, "Bookmarks"),
+];
+
+fn section_bindings(section: Section) -> &'static [Binding] {
+    const RESOURCE_LIST: &[Binding] = &[
+        bind("q", "Quit"),
+        bind("Ctrl+C", "Quit").help_only(),
+        bind("Tab", "Selector"),
+        bind("Shift+Tab", "Previous selector").help_only(),
+        bind("j/k", "Nav"),
+        bind("gg/G", "First/last row").help_only(),
+        bind("5j/5k", "Move 5 rows").help_only(),
+        bind("Enter", "Detail"),
+        bind("E", "Events").help_only(),
+        bind("l", "Logs").when(ResourceType::supports_logs),
+        bind("L", "Logs by label selector").help_only(),
+        bind("d", "Delete"),
+        bind("D", "Force delete")
+            .when(ResourceType::supports_force_delete)
+            .help_only(),
+        bind("Space", "Mark for batch delete").help_only(),
+        bind("r", "Restart").when(ResourceType::supports_restart),
+        bind("p", "Port-forward").when(ResourceType::supports_port_forward),
+        bind("=", "Scale").when(ResourceType::supports_scale),
+        bind("e", "Edit"),
+        bind("n", "New from template")
+            .when(ResourceType::supports_create)
+            .help_only(),
+        bind("y", "Copy name"),
+        bind("Y", "Copy namespace/name").help_only(),
+        bind("R/F5", "Refresh").help_only(),
+        bind("s", "Sort"),
+        bind("/", "Filter"),
+        bind("!", "Problems only").help_only(),
+        bind("i", "Image columns")
+            .when(ResourceType::supports_image_column)
+            .help_only(),
+        bind("w", "Compact/wide columns")
+            .when(ResourceType::supports_column_layouts)
+            .help_only(),
+        bind("Ctrl+F", "Search"),
+        bind("Ctrl+G", "Search namespace").help_only(),
+        bind("Ctrl+P", "Commands").help_only(),
+        bind("?", "Help"),
+        bind("M", "Message history").help_only(),
+        bind("b", "Bookmark").help_only(),
+        bind("B", "Bookmarks").help_only(),
+        bind("P", "Pods on this node (Esc: all)")
+            .when(ResourceType::supports_node_pods)
+            .help_only(),
+    ];
+    const SELECTOR: &[Binding] = &[
+        bind("Esc", "Back"),
+        bind("Tab", "Next"),
+        bind("Type/Arrows", "Search..."),
+    ];
+    const DROPDOWN: &[Binding] = &[
+        bind("Esc", "Close"),
+        bind("Enter", "Select"),
+        bind("Up/Down", "Nav"),
+        bind("Type", "Filter..."),
+        bind("Backspace", "Delete filter character").help_only(),
+        bind("Ctrl+S", "Star/unstar context").help_only(),
+        bind("Ctrl+O", "Only favorite contexts").help_only(),
+    ];
+    const FILTER: &[Binding] = &[
+        bind("Esc", "Cancel"),
+        bind("Enter", "Apply"),
+        bind("Type", "Filter by name..."),
+        bind("status:<text>", "Match the STATUS column").help_only(),
+        bind("label:<selector>", "Server-side label selector, on Enter").help_only(),
+        bind("field:<selector>", "Server-side field selector, on Enter").help_only(),
+    ];
+    const DETAIL: &[Binding] = &[
+        bind("Esc", "Back"),
+        bind("j/k", "Scroll"),
+        bind("e", "Edit"),
+        bind("l", "Logs").when(ResourceType::supports_logs),
+        bind("d", "Delete"),
+        bind("D", "Force delete")
+            .when(ResourceType::supports_force_delete)
+            .help_only(),
+        bind("r", "Restart").when(ResourceType::supports_restart),
+        bind("p", "Port-forward").when(ResourceType::supports_port_forward),
+        bind("=", "Scale").when(ResourceType::supports_scale),
+        bind("g/G", "Top/Bottom"),
+        bind("v", "YAML"),
+        bind("x", "Reveal").when(ResourceType::supports_reveal),
+        bind("o", "Jump to owner"),
+        bind("E", "Events / full description").help_only(),
+        bind("f", "Freeze / live refresh").help_only(),
+        bind("y", "Copy name"),
+        bind("Y", "Copy namespace/name").help_only(),
+        bind("c", "Copy YAML").help_only(),
+        bind("?", "Help").help_only(),
+        bind("M", "Message history").help_only(),
+    ];
+    const SEARCH_DETAIL: &[Binding] = &[
+        bind("Esc", "Back to search"),
+        bind("j/k", "Scroll"),
+        bind("l", "Logs").when(ResourceType::supports_logs),
+        bind("y", "Copy context/namespace/name"),
+        bind("c", "Copy YAML").help_only(),
+        bind("g/G", "Top/Bottom"),
+        bind("v", "YAML"),
+    ];
+    const LOGS: &[Binding] = &[
+        bind("Esc", "Back"),
+        bind("f", "Follow"),
+        bind("j/k", "Scroll"),
+        bind("g/G", "Top/Bottom"),
+        bind("/", "Search"),
+        bind("n/N", "Next/Prev match"),
+        bind("&", "Grep"),
+        bind("i", "Ignore case"),
+        bind("P", "Previous container"),
+        bind("t", "Timestamps"),
+        bind("T", "Tail length"),
+        bind("S", "Since 5m/15m/1h/all").help_only(),
+        bind("w", "Wrap"),
+        bind("h/l", "Scroll sideways").help_only(),
+        bind("o", "Vim"),
+        bind("O", "Less"),
+        bind("s", "Save"),
+        bind("?", "Help").help_only(),
+        bind("M", "Message history").help_only(),
+    ];
+    const SEARCH_LOGS: &[Binding] = &[
+        bind("Esc", "Back to search"),
+        bind("f", "Follow"),
+        bind("j/k", "Scroll"),
+        bind("g/G", "Top/Bottom"),
+        bind("/", "Search"),
+        bind("n/N", "Next/Prev match"),
+        bind("&", "Grep"),
+        bind("i", "Ignore case"),
+        bind("P", "Previous container"),
+        bind("t", "Timestamps"),
+        bind("T", "Tail length"),
+        bind("S", "Since 5m/15m/1h/all").help_only(),
+        bind("w", "Wrap"),
+        bind("h/l", "Scroll sideways").help_only(),
+        bind("o", "Vim"),
+        bind("O", "Less"),
+        bind("s", "Save"),
+    ];
+    const LOG_SEARCH: &[Binding] = &[
+        bind("Esc", "Clear"),
+        bind("Enter", "Done"),
+        bind("Type", "Search logs..."),
+    ];
+    const CONTAINER_PICKER: &[Binding] = &[
+        bind("Esc", "Back"),
+        bind("j/k", "Nav"),
+        bind("Enter", "Select container"),
+    ];
+    const SEARCH: &[Binding] = &[
+        bind("Esc", "Back"),
+        bind("Down/Up", "Nav"),
+        bind("Enter", "Detail"),
+        bind("l", "Logs").help_only(),
+        bind("Ctrl+Y", "Copy context/namespace/name").help_only(),
+        bind("Ctrl+T", "Filter by type").help_only(),
+        bind("Ctrl+E", "Failed clusters").help_only(),
+        bind("Type", "Search..."),
+    ];
+    const CONFIRM: &[Binding] = &[bind("y", "Confirm"), bind("Any other key", "Cancel")];
+    const EDIT_DIFF: &[Binding] = &[
+        bind("y", "Apply (or create)"),
+        bind("d", "Server-side dry run"),
+        bind("n/Esc", "Discard"),
+        bind("j/k", "Scroll"),
+        bind("g/G", "Top/bottom").help_only(),
+    ];
+    const PROMPT: &[Binding] = &[bind("Enter", "Submit"), bind("Esc", "Cancel")];
+    const PALETTE: &[Binding] = &[
+        bind("Enter", "Run"),
+        bind("Up/Down", "Select"),
+        bind("Esc", "Close"),
+    ];
+    const MESSAGE_LOG: &[Binding] = &[bind("Esc/M", "Close"), bind("j/k", "Scroll")];
+    const BOOKMARKS: &[Binding] = &[
+        bind("Enter", "Go to resource"),
+        bind("d", "Remove"),
+        bind("j/k", "Nav"),
+        bind("Esc/B", "Close"),
+    ];
+    const HELP: &[Binding] = &[bind("Esc/?", "Close"), bind("j/k", "Scroll")];
+
+    match section {
+        Section::ResourceList => RESOURCE_LIST,
+        Section::Selector => SELECTOR,
+        Section::Dropdown => DROPDOWN,
+        Section::Filter => FILTER,
+        Section::Detail => DETAIL,
+        Section::SearchDetail => SEARCH_DETAIL,
+        Section::Logs => LOGS,
+        Section::SearchLogs => SEARCH_LOGS,
+        Section::LogSearch => LOG_SEARCH,
+        Section::ContainerPicker => CONTAINER_PICKER,
+        Section::Search => SEARCH,
+        Section::Confirm => CONFIRM,
+        Section::EditDiff => EDIT_DIFF,
+        Section::Prompt => PROMPT,
+        Section::Palette => PALETTE,
+        Section::MessageLog => MESSAGE_LOG,
+        Section::Bookmarks => BOOKMARKS,
+        Section::Help => HELP,
     }
-    parts.push("g/G:Top/Bottom");
-    parts.join("  ")
 }
 
-fn search_detail_bindings(app: &App) -> String {
-    let mut parts = vec!["Esc:Back to search", "j/k:Scroll"];
-    if app.resource_type.supports_logs() {
-        parts.push("l:Logs");
+/// The section whose keys are currently live.
+fn active_section(app: &App) -> Section {
+    if app.show_help {
+        return Section::Help;
+    }
+    if app.show_message_log {
+        return Section::MessageLog;
+    }
+    if app.show_bookmarks {
+        return Section::Bookmarks;
+    }
+    if app.prompt.is_some() {
+        return Section::Prompt;
+    }
+    if app.palette.is_some() {
+        return Section::Palette;
+    }
+    match app.view_mode {
+        ViewMode::List if app.filter_active => Section::Filter,
+        ViewMode::List => match app.focus {
+            Focus::ResourceList => Section::ResourceList,
+            _ if app.dropdown_visible => Section::Dropdown,
+            _ => Section::Selector,
+        },
+        ViewMode::Detail if app.entered_from_search => Section::SearchDetail,
+        ViewMode::Detail => Section::Detail,
+        ViewMode::Logs if !app.container_choices.is_empty() => Section::ContainerPicker,
+        ViewMode::Logs if app.log_search_active => Section::LogSearch,
+        ViewMode::Logs if app.entered_from_search => Section::SearchLogs,
+        ViewMode::Logs => Section::Logs,
+        ViewMode::Confirm(ConfirmAction::ApplyEdit) => Section::EditDiff,
+        ViewMode::Confirm(_) => Section::Confirm,
+        ViewMode::Search => Section::Search,
     }
-    parts.push("g/G:Top/Bottom");
-    parts.join("  ")
+}
+
+/// Footer hint line for the active section, e.g. "q:Quit  j/k:Nav".
+fn footer_bindings(app: &App) -> String {
+    section_bindings(active_section(app))
+        .iter()
+        .filter(|b| b.footer && (b.applies)(&app.resource_type))
+        .map(|b| format!("{}:{}", b.key, b.label))
+        .collect::<Vec<_>>()
+        .join("  ")
 }
 
 pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
-    let bindings_owned: String;
-    let bindings: &str = match app.view_mode {
-        ViewMode::List => {
-            if app.filter_active {
-                "Esc:Cancel  Enter:Apply  Type to filter..."
-            } else if matches!(
-                app.focus,
-                Focus::ContextSelector
-                    | Focus::NamespaceSelector
-                    | Focus::ResourceTypeSelector
-            ) {
-                if app.dropdown_visible {
-                    "Esc:Close  Enter:Select  Up/Down:Nav  Type to filter..."
-                } else {
-                    "Esc:Back  Tab:Next  Type/Arrows:Search..."
-                }
-            } else {
-                bindings_owned = resource_list_bindings(app);
-                &bindings_owned
+    let bindings = footer_bindings(app);
+
+    let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+
+    // Active port-forwards are listed at the right end of the bindings row
+    let forwards = port_forward_indicator(app);
+    let row = Layout::horizontal([
+        Constraint::Min(0),
+        Constraint::Length(forwards.chars().count() as u16),
+    ])
+    .split(chunks[0]);
+
+    let line = Line::from(Span::styled(
+        bindings,
+        Style::default().fg(app.theme.border_unfocused),
+    ));
+    frame.render_widget(Paragraph::new(line), row[0]);
+    if !forwards.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(forwards, Style::default().fg(Color::Green))),
+            row[1],
+        );
+    }
+
+    // Messages get their own wrapped rows below the keybindings so long
+    // ones (e.g. full API errors) stay readable on narrow terminals.
+    if let Some((severity, ref msg)) = app.status_message {
+        let paragraph = Paragraph::new(msg.as_str())
+            .style(severity_style(app, severity).add_modifier(Modifier::BOLD))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, chunks[1]);
+    }
+}
+
+fn severity_style(app: &App, severity: Severity) -> Style {
+    let color = match severity {
+        Severity::Info => app.theme.status_ok,
+        Severity::Warning => app.theme.status_pending,
+        Severity::Error => app.theme.status_error,
+    };
+    Style::default().fg(color)
+}
+
+fn port_forward_indicator(app: &App) -> String {
+    if app.port_forwards.is_empty() {
+        return String::new();
+    }
+    let forwards: Vec<String> = app
+        .port_forwards
+        .iter()
+        .map(|f| format!("{}->{}:{}", f.local_port, f.name, f.remote_port))
+        .collect();
+    format!(" Forwarding {} ", forwards.join(", "))
+}
+
+/// Maximum number of rows the message area of the footer may grow to.
+const MAX_MESSAGE_ROWS: u16 = 4;
+
+/// Height of the footer: one row for keybindings, plus enough rows to show
+/// the current message wrapped to `width`.
+pub fn footer_height(app: &App, width: u16) -> u16 {
+    match app.status_text() {
+        Some(msg) => 1 + wrapped_line_count(msg, width).min(MAX_MESSAGE_ROWS),
+        None => 1,
+    }
+}
+
+/// `text` word-wrapped to `width` columns; words longer than a row are
+/// split.
+fn wrap_words(text: &str, width: u16) -> Vec<String> {
+    let width = width.max(1) as usize;
+    let mut rows = Vec::new();
+    let mut row = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        let used = row.chars().count();
+        if used > 0 && used + 1 + word.len() <= width {
+            row.push(' ');
+            row.extend(word);
+            continue;
+        }
+        if used > 0 {
+            rows.push(std::mem::take(&mut row));
+        }
+        while word.len() > width {
+            rows.push(word.drain(..width).collect());
+        }
+        row = word.into_iter().collect();
+    }
+    if !row.is_empty() || rows.is_empty() {
+        rows.push(row);
+    }
+    rows
+}
+
+/// Number of rows `text` occupies when word-wrapped to `width` columns.
+fn wrapped_line_count(text: &str, width: u16) -> u16 {
+    let width = width.max(1) as usize;
+    let mut rows = 0usize;
+    for line in text.lines() {
+        rows += 1;
+        let mut col = 0usize;
+        for word in line.split(' ') {
+            let len = word.chars().count();
+            if col > 0 && col + 1 + len <= width {
+                col += 1 + len;
+                continue;
+            }
+            if col > 0 {
+                rows += 1;
             }
+            // Words longer than the width are broken across rows
+            let extra_rows = len.saturating_sub(1) / width;
+            rows += extra_rows;
+            col = len - extra_rows * width;
         }
-        ViewMode::Detail if app.entered_from_search => {
-            bindings_owned = search_detail_bindings(app);
-            &bindings_owned
+    }
+    rows.max(1) as u16
+}
+
+/// Names listed in a batch delete confirmation before summarising the rest.
+const MAX_CONFIRM_NAMES: usize = 8;
+
+pub fn render_confirm_dialog(frame: &mut Frame, app: &App, action: ConfirmAction) {
+    let area = frame.area();
+
+    let marked = app.marked_resources();
+    let deleting = matches!(action, ConfirmAction::Delete | ConfirmAction::ForceDelete);
+    let batch = deleting && !marked.is_empty();
+    let mut lines = if action == ConfirmAction::Quit {
+        let running = match app.in_flight.count() {
+            1 => "1 operation is".to_string(),
+            n => format!("{} operations are", n),
+        };
+        vec![
+            format!("{} still in progress, quit anyway?", running),
+            String::new(),
+            "kterm won't see how they end.".to_string(),
+            String::new(),
+        ]
+    } else if batch {
+        let mut lines = vec![
+            format!(
+                "Are you sure you want to {} {} resources?",
+                action.to_string().to_lowercase(),
+                marked.len()
+            ),
+            String::new(),
+        ];
+        lines.extend(
+            marked
+                .iter()
+                .take(MAX_CONFIRM_NAMES)
+                .map(|r| format!("  {}", confirm_target(app, r))),
+        );
+        if marked.len() > MAX_CONFIRM_NAMES {
+            lines.push(format!(
+                "  ... and {} more",
+                marked.len() - MAX_CONFIRM_NAMES
+            ));
         }
-        ViewMode::Detail => {
-            bindings_owned = detail_bindings(app);
-            &bindings_owned
+        lines.push(String::new());
+        lines
+    } else {
+        let mut lines = vec![
+            format!(
+                "Are you sure you want to {} this resource?",
+                action.to_string().to_lowercase()
+            ),
+            String::new(),
+        ];
+        if let Some(item) = app.selected_resource() {
+            lines.push(format!("  {}/{}", app.resource_type, item.name));
+            if !app.resource_type.is_cluster_scoped() {
+                lines.push(format!("  namespace: {}", item.namespace));
+            }
+            lines.push(String::new());
         }
-        ViewMode::Logs if app.entered_from_search => {
-            "Esc:Back to search  f:Follow  j/k:Scroll  g/G:Top/Bottom  o:Vim  O:Less"
+        lines
+    };
+    if action == ConfirmAction::Delete && !batch && app.resource_type.has_dependents() {
+        match app.delete_preview {
+            Some(ref preview) => {
+                lines.push("This will also remove:".to_string());
+                lines.extend(preview.iter().cloned());
+            }
+            None => lines.push("Computing affected resources...".to_string()),
         }
-        ViewMode::Logs => "Esc:Back  f:Follow  j/k:Scroll  g/G:Top/Bottom  o:Vim  O:Less",
-        ViewMode::Confirm(_) => "y:Confirm  Any other key:Cancel",
-        ViewMode::Search => "Esc:Back  Down/Up:Nav  Enter:Detail  Type to search...",
+        lines.push(String::new());
+    }
+    if action == ConfirmAction::ForceDelete {
+        lines.push("WARNING: force delete skips graceful termination.".to_string());
+        lines.push("Containers on an unreachable node may keep running.".to_string());
+        lines.push(String::new());
+    }
+    let width = centered_rect(50, 1, area).width.saturating_sub(2);
+    if action == ConfirmAction::Restart {
+        lines.extend(wrap_words(&restart_semantics(app), width));
+        lines.push(String::new());
+    }
+    let hint = match action {
+        ConfirmAction::Quit => "Press 'y' to quit, any other key to cancel.".to_string(),
+        _ => confirm_hint(app, "confirm"),
     };
+    lines.extend(wrap_words(&hint, width));
 
-    let mut spans = vec![Span::styled(
-        bindings.to_owned(),
-        Style::default().fg(Color::DarkGray),
-    )];
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = centered_rect(50, height, area);
 
-    if let Some(ref err) = app.error_message {
-        spans.push(Span::raw("  "));
-        spans.push(Span::styled(
-            err.as_str(),
-            Style::default()
-                .fg(Color::Red)
-                .add_modifier(Modifier::BOLD),
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Confirm {} ", action))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(block)
+        .style(Style::default().fg(Color::White));
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// The confirm keystroke line. Danger mode names the context and asks for
+/// `y` twice.
+fn confirm_hint(app: &App, verb: &str) -> String {
+    if !app.danger_mode() {
+        return format!("Press 'y' to {}, any other key to cancel.", verb);
+    }
+    if app.confirm_armed {
+        format!("Press 'y' again to {} in {}.", verb, app.current_context())
+    } else {
+        format!(
+            "DANGER: {} is a protected context. Press 'y' twice to {}.",
+            app.current_context(),
+            verb
+        )
+    }
+}
+
+/// What a restart of the selected resource actually does. Kubernetes has
+/// no way to restart a single container, so a pod "restart" is a delete
+/// that only comes back if a controller owns the pod.
+fn restart_semantics(app: &App) -> String {
+    if app.resource_type != ResourceType::Pods {
+        return "Rolling restart: pods are replaced a few at a time, as with \
+                `kubectl rollout restart`."
+            .to_string();
+    }
+    match app
+        .selected_resource()
+        .and_then(|item| item.spec.owner.as_ref())
+    {
+        Some((kind, name)) => format!(
+            "Kubernetes can't restart a single container, so this deletes the pod and \
+             {} {} creates a replacement. Restart the owner ('o' in the detail view) \
+             to replace all of its pods.",
+            kind, name
+        ),
+        None => "WARNING: this pod has no controller. Restarting deletes it and \
+                 nothing recreates it."
+            .to_string(),
+    }
+}
+
+/// Diff of a pending `$EDITOR` edit against the live YAML, applied on `y`.
+pub fn render_edit_diff(frame: &mut Frame, app: &mut App) {
+    let Some(ref edit) = app.pending_edit else {
+        return;
+    };
+    let area = frame.area();
+    let (added, removed) = edit.counts();
+    let whitespace = if edit.whitespace_only {
+        " [whitespace only]"
+    } else {
+        ""
+    };
+    let (verb, title) = if edit.create {
+        (
+            "create",
+            format!(" Create {}/{} (+{}) ", edit.resource_type, edit.name, added),
+        )
+    } else {
+        let title = format!(
+            " Apply edit to {}/{} (+{} -{}){} ",
+            edit.resource_type, edit.name, added, removed, whitespace
+        );
+        ("apply", title)
+    };
+    let popup_area = centered_rect(90, area.height.saturating_sub(4), area);
+    let mut lines: Vec<Line> = Vec::new();
+    if app.danger_mode() {
+        lines.push(Line::styled(
+            confirm_hint(app, verb),
+            Style::default().fg(app.theme.status_error),
         ));
     }
+    lines.extend(match edit.dry_run {
+        None => Vec::new(),
+        Some(DryRun::Running) => vec![Line::from("Dry run in progress...")],
+        Some(DryRun::Passed) => vec![Line::styled(
+            "Dry run passed: the API server accepted the change",
+            Style::default().fg(Color::Green),
+        )],
+        // The diff doesn't wrap, but the server's message should be readable
+        Some(DryRun::Failed(ref e)) => wrap_words(
+            &format!("Dry run failed: {}", e),
+            popup_area.width.saturating_sub(2),
+        )
+        .into_iter()
+        .map(|row| Line::styled(row, Style::default().fg(Color::Red)))
+        .collect(),
+    });
+    if !lines.is_empty() {
+        lines.push(Line::default());
+    }
+    lines.extend(edit.diff.iter().map(|line| {
+        let style = if line.starts_with("@@") {
+            Style::default().fg(Color::Cyan)
+        } else if line.starts_with('+') {
+            Style::default().fg(Color::Green)
+        } else if line.starts_with('-') {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default()
+        };
+        Line::styled(line.as_str(), style)
+    }));
+
+    // Keep the last screenful reachable, as the detail view does for `G`
+    let max_scroll = (lines.len() + 2).saturating_sub(popup_area.height as usize);
+    let scroll = app
+        .edit_diff_scroll
+        .min(max_scroll.min(u16::MAX as usize) as u16);
+    app.edit_diff_scroll = scroll;
+
+    let keys = if edit.create {
+        " y:Create  d:Dry run  n/Esc:Discard  j/k:Scroll "
+    } else {
+        " y:Apply  d:Dry run  n/Esc:Discard  j/k:Scroll "
+    };
+    let block = Block::default()
+        .title(title)
+        .title_bottom(Line::from(keys).right_aligned())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+    let paragraph = Paragraph::new(lines).block(block).scroll((scroll, 0));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// A marked row as listed in the batch confirmation: `namespace/name` when
+/// the list spans namespaces, so same-named rows can be told apart.
+fn confirm_target(app: &App, item: &ResourceItem) -> String {
+    if app.all_namespaces() && !app.resource_type.is_cluster_scoped() {
+        format!("{}/{}", item.namespace, item.name)
+    } else {
+        item.name.clone()
+    }
+}
+
+/// Centered overlay listing every binding, grouped by view.
+pub fn render_help_overlay(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let vertical = Layout::vertical([Constraint::Percentage(80)])
+        .flex(Flex::Center)
+        .split(area);
+    let popup_area = Layout::horizontal([Constraint::Percentage(70)])
+        .flex(Flex::Center)
+        .split(vertical[0])[0];
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (section, title) in HELP_SECTIONS {
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(title, app.theme.header_style())));
+        for binding in section_bindings(section) {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<16}", binding.key),
+                    Style::default().fg(app.theme.border_focused),
+                ),
+                Span::raw(binding.label),
+            ]));
+        }
+    }
+
+    let block = Block::default()
+        .title(" Help (Esc or ? to close) ")
+        .borders(Borders::ALL)
+        .border_style(app.theme.border(true));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.help_scroll, 0));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Centered overlay listing the messages the footer showed, newest first.
+pub fn render_message_log(frame: &mut Frame, app: &mut App) {
+    let area = frame.area();
+    let vertical = Layout::vertical([Constraint::Percentage(80)])
+        .flex(Flex::Center)
+        .split(area);
+    let popup_area = Layout::horizontal([Constraint::Percentage(80)])
+        .flex(Flex::Center)
+        .split(vertical[0])[0];
+
+    let lines: Vec<Line> = if app.message_log.is_empty() {
+        vec![Line::styled(
+            "No messages yet",
+            Style::default().fg(app.theme.border_unfocused),
+        )]
+    } else {
+        app.message_log
+            .iter()
+            .rev()
+            .map(|&(at, severity, ref msg)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>8} ago  ", format_age(Some(at))),
+                        Style::default().fg(app.theme.border_unfocused),
+                    ),
+                    Span::styled(msg.as_str(), severity_style(app, severity)),
+                ])
+            })
+            .collect()
+    };
+
+    let block = Block::default()
+        .title(format!(" Message history ({}) ", app.message_log.len()))
+        .title_bottom(Line::from(" Esc:Close  j/k:Scroll ").right_aligned())
+        .borders(Borders::ALL)
+        .border_style(app.theme.border(true));
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
 
-    let line = Line::from(spans);
-    let paragraph = Paragraph::new(line);
+    // Stop scrolling once the oldest message is on screen
+    let max_scroll = paragraph
+        .line_count(popup_area.width)
+        .saturating_sub(popup_area.height as usize);
+    let scroll = app
+        .message_log_scroll
+        .min(max_scroll.min(u16::MAX as usize) as u16);
+    app.message_log_scroll = scroll;
 
-    frame.render_widget(paragraph, area);
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(paragraph.scroll((scroll, 0)), popup_area);
 }
 
-pub fn render_confirm_dialog(frame: &mut Frame, action: ConfirmAction) {
+/// Bookmarked resources as a table, in the order they were added.
+pub fn render_bookmarks(frame: &mut Frame, app: &App) {
     let area = frame.area();
-    let popup_area = centered_rect(50, 7, area);
+    let vertical = Layout::vertical([Constraint::Percentage(60)])
+        .flex(Flex::Center)
+        .split(area);
+    let popup_area = Layout::horizontal([Constraint::Percentage(80)])
+        .flex(Flex::Center)
+        .split(vertical[0])[0];
 
+    let block = Block::default()
+        .title(format!(" Bookmarks ({}) ", app.bookmarks.len()))
+        .title_bottom(Line::from(" Enter:Go  d:Remove  Esc:Close ").right_aligned())
+        .borders(Borders::ALL)
+        .border_style(app.theme.border(true));
     frame.render_widget(Clear, popup_area);
 
-    let text = format!(
-        "Are you sure you want to {} this resource?\n\nPress 'y' to confirm, any other key to cancel.",
-        action.to_string().to_lowercase()
+    if app.bookmarks.is_empty() {
+        let hint = Paragraph::new(Line::styled(
+            "No bookmarks yet; press 'b' on a resource to add one",
+            Style::default().fg(app.theme.border_unfocused),
+        ))
+        .block(block);
+        frame.render_widget(hint, popup_area);
+        return;
+    }
+
+    let header = Row::new(
+        ["CONTEXT", "NAMESPACE", "TYPE", "NAME"]
+            .into_iter()
+            .map(|h| Cell::from(h).style(app.theme.header_style())),
     );
+    let rows = app.bookmarks.iter().map(|b| {
+        Row::new([
+            b.context.clone(),
+            b.namespace.clone(),
+            b.resource_type.to_string(),
+            b.name.clone(),
+        ])
+    });
+    let widths = [
+        Constraint::Percentage(25),
+        Constraint::Percentage(20),
+        Constraint::Percentage(20),
+        Constraint::Percentage(35),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(app.theme.highlight_style())
+        .highlight_symbol("▶ ");
+    let mut state = TableState::default().with_selected(Some(app.bookmark_selected));
+    frame.render_stateful_widget(table, popup_area, &mut state);
+}
+
+pub fn render_prompt(frame: &mut Frame, app: &App, prompt: &Prompt) {
+    let popup_area = centered_rect(50, 3, frame.area());
+
+    frame.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title(format!(" Confirm {} ", action))
+        .title(format!(" {} ", prompt.kind.title()))
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red));
+        .border_style(app.theme.border(true));
 
-    let paragraph = Paragraph::new(text)
+    let paragraph = Paragraph::new(format!("{}\u{2588}", prompt.input))
         .block(block)
         .style(Style::default().fg(Color::White));
 
     frame.render_widget(paragraph, popup_area);
 }
 
+/// Palette entries shown at once; the selection scrolls the rest into view.
+const MAX_PALETTE_ROWS: usize = 12;
+
+/// Ctrl+P overlay: the query, then the matching actions with their keys.
+pub fn render_palette(frame: &mut Frame, app: &App, palette: &Palette) {
+    let rows = palette.filtered.len().clamp(1, MAX_PALETTE_ROWS);
+    let popup_area = centered_rect(50, rows as u16 + 4, frame.area());
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Commands ")
+        .borders(Borders::ALL)
+        .border_style(app.theme.border(true));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let chunks = Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).split(inner);
+
+    let input = Paragraph::new(format!("> {}\u{2588}", palette.query)).block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(app.theme.border(false)),
+    );
+    frame.render_widget(input, chunks[0]);
+
+    if palette.filtered.is_empty() {
+        frame.render_widget(Paragraph::new("No matching commands"), chunks[1]);
+        return;
+    }
+    let key_width = chunks[1].width.saturating_sub(2) as usize;
+    let skip = (palette.selected + 1).saturating_sub(MAX_PALETTE_ROWS);
+    let lines: Vec<Line> = palette
+        .filtered
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(MAX_PALETTE_ROWS)
+        .map(|(row, &i)| {
+            let entry = &PALETTE_ENTRIES[i];
+            let selected = row == palette.selected;
+            let mut line = fuzzy_highlight(entry.label, &palette.query, app.theme.match_style());
+            let key = entry.command.to_string();
+            let pad = key_width.saturating_sub(entry.label.chars().count() + key.chars().count());
+            line.spans
+                .insert(0, Span::raw(if selected { "\u{25b6} " } else { "  " }));
+            line.spans.push(Span::raw(" ".repeat(pad)));
+            line.spans
+                .push(Span::styled(key, Style::default().fg(Color::DarkGray)));
+            if selected {
+                line = line.style(app.theme.highlight_style());
+            }
+            line
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), chunks[1]);
+}
+
 fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
     let vertical = Layout::vertical([Constraint::Length(height)])
         .flex(Flex::Center)
@@ -1,62 +1,343 @@
-use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use std::borrow::Cow;
+
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::config::LogAnsi;
+use crate::types::{since_label, tail_label};
+use crate::ui::{ansi, pane_border};
+
+pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.areas.content = area;
+    if !app.container_choices.is_empty() {
+        render_container_picker(frame, app, area);
+        return;
+    }
 
-pub fn render(frame: &mut Frame, app: &App, area: Rect) {
+    let container = app
+        .log_container
+        .as_ref()
+        .map(|c| format!(" [{}]", c))
+        .unwrap_or_default();
     let follow_indicator = if app.log_follow { " [FOLLOW] " } else { "" };
+    let search = if app.log_search_active {
+        format!(" [/{}\u{2588}]", app.log_search)
+    } else if !app.log_search.is_empty() {
+        let position = if app.log_matches.is_empty() {
+            0
+        } else {
+            app.log_match_idx + 1
+        };
+        format!(
+            " [/{} {}/{}]",
+            app.log_search,
+            position,
+            app.log_matches.len()
+        )
+    } else {
+        String::new()
+    };
+    let visible = app.visible_log_lines();
+    let flags = match (app.grep_active(), app.log_ignore_case) {
+        (true, true) => " [GREP -i]",
+        (true, false) => " [GREP]",
+        (false, true) => " [-i]",
+        (false, false) => "",
+    };
+    let count = if app.grep_active() {
+        format!("{}/{} lines", visible.len(), app.log_lines.len())
+    } else {
+        format!("{} lines", app.log_lines.len())
+    };
+    let previous = if app.log_previous { " [previous]" } else { "" };
+    let timestamps = if app.log_timestamps {
+        " [timestamps]"
+    } else {
+        ""
+    };
+    let tail = format!(" [tail {}]", tail_label(app.log_tail));
+    let since = app
+        .log_since
+        .map(|s| format!(" [since {}]", since_label(Some(s))))
+        .unwrap_or_default();
+    let pods = match (&app.log_selector, app.log_aggregate) {
+        (Some(selector), _) => format!(" [pods {}]", selector),
+        (None, true) => " [all pods]".to_string(),
+        (None, false) => String::new(),
+    };
+    let wrap = if app.log_wrap { "" } else { " [nowrap]" };
     let title = format!(
-        " Logs{} ({} lines) ",
+        " Logs{}{}{}{}{}{}{}{}{}{} ({}) ",
+        pods,
+        container,
+        previous,
+        timestamps,
+        tail,
+        since,
+        wrap,
+        search,
+        flags,
         follow_indicator,
-        app.log_lines.len()
+        count
     );
 
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(pane_border(app, false));
 
     if app.log_lines.is_empty() {
-        let text = if app.loading {
-            "Waiting for logs..."
-        } else {
-            "No log output"
+        let text = match app.loading_indicator() {
+            Some(indicator) => format!("Waiting for logs... {}", indicator),
+            None => "No log output".to_string(),
         };
         let paragraph = Paragraph::new(text).block(block);
         frame.render_widget(paragraph, area);
         return;
     }
 
-    let lines: Vec<Line> = app
-        .log_lines
+    let current_match = app.current_log_match();
+    let lines: Vec<Line> = visible
         .iter()
-        .map(|line| {
-            let style = if line.contains("ERROR") || line.contains("error") {
-                Style::default().fg(Color::Red)
-            } else if line.contains("WARN") || line.contains("warn") {
-                Style::default().fg(Color::Yellow)
+        .map(|&i| {
+            let line = &app.log_lines[i];
+            let render_ansi = app.log_ansi == LogAnsi::Render;
+            let text = if render_ansi {
+                ansi::strip(line)
+            } else {
+                Cow::Borrowed(line.as_str())
+            };
+            let style = log_level_style(&text);
+            if app.log_search.is_empty() {
+                let (prefix, rest) = match split_pod_prefix(line) {
+                    Some((prefix, rest)) if app.log_aggregate => (Some(prefix), rest),
+                    _ => (None, line.as_str()),
+                };
+                let mut spans: Vec<Span> = prefix
+                    .map(|p| Span::styled(p, pod_prefix_style(p)))
+                    .into_iter()
+                    .collect();
+                if render_ansi {
+                    spans.extend(ansi::spans(rest, style));
+                } else {
+                    spans.push(Span::styled(rest, style));
+                }
+                return Line::from(spans);
+            }
+            // Matches are highlighted in the plain text, without the app's colors
+            let highlight = if current_match == Some(i) {
+                Style::default().fg(Color::Black).bg(Color::LightRed)
             } else {
-                Style::default()
+                Style::default().fg(Color::Black).bg(Color::Yellow)
             };
-            Line::from(Span::styled(line.as_str(), style))
+            let (pattern, ignore_case) = (&app.log_search, app.log_ignore_case);
+            match text {
+                Cow::Borrowed(text) => {
+                    highlight_matches(text, pattern, ignore_case, style, highlight)
+                }
+                Cow::Owned(text) => {
+                    let line = highlight_matches(&text, pattern, ignore_case, style, highlight);
+                    let spans: Vec<Span> = line
+                        .spans
+                        .into_iter()
+                        .map(|span| Span::styled(span.content.into_owned(), span.style))
+                        .collect();
+                    Line::from(spans)
+                }
+            }
         })
         .collect();
 
+    let mut paragraph = Paragraph::new(lines).block(block.clone());
+    if app.log_wrap {
+        paragraph = paragraph.wrap(Wrap { trim: false });
+    } else {
+        let max_hscroll = paragraph.line_width().saturating_sub(area.width as usize);
+        app.log_hscroll = app
+            .log_hscroll
+            .min(max_hscroll.min(u16::MAX as usize) as u16);
+    }
+    let rows = paragraph.line_count(area.width.saturating_sub(2));
+    let max_scroll = rows
+        .saturating_sub(area.height as usize)
+        .min(u16::MAX as usize) as u16;
     let scroll = if app.log_follow {
-        let total = lines.len() as u16;
-        let visible = area.height.saturating_sub(2); // account for border
-        total.saturating_sub(visible)
+        // Record where following left the view, so turning follow off or
+        // scrolling up continues from what is on screen
+        max_scroll
     } else {
-        app.log_scroll
+        app.log_scroll.min(max_scroll)
     };
-
-    let paragraph = Paragraph::new(lines)
-        .block(block)
-        .wrap(Wrap { trim: false })
-        .scroll((scroll, 0));
+    app.log_scroll = scroll;
+    // Last row on screen out of all (wrapped) rows, borders excluded
+    let content_rows = rows.saturating_sub(2);
+    let bottom = (scroll as usize + area.height.saturating_sub(2) as usize).min(content_rows);
+    let position = Line::from(format!(" {}/{} ", bottom, content_rows));
+    let paragraph = paragraph
+        .block(block.title_bottom(position))
+        .scroll((scroll, app.log_hscroll));
 
     frame.render_widget(paragraph, area);
 }
+
+fn render_container_picker(frame: &mut Frame, app: &App, area: Rect) {
+    let outer = Block::default()
+        .title(" Logs ")
+        .borders(Borders::ALL)
+        .border_style(app.theme.border(false));
+    frame.render_widget(outer, area);
+
+    let height = (app.container_choices.len() as u16 + 2).min(area.height);
+    let width = app
+        .container_choices
+        .iter()
+        .map(|c| c.chars().count() as u16 + 6)
+        .max()
+        .unwrap_or(0)
+        .max(24)
+        .min(area.width);
+    let vertical = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .split(area);
+    let popup = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .split(vertical[0])[0];
+
+    let items: Vec<ListItem> = app
+        .container_choices
+        .iter()
+        .map(|c| ListItem::new(c.as_str()))
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Container ")
+                .borders(Borders::ALL)
+                .border_style(app.theme.border(true)),
+        )
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default();
+    state.select(Some(app.container_selected));
+
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut state);
+}
+
+/// Keys structured loggers put the level under.
+const LEVEL_KEYS: [&str; 3] = ["level", "lvl", "severity"];
+
+/// Red for errors, yellow for warnings. A structured level field (logfmt
+/// `level=error`, JSON `"level":"error"`) decides when the line has one,
+/// so an info line that mentions an error stays plain; otherwise any
+/// ERROR/WARN in the line does.
+fn log_level_style(line: &str) -> Style {
+    let (error, warn) = match structured_level(line) {
+        Some(level) => (
+            matches!(
+                level.as_str(),
+                "error" | "err" | "fatal" | "panic" | "critical" | "crit" | "alert" | "emerg"
+            ),
+            matches!(level.as_str(), "warn" | "warning"),
+        ),
+        None => (
+            line.contains("ERROR") || line.contains("error"),
+            line.contains("WARN") || line.contains("warn"),
+        ),
+    };
+    if error {
+        Style::default().fg(Color::Red)
+    } else if warn {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default()
+    }
+}
+
+/// The lowercased value of the first logfmt or JSON level field in `line`.
+fn structured_level(line: &str) -> Option<String> {
+    for key in LEVEL_KEYS {
+        let json = format!("\"{}\":", key);
+        if let Some(at) = line.find(&json) {
+            let value = line[at + json.len()..].trim_start();
+            if let Some(value) = value.strip_prefix('"') {
+                let end = value.find('"').unwrap_or(value.len());
+                return Some(value[..end].to_ascii_lowercase());
+            }
+        }
+        let logfmt = format!("{}=", key);
+        for (at, _) in line.match_indices(&logfmt) {
+            // `key=` must start a field, not end one like `loglevel=`
+            if at > 0 && !line[..at].ends_with(' ') {
+                continue;
+            }
+            let value = &line[at + logfmt.len()..];
+            let value = value.strip_prefix('"').unwrap_or(value);
+            let end = value.find([' ', '"']).unwrap_or(value.len());
+            return Some(value[..end].to_ascii_lowercase());
+        }
+    }
+    None
+}
+
+/// Splits an aggregated log line into its `[pod] ` prefix and the rest.
+fn split_pod_prefix(line: &str) -> Option<(&str, &str)> {
+    if !line.starts_with('[') {
+        return None;
+    }
+    let end = line.find("] ")? + 2;
+    Some(line.split_at(end))
+}
+
+/// Stable per-pod color so lines from the same pod are easy to follow.
+fn pod_prefix_style(prefix: &str) -> Style {
+    const COLORS: [Color; 5] = [
+        Color::Cyan,
+        Color::Green,
+        Color::Magenta,
+        Color::Blue,
+        Color::LightYellow,
+    ];
+    let hash = prefix
+        .bytes()
+        .fold(0usize, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+    Style::default()
+        .fg(COLORS[hash % COLORS.len()])
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Splits `line` into spans with every occurrence of `pattern` highlighted.
+/// Ignore-case matching uses ASCII folding so byte offsets stay valid.
+fn highlight_matches<'a>(
+    line: &'a str,
+    pattern: &str,
+    ignore_case: bool,
+    base: Style,
+    highlight: Style,
+) -> Line<'a> {
+    let (haystack, needle) = if ignore_case {
+        (line.to_ascii_lowercase(), pattern.to_ascii_lowercase())
+    } else {
+        (line.to_string(), pattern.to_string())
+    };
+    let mut spans = Vec::new();
+    let mut start = 0;
+    while let Some(pos) = haystack[start..].find(&needle) {
+        let pos = start + pos;
+        if pos > start {
+            spans.push(Span::styled(&line[start..pos], base));
+        }
+        let end = pos + needle.len();
+        spans.push(Span::styled(&line[pos..end], highlight));
+        start = end;
+    }
+    if start < line.len() {
+        spans.push(Span::styled(&line[start..], base));
+    }
+    Line::from(spans)
+}
@@ -1,24 +1,113 @@
+pub mod ansi;
 pub mod detail;
 pub mod header;
 pub mod help;
 pub mod logs;
 pub mod resource_list;
 pub mod search;
+pub mod theme;
 
 use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::Frame;
 
 use crate::app::App;
-use crate::types::ViewMode;
+use crate::types::{fuzzy_match_positions, ConfirmAction, ViewMode};
+
+/// Border of the list, detail and logs panes: red throughout in danger
+/// mode, bold where focused, the theme's colors otherwise.
+pub fn pane_border(app: &App, focused: bool) -> Style {
+    if app.danger_mode() {
+        let style = Style::default().fg(app.theme.status_error);
+        if focused {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
+        }
+    } else {
+        app.theme.border(focused)
+    }
+}
+
+/// " 3/57 " for the selected row of a table, if one is selected.
+pub fn selection_position(selected: Option<usize>, len: usize) -> Option<String> {
+    let selected = selected.filter(|&i| i < len)?;
+    Some(format!(" {}/{} ", selected + 1, len))
+}
+
+/// `text` with the characters `query` fuzzy-matches drawn in `matched`.
+pub fn fuzzy_highlight(text: &str, query: &str, matched: Style) -> Line<'static> {
+    let positions = match fuzzy_match_positions(query, text) {
+        Some((_, positions)) if !positions.is_empty() => positions,
+        _ => return Line::from(text.to_string()),
+    };
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in text.chars().enumerate() {
+        let is_match = positions.binary_search(&i).is_ok();
+        if is_match != run_matched && !run.is_empty() {
+            let style = if run_matched {
+                matched
+            } else {
+                Style::default()
+            };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = is_match;
+        run.push(c);
+    }
+    let style = if run_matched {
+        matched
+    } else {
+        Style::default()
+    };
+    spans.push(Span::styled(run, style));
+    Line::from(spans)
+}
+
+/// Rows the main content keeps however short the terminal: its borders,
+/// the column headers and a few rows.
+const MIN_CONTENT_HEIGHT: u16 = 6;
+
+/// Height of the header selectors.
+const HEADER_HEIGHT: u16 = 3;
+
+/// Heights of the header, dropdown and footer in a terminal `height` rows
+/// tall, given the ones they ask for. Before the main content gets fewer
+/// than `MIN_CONTENT_HEIGHT` rows the dropdown shrinks and then is left
+/// out, then the footer shrinks, then the header is left out.
+fn chrome_heights(height: u16, dropdown: u16, footer: u16) -> (u16, u16, u16) {
+    let mut spare = height.saturating_sub(MIN_CONTENT_HEIGHT);
+    let header = if spare >= HEADER_HEIGHT {
+        HEADER_HEIGHT
+    } else {
+        0
+    };
+    spare -= header;
+    let footer = footer.min(spare);
+    spare -= footer;
+    // Fewer rows than its borders and one item would show nothing
+    let dropdown = match dropdown.min(spare) {
+        rows if rows < 3 => 0,
+        rows => rows,
+    };
+    (header, dropdown, footer)
+}
 
 pub fn render(frame: &mut Frame, app: &mut App) {
+    // Re-recorded by whichever panes this frame draws
+    app.areas = Default::default();
+    let footer_height = help::footer_height(app, frame.area().width);
+
     // Search mode takes over the full screen (no header selectors)
     if app.view_mode == ViewMode::Search {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Min(10),  // search content
-                Constraint::Length(1), // footer
+                Constraint::Length(footer_height), // footer
             ])
             .split(frame.area());
         search::render(frame, app, chunks[0]);
@@ -32,7 +121,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Min(10),  // content
-                Constraint::Length(1), // footer
+                Constraint::Length(footer_height), // footer
             ])
             .split(frame.area());
 
@@ -42,6 +131,9 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             _ => unreachable!(),
         }
         help::render_footer(frame, app, chunks[1]);
+        if let Some(ref prompt) = app.prompt {
+            help::render_prompt(frame, app, prompt);
+        }
         return;
     }
 
@@ -53,19 +145,24 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         0
     };
 
+    let (header_height, dropdown_height, footer_height) =
+        chrome_heights(frame.area().height, dropdown_height, footer_height);
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),               // header selectors
-            Constraint::Length(dropdown_height),  // dropdown (0 when hidden)
-            Constraint::Min(10),                 // main content
-            Constraint::Length(1),               // footer keybindings
+            Constraint::Length(header_height),   // header selectors
+            Constraint::Length(dropdown_height), // dropdown (0 when hidden or no room)
+            Constraint::Min(MIN_CONTENT_HEIGHT), // main content
+            Constraint::Length(footer_height),   // footer keybindings + errors
         ])
         .split(frame.area());
 
-    header::render(frame, app, chunks[0]);
+    if header_height > 0 {
+        header::render(frame, app, chunks[0]);
+    }
 
-    if app.dropdown_visible {
+    if dropdown_height > 0 {
         header::render_dropdown(frame, app, chunks[1]);
     }
 
@@ -81,8 +178,10 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             resource_list::render(frame, app, split[0]);
             detail::render(frame, app, split[1]);
 
-            if let ViewMode::Confirm(action) = app.view_mode {
-                help::render_confirm_dialog(frame, action);
+            match app.view_mode {
+                ViewMode::Confirm(ConfirmAction::ApplyEdit) => help::render_edit_diff(frame, app),
+                ViewMode::Confirm(action) => help::render_confirm_dialog(frame, app, action),
+                _ => {}
             }
         }
         ViewMode::Logs => {
@@ -97,4 +196,21 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     }
 
     help::render_footer(frame, app, chunks[3]);
+
+    if let Some(ref prompt) = app.prompt {
+        help::render_prompt(frame, app, prompt);
+    }
+    if let Some(ref palette) = app.palette {
+        help::render_palette(frame, app, palette);
+    }
+
+    if app.show_bookmarks {
+        help::render_bookmarks(frame, app);
+    }
+    if app.show_message_log {
+        help::render_message_log(frame, app);
+    }
+    if app.show_help {
+        help::render_help_overlay(frame, app);
+    }
 }
@@ -1,17 +1,36 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Cell, Row, Table};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::types::ColumnLayout;
+use crate::ui::{pane_border, selection_position};
 
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.areas.list = area;
     let resource_type = app.resource_type;
-    let headers = resource_type.column_headers();
+    // With "<all>" selected, namespaced types get a leading NAMESPACE column
+    let show_namespace = app.all_namespaces() && !resource_type.is_cluster_scoped();
+    let mut headers = app.visible_headers(area.width);
+    let mut widths = if app.show_images && resource_type.supports_image_column() {
+        image_column_widths()
+    } else {
+        column_widths(resource_type, app.column_layout)
+    };
+    drop_narrow_columns(&app.column_headers(), &headers, &mut widths);
+    if show_namespace {
+        headers.insert(0, "NAMESPACE");
+        widths.insert(0, ratatui::layout::Constraint::Percentage(15));
+    }
+    let status_col = headers.iter().position(|h| *h == "STATUS");
+    // Events have no STATUS column; a warning colors its whole row instead
+    let events = resource_type == crate::types::ResourceType::Events;
 
     let header_cells: Vec<Cell> = headers
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+        .map(|h| Cell::from(*h).style(app.theme.header_style()))
         .collect();
     let header_row = Row::new(header_cells).height(1);
 
@@ -19,67 +38,193 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     let rows: Vec<Row> = filtered
         .iter()
         .map(|item| {
-            let cols = item.columns(resource_type);
+            let mut cols = item.columns(&headers[usize::from(show_namespace)..]);
+            if show_namespace {
+                cols.insert(0, item.namespace.clone());
+            }
+            let marked = app
+                .marked
+                .contains(&format!("{}/{}", item.namespace, item.name));
+            if marked {
+                cols[0] = format!("* {}", cols[0]);
+            }
             let cells: Vec<Cell> = cols
                 .into_iter()
                 .enumerate()
                 .map(|(i, val)| {
-                    let style = if i == 1 {
-                        status_style(&val)
+                    // Styled by the bare status, which may have its last exit appended
+                    let style = if Some(i) == status_col {
+                        app.theme.status_style(&item.status)
+                    } else if marked {
+                        Style::default().fg(Color::Magenta)
                     } else {
                         Style::default()
                     };
                     Cell::from(val).style(style)
                 })
                 .collect();
-            Row::new(cells).height(1)
+            let row = Row::new(cells).height(1);
+            if events {
+                row.style(app.theme.status_style(&item.status))
+            } else {
+                row
+            }
         })
         .collect();
 
-    let widths = column_widths(resource_type);
-
+    let sort = app
+        .sort_header()
+        .map(|(_, header)| {
+            let arrow = if app.sort_ascending { "↑" } else { "↓" };
+            format!(" [sort: {} {}]", header, arrow)
+        })
+        .unwrap_or_default();
+    let marked = if app.marked.is_empty() {
+        String::new()
+    } else {
+        format!(" [{} marked]", app.marked.len())
+    };
+    let problems = if app.problems_only {
+        " [problems only]"
+    } else {
+        ""
+    };
+    let node = match &app.pod_node {
+        Some(node) => format!(" [node: {}]", node),
+        None => String::new(),
+    };
+    let layout = if app.show_images
+        || !resource_type.supports_column_layouts()
+        || app.column_layout == ColumnLayout::Regular
+    {
+        String::new()
+    } else {
+        format!(" [{}]", app.column_layout)
+    };
+    let count = match app.list_summary() {
+        (total, Some(not_ready)) if not_ready > 0 => {
+            format!(" ({}, {} not ready)", total, not_ready)
+        }
+        (total, _) => format!(" ({})", total),
+    };
     let title = if app.filter.is_empty() {
-        format!(" {} ", resource_type)
+        format!(
+            " {}{}{}{}{}{}{} ",
+            resource_type, node, count, sort, marked, problems, layout
+        )
     } else {
-        format!(" {} [filter: {}] ", resource_type, app.filter)
+        format!(
+            " {}{}{}{}{}{}{} [filter: {}] ",
+            resource_type, node, count, sort, marked, problems, layout, app.filter
+        )
     };
 
-    let highlight_style = Style::default()
-        .bg(Color::DarkGray)
-        .add_modifier(Modifier::BOLD);
+    let highlight_style = app.theme.highlight_style();
+    let border_style = pane_border(app, app.focus == crate::types::Focus::ResourceList);
 
-    let border_style = if app.focus == crate::types::Focus::ResourceList {
-        Style::default().fg(Color::Cyan)
-    } else {
-        Style::default().fg(Color::DarkGray)
-    };
+    let mut block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    // Full name of the selected row, which the NAME column may truncate
+    if let Some(item) = app.selected_resource() {
+        let name = if show_namespace {
+            format!(" {}/{} ", item.namespace, item.name)
+        } else {
+            format!(" {} ", item.name)
+        };
+        block = block.title_bottom(Line::from(name).right_aligned());
+    }
+    if let Some(position) = selection_position(app.table_state.selected(), filtered.len()) {
+        block = block.title_bottom(Line::from(position));
+    }
 
     let table = Table::new(rows, &widths)
         .header(header_row)
-        .block(
-            Block::default()
-                .title(title)
-                .borders(Borders::ALL)
-                .border_style(border_style),
-        )
+        .block(block)
         .row_highlight_style(highlight_style)
         .highlight_symbol("▶ ");
 
     frame.render_stateful_widget(table, area, &mut app.table_state);
 }
 
-fn column_widths(resource_type: crate::types::ResourceType) -> Vec<ratatui::layout::Constraint> {
+/// Drop the widths of the columns in `all` that aren't `visible`, giving
+/// their share to the remaining ones. Sorting still uses the full layout's
+/// indices.
+fn drop_narrow_columns(
+    all: &[&str],
+    visible: &[&str],
+    widths: &mut Vec<ratatui::layout::Constraint>,
+) {
+    use ratatui::layout::Constraint;
+
+    if all.len() == visible.len() {
+        return;
+    }
+    let mut headers = all.iter();
+    widths.retain(|_| headers.next().is_some_and(|h| visible.contains(h)));
+    // Fill splits the whole width by the same ratios, so the freed space
+    // isn't lost to percentages that no longer add up to 100
+    for c in widths.iter_mut() {
+        if let Constraint::Percentage(p) = *c {
+            *c = Constraint::Fill(p);
+        }
+    }
+}
+
+/// Widths for the image layout, which gives IMAGE the space of the
+/// RESTARTS and NODE columns it replaces.
+fn image_column_widths() -> Vec<ratatui::layout::Constraint> {
+    use ratatui::layout::Constraint;
+
+    // 7 columns: NAME, READY, STATUS, AGE, IMAGE, CPU, MEM
+    vec![
+        Constraint::Percentage(24),
+        Constraint::Percentage(6),
+        Constraint::Percentage(18),
+        Constraint::Percentage(8),
+        Constraint::Percentage(24),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+    ]
+}
+
+fn column_widths(
+    resource_type: crate::types::ResourceType,
+    layout: ColumnLayout,
+) -> Vec<ratatui::layout::Constraint> {
     use crate::types::ResourceType;
     use ratatui::layout::Constraint;
 
     match resource_type {
-        // 5 columns: NAME, STATUS, AGE, RESTARTS, NODE
-        ResourceType::Pods => vec![
-            Constraint::Percentage(30),
+        // 3 columns: NAME, STATUS, AGE
+        ResourceType::Pods if layout == ColumnLayout::Compact => vec![
+            Constraint::Percentage(60),
+            Constraint::Percentage(25),
             Constraint::Percentage(15),
+        ],
+        // 9 columns: NAME, READY, STATUS, AGE, RESTARTS, IP, NODE, CPU, MEM
+        ResourceType::Pods if layout == ColumnLayout::Wide => vec![
+            Constraint::Percentage(22),
+            Constraint::Percentage(6),
+            Constraint::Percentage(14),
+            Constraint::Percentage(7),
+            Constraint::Percentage(8),
+            Constraint::Percentage(12),
             Constraint::Percentage(15),
+            Constraint::Percentage(8),
+            Constraint::Percentage(8),
+        ],
+        // 8 columns: NAME, READY, STATUS, AGE, RESTARTS, NODE, CPU, MEM
+        ResourceType::Pods => vec![
+            Constraint::Percentage(20),
+            Constraint::Percentage(6),
+            Constraint::Percentage(26),
+            Constraint::Percentage(8),
+            Constraint::Percentage(9),
             Constraint::Percentage(15),
-            Constraint::Percentage(25),
+            Constraint::Percentage(8),
+            Constraint::Percentage(8),
         ],
         // 5 columns: NAME, READY, UP-TO-DATE, AVAILABLE, AGE
         ResourceType::Deployments => vec![
@@ -95,8 +240,17 @@ fn column_widths(resource_type: crate::types::ResourceType) -> Vec<ratatui::layo
             Constraint::Percentage(30),
             Constraint::Percentage(30),
         ],
+        // 6 columns: NAME, DESIRED, CURRENT, READY, UP-TO-DATE, AGE
+        ResourceType::DaemonSets => vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(14),
+            Constraint::Percentage(20),
+        ],
         // 5 columns: NAME, DESIRED, CURRENT, READY, AGE
-        ResourceType::DaemonSets | ResourceType::ReplicaSets | ResourceType::ReplicationControllers => vec![
+        ResourceType::ReplicaSets | ResourceType::ReplicationControllers => vec![
             Constraint::Percentage(30),
             Constraint::Percentage(15),
             Constraint::Percentage(15),
@@ -139,12 +293,13 @@ fn column_widths(resource_type: crate::types::ResourceType) -> Vec<ratatui::layo
             Constraint::Percentage(50),
             Constraint::Percentage(20),
         ],
-        // 4 columns: NAME, CLASS, HOSTS, AGE
+        // 5 columns: NAME, CLASS, HOSTS, ADDRESS, AGE
         ResourceType::Ingresses => vec![
             Constraint::Percentage(25),
+            Constraint::Percentage(15),
+            Constraint::Percentage(30),
             Constraint::Percentage(20),
-            Constraint::Percentage(35),
-            Constraint::Percentage(20),
+            Constraint::Percentage(10),
         ],
         // 3 columns: NAME, POD-SELECTOR, AGE
         ResourceType::NetworkPolicies => vec![
@@ -198,20 +353,21 @@ fn column_widths(resource_type: crate::types::ResourceType) -> Vec<ratatui::layo
             Constraint::Percentage(30),
             Constraint::Percentage(30),
         ],
-        // 4 columns: NAME, STATUS, ROLES, AGE
+        // 5 columns: NAME, STATUS, ROLES, VERSION, AGE
         ResourceType::Nodes => vec![
             Constraint::Percentage(30),
+            Constraint::Percentage(15),
             Constraint::Percentage(20),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-        ],
-        // 5 columns: NAME, TYPE, REASON, MESSAGE, AGE
-        ResourceType::Events => vec![
             Constraint::Percentage(20),
-            Constraint::Percentage(10),
             Constraint::Percentage(15),
-            Constraint::Percentage(40),
+        ],
+        // 5 columns: TYPE, REASON, OBJECT, MESSAGE, AGE
+        ResourceType::Events => vec![
+            Constraint::Percentage(8),
             Constraint::Percentage(15),
+            Constraint::Percentage(22),
+            Constraint::Percentage(45),
+            Constraint::Percentage(10),
         ],
         // 4 columns: NAME, MIN-AVAILABLE, MAX-UNAVAILABLE, AGE
         ResourceType::PodDisruptionBudgets => vec![
@@ -222,16 +378,3 @@ fn column_widths(resource_type: crate::types::ResourceType) -> Vec<ratatui::layo
         ],
     }
 }
-
-fn status_style(status: &str) -> Style {
-    match status {
-        "Running" | "Bound" | "Active" | "Ready" | "Available" => Style::default().fg(Color::Green),
-        "Pending" | "ContainerCreating" | "Updating" => Style::default().fg(Color::Yellow),
-        "Failed" | "Error" | "CrashLoopBackOff" | "Lost" | "NotReady" => {
-            Style::default().fg(Color::Red)
-        }
-        "Terminating" => Style::default().fg(Color::Magenta),
-        "Succeeded" | "Completed" | "Released" => Style::default().fg(Color::Blue),
-        _ => Style::default(),
-    }
-}
@@ -1,30 +1,65 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use ratatui::Frame;
 
 use crate::app::App;
+use crate::types::SearchQuery;
+use crate::ui::{fuzzy_highlight, selection_position};
 
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
+    let failures_height = if app.search_failures_expanded {
+        // Up to 6 failures + 2 for border
+        (app.search_failures.len() as u16 + 2).min(8)
+    } else {
+        0
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // search input
-            Constraint::Min(5),   // results table
+            Constraint::Length(3),               // search input
+            Constraint::Length(failures_height), // failed clusters (0 when collapsed)
+            Constraint::Min(5),                  // results table
         ])
         .split(area);
 
     render_search_input(frame, app, chunks[0]);
-    render_search_results(frame, app, chunks[1]);
+    if app.search_failures_expanded {
+        render_search_failures(frame, app, chunks[1]);
+    }
+    render_search_results(frame, app, chunks[2]);
+}
+
+fn render_search_failures(frame: &mut Frame, app: &App, area: Rect) {
+    let lines: Vec<Line> = app
+        .search_failures
+        .iter()
+        .map(|(context, error)| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", context), app.theme.status_style("Failed")),
+                Span::raw(error.as_str()),
+            ])
+        })
+        .collect();
+    let block = Block::default()
+        .title(" Failed clusters (Ctrl+E to hide) ")
+        .borders(Borders::ALL)
+        .border_style(app.theme.border(false));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
 }
 
 fn render_search_input(frame: &mut Frame, app: &App, area: Rect) {
     let display_text = format!("{}\u{2588}", app.search_query); // block cursor
 
+    let title = match &app.search_scope {
+        Some(scope) => format!(" Search {} (Ctrl+G) ", scope),
+        None => " Search (Ctrl+F) ".to_string(),
+    };
     let block = Block::default()
-        .title(" Search (Ctrl+F) ")
+        .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(app.theme.border(true));
 
     let paragraph = Paragraph::new(display_text)
         .block(block)
@@ -34,48 +69,67 @@ fn render_search_input(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_search_results(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.areas.search_results = area;
     let header_cells = ["NAME", "TYPE", "NAMESPACE", "CLUSTER"]
         .iter()
-        .map(|h| {
-            Cell::from(*h).style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )
-        });
+        .map(|h| Cell::from(*h).style(app.theme.header_style()));
     let header_row = Row::new(header_cells).height(1);
 
+    let query = SearchQuery::parse(&app.search_query);
+    let matched = app.theme.match_style();
     let rows: Vec<Row> = app
         .search_filtered
         .iter()
         .filter_map(|&idx| app.search_results.get(idx))
         .map(|result| {
             Row::new(vec![
-                Cell::from(result.resource.name.clone()),
+                Cell::from(fuzzy_highlight(&result.resource.name, &query.name, matched)),
                 Cell::from(result.resource_type.to_string()),
-                Cell::from(result.resource.namespace.clone()),
-                Cell::from(result.context.clone()),
+                Cell::from(fuzzy_highlight(
+                    &result.resource.namespace,
+                    &query.namespace,
+                    matched,
+                )),
+                Cell::from(fuzzy_highlight(&result.context, &query.context, matched)),
             ])
             .height(1)
         })
         .collect();
 
+    let found = match (&app.search_scope, app.search_type_filter) {
+        (Some(scope), Some(rt)) => {
+            format!("{} {} found in {}", app.search_filtered.len(), rt, scope)
+        }
+        (Some(scope), None) => format!("{} found in {}", app.search_filtered.len(), scope),
+        (None, Some(rt)) => format!("{} {} found", app.search_filtered.len(), rt),
+        (None, None) => format!("{} found", app.search_filtered.len()),
+    };
+    let failed = match app.search_failures.len() {
+        0 => String::new(),
+        1 => ", failed: 1 cluster".to_string(),
+        n => format!(", failed: {} clusters", n),
+    };
     let title = if app.search_loading {
         let done = app.search_contexts_done;
         let total = app.search_contexts_total;
         format!(
-            " Results ({} found, scanning {}/{} clusters...) ",
-            app.search_filtered.len(),
-            done,
-            total
+            " Results ({}, scanning {}/{} clusters...{}) ",
+            found, done, total, failed
         )
     } else {
-        format!(" Results ({} found) ", app.search_filtered.len())
+        format!(" Results ({}{}) ", found, failed)
     };
 
-    let highlight_style = Style::default()
-        .bg(Color::DarkGray)
-        .add_modifier(Modifier::BOLD);
+    let highlight_style = app.theme.highlight_style();
+    let mut block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(app.theme.border(false));
+    if let Some(position) =
+        selection_position(app.search_table_state.selected(), app.search_filtered.len())
+    {
+        block = block.title_bottom(Line::from(position));
+    }
 
     let table = Table::new(
         rows,
@@ -87,12 +141,7 @@ fn render_search_results(frame: &mut Frame, app: &mut App, area: Rect) {
         ],
     )
     .header(header_row)
-    .block(
-        Block::default()
-            .title(title)
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::DarkGray)),
-    )
+    .block(block)
     .row_highlight_style(highlight_style)
     .highlight_symbol("▶ ");
 
@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::config::ThemeConfig;
+
+/// Palette used by the render functions. The default matches kterm's
+/// built-in colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub border_focused: Color,
+    pub border_unfocused: Color,
+    pub header: Color,
+    pub highlight: Color,
+    pub status_ok: Color,
+    pub status_pending: Color,
+    pub status_error: Color,
+    pub status_terminating: Color,
+    pub status_done: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            border_focused: Color::Cyan,
+            border_unfocused: Color::DarkGray,
+            header: Color::Yellow,
+            highlight: Color::DarkGray,
+            status_ok: Color::Green,
+            status_pending: Color::Yellow,
+            status_error: Color::Red,
+            status_terminating: Color::Magenta,
+            status_done: Color::Blue,
+        }
+    }
+}
+
+impl Theme {
+    /// Apply the config's overrides to the default palette. Unknown color
+    /// names keep the default and are reported as warnings.
+    pub fn from_config(config: &ThemeConfig) -> (Self, Vec<String>) {
+        let mut theme = Self::default();
+        let mut warnings = Vec::new();
+        let overrides = [
+            (
+                "border_focused",
+                &config.border_focused,
+                &mut theme.border_focused,
+            ),
+            (
+                "border_unfocused",
+                &config.border_unfocused,
+                &mut theme.border_unfocused,
+            ),
+            ("header", &config.header, &mut theme.header),
+            ("highlight", &config.highlight, &mut theme.highlight),
+            ("status.ok", &config.status.ok, &mut theme.status_ok),
+            (
+                "status.pending",
+                &config.status.pending,
+                &mut theme.status_pending,
+            ),
+            (
+                "status.error",
+                &config.status.error,
+                &mut theme.status_error,
+            ),
+            (
+                "status.terminating",
+                &config.status.terminating,
+                &mut theme.status_terminating,
+            ),
+            ("status.done", &config.status.done, &mut theme.status_done),
+        ];
+        for (key, value, slot) in overrides {
+            let Some(name) = value else { continue };
+            match Color::from_str(name) {
+                Ok(color) => *slot = color,
+                Err(_) => warnings.push(format!("theme.{}: unknown color '{}'", key, name)),
+            }
+        }
+        (theme, warnings)
+    }
+
+    pub fn border(&self, focused: bool) -> Style {
+        let color = if focused {
+            self.border_focused
+        } else {
+            self.border_unfocused
+        };
+        Style::default().fg(color)
+    }
+
+    pub fn header_style(&self) -> Style {
+        Style::default()
+            .fg(self.header)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Characters a fuzzy query matched.
+    pub fn match_style(&self) -> Style {
+        Style::default()
+            .fg(self.header)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    pub fn highlight_style(&self) -> Style {
+        Style::default()
+            .bg(self.highlight)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    pub fn status_style(&self, status: &str) -> Style {
+        let color = match status {
+            "Running" | "Bound" | "Active" | "Ready" | "Available" => self.status_ok,
+            "Pending" | "ContainerCreating" | "PodInitializing" | "Updating" => self.status_pending,
+            "Failed" | "Error" | "CrashLoopBackOff" | "OOMKilled" | "Evicted" | "Lost"
+            | "NotReady" | "Warning" => self.status_error,
+            init if init_progress(init) => self.status_pending,
+            init if init.starts_with("Init:") => self.status_error,
+            "Terminating" => self.status_terminating,
+            "Succeeded" | "Completed" | "Released" => self.status_done,
+            _ => return Style::default(),
+        };
+        Style::default().fg(color)
+    }
+}
+
+/// Whether an `Init:` status counts init containers, like `Init:1/2`, rather
+/// than naming how one failed, like `Init:CrashLoopBackOff` or `Init:Error`.
+fn init_progress(status: &str) -> bool {
+    let number = |n: &str| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit());
+    status
+        .strip_prefix("Init:")
+        .and_then(|counts| counts.split_once('/'))
+        .is_some_and(|(done, total)| number(done) && number(total))
+}
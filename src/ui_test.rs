@@ -2,10 +2,16 @@
 mod tests {
     use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
     use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::style::{Color, Modifier};
     use ratatui::Terminal;
 
     use crate::app::App;
-    use crate::types::{ConfirmAction, Focus, ResourceItem, ResourceType, ViewMode};
+    use crate::test_support::{ago, fake_event};
+    use crate::types::{
+        ColumnLayout, ConfirmAction, Focus, ResourceItem, ResourceType, SpecSummary, ViewMode,
+        ALL_NAMESPACES,
+    };
     use crate::ui;
 
     fn key(code: KeyCode) -> KeyEvent {
@@ -22,12 +28,12 @@ mod tests {
             name: name.to_string(),
             namespace: "default".to_string(),
             status: status.to_string(),
-            age: "1h".to_string(),
+            created: ago(3600),
             extra: vec![
                 ("restarts".to_string(), "0".to_string()),
                 ("node".to_string(), "node-a".to_string()),
             ],
-            raw_yaml: "---\napiVersion: v1\nkind: Pod".to_string(),
+            spec: SpecSummary::default(),
         }
     }
 
@@ -38,11 +44,11 @@ mod tests {
         app.selected_context = 0;
         app.namespaces = vec!["default".to_string(), "kube-system".to_string()];
         app.selected_namespace = 0;
-        app.resources = vec![
+        app.set_resources(vec![
             fake_pod("nginx-pod-0", "Running"),
             fake_pod("redis-pod-1", "Pending"),
             fake_pod("api-pod-2", "CrashLoopBackOff"),
-        ];
+        ]);
         app
     }
 
@@ -53,6 +59,29 @@ mod tests {
         terminal.backend().to_string()
     }
 
+    fn render_to_buffer(app: &mut App, width: u16, height: u16) -> Buffer {
+        let backend = TestBackend::new(width, height);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|f| ui::render(f, app)).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// Cell position where `text` first appears on screen.
+    fn find_text(buffer: &Buffer, text: &str) -> Option<(u16, u16)> {
+        (0..buffer.area.height).find_map(|y| {
+            let row: String = (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect();
+            row.find(text)
+                .map(|col| (row[..col].chars().count() as u16, y))
+        })
+    }
+
+    /// Foreground colour of the first character of `text` on screen.
+    fn fg_of(buffer: &Buffer, text: &str) -> Option<Color> {
+        find_text(buffer, text).map(|pos| buffer[pos].fg)
+    }
+
     // --- List View Rendering ---
 
     #[test]
@@ -111,6 +140,96 @@ mod tests {
         assert!(output.contains("NODE"), "Should show NODE column header");
     }
 
+    #[test]
+    fn test_image_layout_swaps_restarts_and_node_for_image() {
+        let mut app = app_with_pods();
+        app.resources[0]
+            .extra
+            .push(("image".to_string(), "nginx:1.27".to_string()));
+        app.show_images = true;
+        let output = render_to_string(&mut app, 100, 24);
+
+        assert!(output.contains("IMAGE"), "got:\n{}", output);
+        assert!(output.contains("nginx:1.27"));
+        assert!(!output.contains("RESTARTS"));
+        assert!(!output.contains("NODE"));
+    }
+
+    #[test]
+    fn test_compact_and_wide_pod_layouts() {
+        let mut app = app_with_pods();
+        app.resources[0]
+            .extra
+            .push(("ip".to_string(), "10.0.0.7".to_string()));
+
+        app.column_layout = ColumnLayout::Wide;
+        let output = render_to_string(&mut app, 140, 24);
+        assert!(output.contains("IP"), "got:\n{}", output);
+        assert!(output.contains("10.0.0.7"));
+        assert!(output.contains("[wide]"));
+
+        app.column_layout = ColumnLayout::Compact;
+        let output = render_to_string(&mut app, 60, 24);
+        assert!(output.contains("[compact]"), "got:\n{}", output);
+        assert!(output.contains("nginx-pod-0"));
+        assert!(!output.contains("READY"));
+        assert!(!output.contains("NODE"));
+    }
+
+    #[test]
+    fn test_all_namespaces_adds_namespace_column() {
+        let mut app = app_with_pods();
+        app.namespaces.insert(0, ALL_NAMESPACES.to_string());
+        app.selected_namespace = 0;
+        app.resources[1].namespace = "kube-system".to_string();
+
+        let output = render_to_string(&mut app, 140, 20);
+        assert!(
+            output.contains("NAMESPACE"),
+            "All-namespaces view should show a NAMESPACE column, got:\n{}",
+            output
+        );
+        assert!(output.contains("kube-system"));
+        assert!(output.contains("STATUS"));
+
+        app.selected_namespace = 1;
+        let output = render_to_string(&mut app, 140, 20);
+        assert!(!output.contains("NAMESPACE"));
+    }
+
+    #[test]
+    fn test_pod_metrics_columns() {
+        let mut app = app_with_pods();
+        app.pod_metrics.insert(
+            "default/nginx-pod-0".to_string(),
+            ("12m".to_string(), "64Mi".to_string()),
+        );
+        app.apply_pod_metrics();
+
+        let output = render_to_string(&mut app, 160, 20);
+        assert!(output.contains("CPU"));
+        assert!(output.contains("MEM"));
+        assert!(output.contains("64Mi"));
+        assert!(
+            output.contains("<none>"),
+            "Pods without metrics should show <none>"
+        );
+    }
+
+    #[test]
+    fn test_sort_shown_in_list_title() {
+        let mut app = app_with_pods();
+        app.sort_column = Some(3);
+        app.sort_ascending = false;
+
+        let output = render_to_string(&mut app, 140, 20);
+        assert!(
+            output.contains("[sort: AGE ↓]"),
+            "List title should show the active sort, got:\n{}",
+            output
+        );
+    }
+
     #[test]
     fn test_list_view_renders_pod_names() {
         let mut app = app_with_pods();
@@ -131,6 +250,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_list_view_renders_pod_ready_column() {
+        let mut app = app_with_pods();
+        app.resources[0]
+            .extra
+            .push(("ready".to_string(), "1/2".to_string()));
+        let output = render_to_string(&mut app, 140, 24);
+
+        assert!(
+            output.contains("READY"),
+            "Pods list should have a READY column"
+        );
+        assert!(output.contains("1/2"), "got:\n{}", output);
+    }
+
+    #[test]
+    fn test_list_shows_full_name_of_selected_row() {
+        let mut app = app_with_pods();
+        let long = "checkout-service-7f9c8d6b5-canary-rollout-abcde";
+        app.resources[1].name = long.to_string();
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(
+            !output.contains(long),
+            "NAME column truncates the long name"
+        );
+
+        app.table_state.select(Some(1));
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains(long), "got:\n{}", output);
+    }
+
     #[test]
     fn test_list_view_renders_pod_statuses() {
         let mut app = app_with_pods();
@@ -146,6 +296,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unhealthy_pod_shows_last_exit_in_status() {
+        let mut app = app_with_pods();
+        let mut crashing = fake_pod("api-pod-2", "CrashLoopBackOff");
+        crashing
+            .extra
+            .push(("last_exit".to_string(), "Error: 137".to_string()));
+        app.set_resources(vec![fake_pod("nginx-pod-0", "Running"), crashing]);
+        let buffer = render_to_buffer(&mut app, 160, 24);
+        assert_eq!(
+            fg_of(&buffer, "CrashLoopBackOff (Error: 137)"),
+            Some(Color::Red),
+            "Colored by the bare status"
+        );
+    }
+
+    #[test]
+    fn test_warning_events_are_red() {
+        let mut app = app_with_pods();
+        app.resource_type = ResourceType::Events;
+        app.set_resources(vec![
+            fake_event("web-0.a", "Warning", "FailedScheduling", 60),
+            fake_event("web-0.b", "Normal", "Scheduled", 5),
+        ]);
+        app.update_filter();
+        let buffer = render_to_buffer(&mut app, 120, 24);
+        assert_eq!(fg_of(&buffer, "FailedScheduling"), Some(Color::Red));
+        assert_ne!(fg_of(&buffer, "Scheduled"), Some(Color::Red));
+        assert!(fg_of(&buffer, "OBJECT").is_some());
+    }
+
     #[test]
     fn test_list_view_renders_footer_keybindings() {
         let mut app = app_with_pods();
@@ -184,17 +365,17 @@ mod tests {
     fn test_pvc_column_headers() {
         let mut app = App::new();
         app.resource_type = ResourceType::PersistentVolumeClaims;
-        app.resources = vec![ResourceItem {
+        app.set_resources(vec![ResourceItem {
             name: "data-pvc".to_string(),
             namespace: "default".to_string(),
             status: "Bound".to_string(),
-            age: "5d".to_string(),
+            created: ago(432000),
             extra: vec![
                 ("volume".to_string(), "pv-abc".to_string()),
                 ("capacity".to_string(), "10Gi".to_string()),
             ],
-            raw_yaml: String::new(),
-        }];
+            spec: SpecSummary::default(),
+        }]);
         let output = render_to_string(&mut app, 100, 24);
 
         assert!(output.contains("VOLUME"), "PVC view should show VOLUME column");
@@ -213,14 +394,14 @@ mod tests {
     fn test_statefulset_column_headers() {
         let mut app = App::new();
         app.resource_type = ResourceType::StatefulSets;
-        app.resources = vec![ResourceItem {
+        app.set_resources(vec![ResourceItem {
             name: "web-ss".to_string(),
             namespace: "default".to_string(),
             status: "Active".to_string(),
-            age: "3d".to_string(),
+            created: ago(259200),
             extra: vec![("ready".to_string(), "3/3".to_string())],
-            raw_yaml: String::new(),
-        }];
+            spec: SpecSummary::default(),
+        }]);
         let output = render_to_string(&mut app, 100, 24);
 
         assert!(
@@ -245,17 +426,17 @@ mod tests {
 
         // Switch to PVCs
         app.resource_type = ResourceType::PersistentVolumeClaims;
-        app.resources = vec![ResourceItem {
+        app.set_resources(vec![ResourceItem {
             name: "my-pvc".to_string(),
             namespace: "default".to_string(),
             status: "Bound".to_string(),
-            age: "1d".to_string(),
+            created: ago(86400),
             extra: vec![
                 ("volume".to_string(), "pv-001".to_string()),
                 ("capacity".to_string(), "5Gi".to_string()),
             ],
-            raw_yaml: String::new(),
-        }];
+            spec: SpecSummary::default(),
+        }]);
         let pvc_output = render_to_string(&mut app, 100, 24);
         assert!(pvc_output.contains("VOLUME"));
         assert!(!pvc_output.contains("RESTARTS"));
@@ -288,6 +469,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detail_yaml_view_renders_raw_yaml() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Detail;
+        app.detail_text = "Events:\n  Normal  Scheduled".to_string();
+        app.detail_raw_yaml = "apiVersion: v1\nkind: Pod\n".to_string();
+        app.detail_yaml = true;
+
+        let output = render_to_string(&mut app, 100, 24);
+
+        assert!(output.contains("[YAML]"), "Title should mark the YAML view");
+        assert!(output.contains("apiVersion: v1"));
+        assert!(
+            !output.contains("Scheduled"),
+            "Describe text should be hidden in the YAML view"
+        );
+    }
+
+    #[test]
+    fn test_detail_events_view_title() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Detail;
+        app.detail_events = true;
+        app.detail_text = "Events for pods/nginx-pod-0 (newest first):".to_string();
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("nginx-pod-0 [events]"), "got:\n{}", output);
+        assert!(output.contains("newest first"));
+    }
+
     #[test]
     fn test_detail_view_shows_detail_keybindings() {
         let mut app = app_with_pods();
@@ -334,6 +545,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_logs_color_structured_levels() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_lines = vec![
+            r#"{"ts":1,"msg":"retrying upstream","level":"warn"}"#.to_string(),
+            "ts=2 level=error msg=\"write failed\"".to_string(),
+            r#"{"level":"info","msg":"no errors in 5m"}"#.to_string(),
+            "plain ERROR line".to_string(),
+        ];
+        let buffer = render_to_buffer(&mut app, 100, 24);
+        assert_eq!(fg_of(&buffer, "retrying upstream"), Some(Color::Yellow));
+        assert_eq!(fg_of(&buffer, "write failed"), Some(Color::Red));
+        assert_eq!(
+            fg_of(&buffer, "no errors in 5m"),
+            Some(Color::Reset),
+            "The info level wins"
+        );
+        assert_eq!(fg_of(&buffer, "plain ERROR line"), Some(Color::Red));
+    }
+
+    #[test]
+    fn test_logs_render_ansi_colors() {
+        use ratatui::style::Modifier;
+
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_lines = vec![
+            "\x1b[32mINFO\x1b[0m served \x1b[1;38;5;208m/api\x1b[22;39m ok".to_string(),
+            "\x1b]0;title\x07\x1b[2Kcleared".to_string(),
+        ];
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|f| ui::render(f, &mut app)).unwrap();
+
+        let output = terminal.backend().to_string();
+        assert!(output.contains("INFO served /api ok"), "got:\n{}", output);
+        assert!(output.contains("cleared"));
+        assert!(!output.contains("[32m") && !output.contains("title"));
+
+        let buffer = terminal.backend().buffer();
+        let cell_of = |text: &str| find_text(buffer, text).map(|pos| buffer[pos].clone());
+        assert_eq!(fg_of(buffer, "INFO"), Some(Color::Green));
+        assert_eq!(fg_of(buffer, "served"), Some(Color::Reset));
+        let api = cell_of("/api").unwrap();
+        assert_eq!(api.fg, Color::Indexed(208));
+        assert!(api.modifier.contains(Modifier::BOLD));
+        assert!(!cell_of(" ok").unwrap().modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_ansi_strip_drops_every_escape_sequence() {
+        use crate::ui::ansi::strip;
+
+        assert_eq!(strip("plain"), "plain");
+        assert_eq!(strip("\x1b[31mred\x1b[0m"), "red");
+        assert_eq!(
+            strip("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"),
+            "link"
+        );
+        assert_eq!(strip("cut off \x1b[38;5"), "cut off ");
+        assert_eq!(strip("é\x1bé"), "é");
+    }
+
     #[test]
     fn test_logs_view_shows_follow_indicator() {
         let mut app = app_with_pods();
@@ -349,6 +624,263 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_logs_follow_off_keeps_scroll_position() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_follow = true;
+        app.log_lines = (0..100).map(|i| format!("line {}", i)).collect();
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("line 99"));
+        let bottom = app.log_scroll;
+        assert!(bottom > 0, "Following records the scroll it rendered at");
+
+        app.handle_input(key(KeyCode::Char('f')));
+        assert!(!app.log_follow);
+        let output = render_to_string(&mut app, 100, 24);
+        assert_eq!(app.log_scroll, bottom, "Turning follow off stays in place");
+        assert!(output.contains("line 99"), "got:\n{}", output);
+
+        app.handle_input(key(KeyCode::Char('k')));
+        let output = render_to_string(&mut app, 100, 24);
+        assert_eq!(
+            app.log_scroll,
+            bottom - 1,
+            "Scrolling up starts from the view"
+        );
+        assert!(!output.contains("line 99"));
+    }
+
+    #[test]
+    fn test_logs_show_position_of_last_visible_line() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_follow = true;
+        app.log_lines = (0..100).map(|i| format!("line {}", i)).collect();
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains(" 100/100 "), "got:\n{}", output);
+
+        app.log_follow = false;
+        app.log_scroll = 0;
+        let output = render_to_string(&mut app, 100, 24);
+        // "N/100" counts from 1, so `line N-1` is the last one on screen
+        let shown = (0..100)
+            .filter(|i| output.contains(&format!("line {} ", i)))
+            .count();
+        assert!(
+            output.contains(&format!(" {}/100 ", shown)),
+            "got:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_detail_jump_to_bottom_fits_terminal_height() {
+        for height in [16, 24, 60] {
+            let mut app = app_with_pods();
+            app.view_mode = ViewMode::Detail;
+            app.detail_text = (0..100)
+                .map(|i| format!("line {}", i))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            app.handle_input(key(KeyCode::Char('G')));
+            let output = render_to_string(&mut app, 100, height);
+            assert!(output.contains("line 99"), "height {}:\n{}", height, output);
+
+            app.handle_input(key(KeyCode::Char('k')));
+            let output = render_to_string(&mut app, 100, height);
+            assert!(
+                !output.contains("line 99"),
+                "height {}:\n{}",
+                height,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn test_detail_scroll_clamps_to_content() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Detail;
+        app.detail_text = "one\ntwo\nthree".to_string();
+        app.detail_scroll = 50;
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert_eq!(app.detail_scroll, 0, "Short content fits without scrolling");
+        assert!(output.contains("one"));
+    }
+
+    #[test]
+    fn test_logs_jump_to_bottom_fits_terminal_height() {
+        for height in [16, 24, 60] {
+            let mut app = app_with_pods();
+            app.view_mode = ViewMode::Logs;
+            app.log_lines = (0..100).map(|i| format!("line {}", i)).collect();
+
+            app.handle_input(key(KeyCode::Char('G')));
+            let output = render_to_string(&mut app, 100, height);
+            assert!(output.contains("line 99"), "height {}:\n{}", height, output);
+
+            app.handle_input(key(KeyCode::Char('k')));
+            let output = render_to_string(&mut app, 100, height);
+            assert!(
+                !output.contains("line 99"),
+                "height {}:\n{}",
+                height,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn test_logs_nowrap_scrolls_horizontally() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        let line = format!("{}END", "x".repeat(200));
+        app.log_lines = vec![line];
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("END"), "Wrapped view shows the whole line");
+
+        app.handle_input(key(KeyCode::Char('w')));
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("[nowrap]"), "got:\n{}", output);
+        assert!(!output.contains("END"));
+
+        app.log_hscroll = u16::MAX;
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("END"), "got:\n{}", output);
+        assert!(
+            app.log_hscroll < 203,
+            "Offset is clamped to the longest line"
+        );
+    }
+
+    #[test]
+    fn test_logs_view_shows_container_in_title() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_container = Some("istio-proxy".to_string());
+        app.log_lines = vec!["hello".to_string()];
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(
+            output.contains("Logs [istio-proxy]"),
+            "Logs title should name the container, got:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_logs_view_shows_stream_options_in_title() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_lines = vec!["hello".to_string()];
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("[tail 100]"), "got:\n{}", output);
+
+        app.log_tail = None;
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("[tail all]"), "got:\n{}", output);
+
+        app.log_previous = true;
+        app.log_timestamps = true;
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("[previous]"), "got:\n{}", output);
+        assert!(output.contains("[timestamps]"), "got:\n{}", output);
+    }
+
+    #[test]
+    fn test_logs_view_aggregated_workload_lines() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_aggregate = true;
+        app.log_lines = vec![
+            "[7d9f-abcde] started".to_string(),
+            "[7d9f-fghij] listening".to_string(),
+        ];
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("[all pods]"), "got:\n{}", output);
+        assert!(output.contains("[7d9f-abcde] started"));
+        assert!(output.contains("[7d9f-fghij] listening"));
+    }
+
+    #[test]
+    fn test_logs_view_shows_label_selector() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_aggregate = true;
+        app.log_selector = Some("app=nginx".to_string());
+        app.log_lines = vec!["[nginx-pod-0] ready".to_string()];
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("[pods app=nginx]"), "got:\n{}", output);
+        assert!(!output.contains("[all pods]"));
+    }
+
+    #[test]
+    fn test_logs_view_renders_container_picker() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.container_choices = vec!["app".to_string(), "istio-proxy".to_string()];
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("Container"));
+        assert!(output.contains("istio-proxy"));
+        assert!(output.contains("Enter:Select container"));
+    }
+
+    #[test]
+    fn test_logs_view_shows_search_position() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_lines = vec![
+            "GET /health 200".to_string(),
+            "GET /api 500".to_string(),
+            "GET /health 200".to_string(),
+        ];
+        app.log_search = "health".to_string();
+        app.log_matches = vec![0, 2];
+        app.log_match_idx = 1;
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(
+            output.contains("[/health 2/2]"),
+            "Logs title should show the search and match position, got:\n{}",
+            output
+        );
+        assert!(output.contains("GET /api 500"));
+    }
+
+    #[test]
+    fn test_logs_view_grep_shows_filtered_count() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+        app.log_lines = vec![
+            "GET /health 200".to_string(),
+            "GET /api 500".to_string(),
+            "GET /health 200".to_string(),
+        ];
+        app.log_search = "500".to_string();
+        app.log_matches = vec![1];
+        app.log_grep = true;
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("[GREP]"));
+        assert!(
+            output.contains("1/3 lines"),
+            "Title should show filtered vs total, got:\n{}",
+            output
+        );
+        assert!(output.contains("GET /api 500"));
+        assert!(!output.contains("GET /health"));
+    }
+
     #[test]
     fn test_logs_view_shows_line_count() {
         let mut app = app_with_pods();
@@ -369,52 +901,421 @@ mod tests {
     }
 
     #[test]
-    fn test_logs_view_shows_log_keybindings() {
+    fn test_logs_view_shows_log_keybindings() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Logs;
+
+        let output = render_to_string(&mut app, 100, 24);
+
+        assert!(
+            output.contains("f:Follow"),
+            "Log footer should show follow toggle binding"
+        );
+    }
+
+    // --- Confirmation Dialog ---
+
+    #[test]
+    fn test_quit_confirm_counts_operations_in_flight() {
+        let mut app = app_with_pods();
+        let _first = app.in_flight.start();
+        let _second = app.in_flight.start();
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Quit);
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("Confirm Quit"), "got:\n{}", output);
+        assert!(output.contains("2 operations are still in progress, quit anyway?"));
+        assert!(output.contains("Press 'y' to quit"));
+    }
+
+    #[test]
+    fn test_confirm_dialog_renders_overlay() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
+
+        let output = render_to_string(&mut app, 100, 24);
+
+        assert!(
+            output.contains("Confirm Delete"),
+            "Confirm dialog should show action name, got:\n{}",
+            output
+        );
+        assert!(
+            output.contains("Are you sure"),
+            "Confirm dialog should show confirmation prompt"
+        );
+        assert!(
+            output.contains("pods/nginx-pod-0"),
+            "Confirm dialog should name the resource, got:\n{}",
+            output
+        );
+        assert!(output.contains("namespace: default"));
+    }
+
+    #[test]
+    fn test_danger_mode_flags_context_and_confirm() {
+        let mut app = app_with_pods();
+        app.danger_contexts = vec!["prod".to_string()];
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("Context ⚠ DANGER"), "got:\n{}", output);
+        assert!(output.contains("twice to confirm"), "got:\n{}", output);
+
+        app.confirm_armed = true;
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("Press 'y' again"), "got:\n{}", output);
+
+        // Red panes still show which one has focus
+        let focused = ui::pane_border(&app, true);
+        assert_eq!(focused.fg, Some(app.theme.status_error));
+        assert!(focused.add_modifier.contains(Modifier::BOLD));
+        assert!(!ui::pane_border(&app, false)
+            .add_modifier
+            .contains(Modifier::BOLD));
+
+        app.danger_contexts.clear();
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(!output.contains("DANGER"));
+    }
+
+    #[test]
+    fn test_header_shows_server_url() {
+        let mut app = app_with_pods();
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(!output.contains("https://"));
+
+        app.server_url = Some("https://10.0.0.1:6443".to_string());
+        let output = render_to_string(&mut app, 100, 24);
+        let header_bottom = output.lines().nth(2).unwrap_or_default();
+        assert!(
+            header_bottom.contains("https://10.0.0.1:6443"),
+            "got:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_confirm_force_delete_dialog_warns() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Confirm(ConfirmAction::ForceDelete);
+
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("Confirm Force delete"), "got:\n{}", output);
+        assert!(output.contains("skips graceful termination"));
+        assert!(output.contains("pods/nginx-pod-0"));
+    }
+
+    #[test]
+    fn test_edit_diff_renders_changes() {
+        let mut app = app_with_pods();
+        app.open_edit_preview(crate::types::PendingEdit::new(
+            "default".to_string(),
+            "nginx-pod-0".to_string(),
+            ResourceType::Pods,
+            "metadata:\n  labels:\n    app: nginx\n",
+            "metadata:\n  labels:\n    app: web\n".to_string(),
+        ));
+
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("Apply edit to pods/nginx-pod-0 (+1 -1)"),
+            "got:\n{}",
+            output
+        );
+        assert!(output.contains("@@ -1,3 +1,3 @@"));
+        assert!(output.contains("-    app: nginx"));
+        assert!(output.contains("+    app: web"));
+        assert!(output.contains("y:Apply"));
+    }
+
+    #[test]
+    fn test_create_diff_names_the_new_resource() {
+        let mut app = app_with_pods();
+        app.open_edit_preview(
+            crate::types::PendingEdit::create(
+                "default".to_string(),
+                ResourceType::Pods,
+                "kind: Pod\nmetadata:\n  name: debug\n".to_string(),
+            )
+            .unwrap(),
+        );
+
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("Create pods/debug (+3)"),
+            "got:\n{}",
+            output
+        );
+        assert!(output.contains("+  name: debug"));
+        assert!(output.contains("y:Create"));
+    }
+
+    #[test]
+    fn test_edit_diff_shows_dry_run_failure() {
+        let mut app = app_with_pods();
+        let mut edit = crate::types::PendingEdit::new(
+            "default".to_string(),
+            "nginx-pod-0".to_string(),
+            ResourceType::Pods,
+            "metadata:\n  labels:\n    app: nginx\n",
+            "metadata:\n  labels:\n    app: web\n".to_string(),
+        );
+        edit.dry_run = Some(crate::types::DryRun::Failed(
+            "Failed to apply Pod YAML: admission webhook \"policy.example.com\" denied the request: \
+             label app must match the deployment"
+                .to_string(),
+        ));
+        app.open_edit_preview(edit);
+
+        let output = render_to_string(&mut app, 80, 30);
+        assert!(
+            output.contains("Dry run failed: Failed to apply Pod YAML"),
+            "got:\n{}",
+            output
+        );
+        assert!(output.contains("must match the "), "got:\n{}", output);
+        assert!(output.contains("│deployment "), "wrapped onto the next row");
+        assert!(output.contains("+    app: web"));
+        assert!(output.contains("d:Dry run"));
+    }
+
+    #[test]
+    fn test_confirm_delete_dialog_shows_dependents() {
+        let mut app = app_with_pods();
+        app.resource_type = ResourceType::StatefulSets;
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
+
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("Computing affected resources"),
+            "Dialog should show loading state before preview arrives, got:\n{}",
+            output
+        );
+
+        app.delete_preview = Some(vec![
+            "Pods (2):".to_string(),
+            "  web-0".to_string(),
+            "  web-1".to_string(),
+        ]);
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("This will also remove:"));
+        assert!(output.contains("web-1"));
+        assert!(output.contains("Press 'y' to confirm"));
+    }
+
+    #[test]
+    fn test_confirm_restart_dialog() {
+        let mut app = app_with_pods();
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Restart);
+
+        let output = render_to_string(&mut app, 100, 24);
+
+        assert!(
+            output.contains("Confirm Restart"),
+            "Confirm dialog should show 'Confirm Restart'"
+        );
+    }
+
+    #[test]
+    fn test_confirm_restart_explains_pod_restarts() {
+        let mut app = app_with_pods();
+        app.resources[0].spec.owner = Some(("ReplicaSet".to_string(), "nginx-7d9f".to_string()));
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Restart);
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("can't restart a single"),
+            "got:\n{}",
+            output
+        );
+        assert!(output.contains("ReplicaSet nginx-7d9f"), "got:\n{}", output);
+
+        app.resources[0].spec.owner = None;
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("this pod has no controller"),
+            "got:\n{}",
+            output
+        );
+
+        app.resource_type = ResourceType::Deployments;
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("Rolling restart"), "got:\n{}", output);
+    }
+
+    #[test]
+    fn test_port_forward_prompt_and_indicator() {
+        use crate::types::{PortForward, Prompt, PromptKind};
+
+        let mut app = app_with_pods();
+        app.prompt = Some(Prompt {
+            kind: PromptKind::PortForward,
+            input: "8080:80".to_string(),
+        });
+        let output = render_to_string(&mut app, 140, 30);
+        assert!(output.contains("Port-forward (localPort:remotePort)"));
+        assert!(output.contains("8080:80"));
+        assert!(output.contains("Enter:Submit"));
+
+        app.prompt = None;
+        app.port_forwards.push(PortForward {
+            namespace: "default".to_string(),
+            name: "nginx-pod-0".to_string(),
+            resource_type: ResourceType::Pods,
+            local_port: 8080,
+            remote_port: 80,
+        });
+        let output = render_to_string(&mut app, 140, 30);
+        assert!(
+            output.contains("Forwarding 8080->nginx-pod-0:80"),
+            "Footer should list active forwards, got:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_help_overlay_lists_bindings_by_view() {
         let mut app = app_with_pods();
-        app.view_mode = ViewMode::Logs;
+        app.show_help = true;
+
+        let output = render_to_string(&mut app, 140, 40);
+        assert!(output.contains("Help (Esc or ? to close)"));
+        assert!(output.contains("Resource list"));
+        assert!(output.contains("Port-forward"));
+        // The footer hints come from the same bindings
+        assert!(output.contains("Esc/?:Close"));
+
+        // Later sections are reached by scrolling
+        app.help_scroll = 52;
+        let output = render_to_string(&mut app, 140, 40);
+        assert!(output.contains("Logs view"));
+    }
 
+    #[test]
+    fn test_node_pods_title_names_the_node() {
+        let mut app = app_with_pods();
+        app.pod_node = Some("node-a".to_string());
         let output = render_to_string(&mut app, 100, 24);
+        assert!(
+            output.contains("pods [node: node-a] (3"),
+            "got:\n{}",
+            output
+        );
+    }
 
+    #[test]
+    fn test_bookmarks_overlay_lists_resources() {
+        let mut app = app_with_pods();
+        app.show_bookmarks = true;
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("No bookmarks yet"), "got:\n{}", output);
+
+        app.bookmarks.push(crate::types::Bookmark {
+            context: "minikube".to_string(),
+            namespace: "cache".to_string(),
+            resource_type: ResourceType::StatefulSets,
+            name: "redis".to_string(),
+        });
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("Bookmarks (1)"), "got:\n{}", output);
+        assert!(output.contains("CONTEXT"));
+        assert!(output.contains("statefulsets"));
+        assert!(output.contains("redis"));
         assert!(
-            output.contains("f:Follow"),
-            "Log footer should show follow toggle binding"
+            output.contains("Enter:Go to resource"),
+            "Footer shows the overlay keys"
         );
     }
 
-    // --- Confirmation Dialog ---
+    #[test]
+    fn test_error_history_overlay_newest_first() {
+        let mut app = app_with_pods();
+        app.set_error("Failed to list namespaces".to_string());
+        app.set_error("Apply error: conflict".to_string());
+        app.status_message = None;
+        app.show_message_log = true;
+
+        let output = render_to_string(&mut app, 120, 30);
+        assert!(output.contains("Message history (2)"), "got:\n{}", output);
+        let newest = output.find("Apply error: conflict").unwrap();
+        let oldest = output.find("Failed to list namespaces").unwrap();
+        assert!(newest < oldest, "got:\n{}", output);
+        assert!(output.contains("0s ago"));
+        assert!(output.contains("Esc/M:Close"));
+    }
 
     #[test]
-    fn test_confirm_dialog_renders_overlay() {
+    fn test_footer_hides_bindings_for_unsupported_types() {
         let mut app = app_with_pods();
-        app.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
+        let output = render_to_string(&mut app, 200, 24);
+        assert!(output.contains("l:Logs"));
+        assert!(output.contains("?:Help"));
+
+        app.resource_type = ResourceType::ConfigMaps;
+        let output = render_to_string(&mut app, 200, 24);
+        assert!(!output.contains("l:Logs"));
+        assert!(!output.contains("r:Restart"));
+    }
 
-        let output = render_to_string(&mut app, 100, 24);
+    #[test]
+    fn test_batch_delete_dialog_counts_marked() {
+        let mut app = app_with_pods();
+        app.marked.insert("default/nginx-pod-0".to_string());
+        app.marked.insert("default/api-pod-2".to_string());
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
 
+        let output = render_to_string(&mut app, 100, 30);
         assert!(
-            output.contains("Confirm Delete"),
-            "Confirm dialog should show action name, got:\n{}",
+            output.contains("delete 2 resources?"),
+            "Dialog should count the marked rows, got:\n{}",
             output
         );
+        assert!(output.contains("api-pod-2"));
         assert!(
-            output.contains("Are you sure"),
-            "Confirm dialog should show confirmation prompt"
+            output.contains("[2 marked]"),
+            "List title shows the mark count"
         );
     }
 
     #[test]
-    fn test_confirm_restart_dialog() {
+    fn test_batch_delete_dialog_qualifies_names_across_namespaces() {
         let mut app = app_with_pods();
-        app.view_mode = ViewMode::Confirm(ConfirmAction::Restart);
+        app.namespaces.insert(0, ALL_NAMESPACES.to_string());
+        app.selected_namespace = 0;
+        app.marked.insert("default/api-pod-2".to_string());
+        app.view_mode = ViewMode::Confirm(ConfirmAction::Delete);
+
+        let output = render_to_string(&mut app, 140, 30);
+        assert!(output.contains("default/api-pod-2"), "got:\n{}", output);
+    }
+
+    // --- Filter Mode ---
 
+    #[test]
+    fn test_list_title_shows_count_and_not_ready() {
+        let mut app = app_with_pods();
         let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("pods (3, 2 not ready)"), "got:\n{}", output);
 
+        app.resources.truncate(1);
+        app.update_filter();
+        let output = render_to_string(&mut app, 100, 24);
         assert!(
-            output.contains("Confirm Restart"),
-            "Confirm dialog should show 'Confirm Restart'"
+            output.contains("pods (1)"),
+            "All ready omits the not-ready part"
         );
     }
 
-    // --- Filter Mode ---
+    #[test]
+    fn test_problems_only_title() {
+        let mut app = app_with_pods();
+        app.problems_only = true;
+        app.update_filter();
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("[problems only]"), "got:\n{}", output);
+        assert!(!output.contains("nginx-pod-0"), "Running pods are hidden");
+        assert!(output.contains("api-pod-2"));
+    }
 
     #[test]
     fn test_filter_mode_shows_filter_text_in_title() {
@@ -454,8 +1355,7 @@ mod tests {
         let mut app = app_with_pods();
         app.set_error("Connection timed out".to_string());
 
-        // Use wider terminal so the error message isn't clipped by the footer
-        let output = render_to_string(&mut app, 140, 24);
+        let output = render_to_string(&mut app, 80, 24);
 
         assert!(
             output.contains("Connection timed out"),
@@ -464,6 +1364,135 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_footer_colors_messages_by_severity() {
+        let mut app = app_with_pods();
+        let footer_color = |app: &mut App, text: &str| fg_of(&render_to_buffer(app, 100, 24), text);
+
+        app.set_info("Copied nginx-pod-0 to clipboard".to_string());
+        assert_eq!(footer_color(&mut app, "Copied"), Some(app.theme.status_ok));
+        app.set_warning("No label selector given".to_string());
+        assert_eq!(
+            footer_color(&mut app, "No label"),
+            Some(app.theme.status_pending)
+        );
+        app.set_error("Apply error: conflict".to_string());
+        assert_eq!(
+            footer_color(&mut app, "Apply error"),
+            Some(app.theme.status_error)
+        );
+    }
+
+    #[test]
+    fn test_long_error_message_wraps_at_80_columns() {
+        let mut app = app_with_pods();
+        app.set_error(
+            "Watch error: ApiError: pods is forbidden: User \"system:serviceaccount:ci:runner\" \
+             cannot watch resource \"pods\" in API group \"\" in the namespace \"payments\""
+                .to_string(),
+        );
+
+        let output = render_to_string(&mut app, 80, 24);
+
+        assert!(
+            output.contains("Watch error: ApiError: pods is forbidden"),
+            "Start of the error should be visible, got:\n{}",
+            output
+        );
+        assert!(
+            output.contains("in the namespace \"payments\""),
+            "End of the error should wrap onto the next row instead of being clipped, got:\n{}",
+            output
+        );
+        assert!(
+            output.contains("q:Quit"),
+            "Keybindings should still be shown alongside the error"
+        );
+    }
+
+    #[test]
+    fn test_reconnecting_indicator_in_header() {
+        let mut app = app_with_pods();
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(!output.contains("reconnecting"));
+
+        app.watch_reconnecting = true;
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(
+            output.contains("reconnecting\u{2026}"),
+            "Header should show the reconnecting indicator, got:\n{}",
+            output
+        );
+        assert!(
+            output.contains("pod-0"),
+            "Stale rows stay visible while reconnecting"
+        );
+    }
+
+    #[test]
+    fn test_loading_spinner_in_header_and_detail() {
+        let mut app = app_with_pods();
+        app.loading = true;
+        app.loading_ticks = 13;
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(
+            output.contains("⠸ 3s loading"),
+            "Header should show the spinner and elapsed time, got:\n{}",
+            output
+        );
+
+        app.view_mode = ViewMode::Detail;
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("Loading... ⠸ 3s"), "got:\n{}", output);
+    }
+
+    #[test]
+    fn test_selection_position_in_list_and_search() {
+        let mut app = app_with_pods();
+        app.table_state.select(Some(1));
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(output.contains("└ 2/3 ─"), "got:\n{}", output);
+
+        app.resources.clear();
+        app.update_filter();
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(
+            !output.contains("/0 "),
+            "No position without rows, got:\n{}",
+            output
+        );
+
+        let mut app = app_with_search();
+        app.search_table_state.select(Some(0));
+        let output = render_to_string(&mut app, 100, 24);
+        let total = app.search_filtered.len();
+        assert!(
+            output.contains(&format!(" 1/{} ", total)),
+            "got:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_namespace_selector_marked_for_cluster_scoped_types() {
+        let mut app = app_with_pods();
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(!output.contains("cluster-scoped"));
+
+        app.resource_type = ResourceType::Nodes;
+        app.set_resources(vec![]);
+        let output = render_to_string(&mut app, 100, 24);
+        assert!(
+            output.contains("Namespace (cluster-scoped)"),
+            "Namespace selector should say it is ignored, got:\n{}",
+            output
+        );
+        assert!(
+            output.contains("VERSION"),
+            "Nodes list shows the kubelet version"
+        );
+    }
+
     // --- Focus Indicator ---
 
     #[test]
@@ -502,12 +1531,199 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_short_terminal_keeps_list_rows_over_dropdown() {
+        let mut app = app_with_pods();
+        app.focus = Focus::ContextSelector;
+        app.dropdown_open();
+        app.dropdown_visible = true;
+
+        // No room for the dropdown without squeezing the list
+        let output = render_to_string(&mut app, 100, 12);
+        assert!(
+            !output.contains("minikube"),
+            "Dropdown should be left out, got:\n{}",
+            output
+        );
+        for pod in ["nginx-pod-0", "redis-pod-1", "api-pod-2"] {
+            assert!(
+                output.contains(pod),
+                "List should keep its rows, got:\n{}",
+                output
+            );
+        }
+
+        // Still drawn when there is room
+        let output = render_to_string(&mut app, 100, 18);
+        assert!(output.contains("minikube"));
+        assert!(output.contains("api-pod-2"));
+
+        // Too short even for the header: the list gets what there is
+        let output = render_to_string(&mut app, 100, 7);
+        assert!(!output.contains("gke-prod"));
+        assert!(output.contains("nginx-pod-0"));
+    }
+
+    #[test]
+    fn test_unlisted_namespace_dropdown_asks_for_a_name() {
+        let mut app = app_with_pods();
+        app.namespaces_unlisted = true;
+        app.focus = Focus::NamespaceSelector;
+        app.dropdown_open();
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("not listed, type a namespace"),
+            "got:\n{}",
+            output
+        );
+
+        app.dropdown_query = "team-a".to_string();
+        app.update_dropdown_filter();
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("Enter to open 'team-a'"),
+            "got:\n{}",
+            output
+        );
+
+        // The listed namespaces take typed names too
+        app.namespaces_unlisted = false;
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(
+            output.contains("Enter to open 'team-a'"),
+            "got:\n{}",
+            output
+        );
+        app.dropdown_query.clear();
+        app.update_dropdown_filter();
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(!output.contains("not listed"), "got:\n{}", output);
+    }
+
+    #[test]
+    fn test_context_dropdown_shows_health_dots() {
+        use crate::types::ContextHealth;
+
+        let mut app = app_with_pods();
+        app.focus = Focus::ContextSelector;
+        app.dropdown_open();
+        app.set_context_health("gke-prod".to_string(), ContextHealth::Reachable);
+        app.set_context_health("minikube".to_string(), ContextHealth::Unreachable);
+
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("● gke-prod"), "got:\n{}", output);
+        assert!(output.contains("● minikube"), "got:\n{}", output);
+
+        app.context_health.clear();
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("○ gke-prod"), "got:\n{}", output);
+
+        // Namespaces get no dot
+        app.focus = Focus::NamespaceSelector;
+        app.dropdown_open();
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(!output.contains("○ default"), "got:\n{}", output);
+    }
+
+    #[test]
+    fn test_context_dropdown_stars_favorites() {
+        let mut app = app_with_pods();
+        app.favorite_contexts.insert("minikube".to_string());
+        app.focus = Focus::ContextSelector;
+        app.dropdown_open();
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("minikube ★"), "got:\n{}", output);
+        assert!(!output.contains("gke-prod ★"));
+
+        app.favorites_only = true;
+        app.update_dropdown_filter();
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains("1 favorites"), "got:\n{}", output);
+    }
+
+    #[test]
+    fn test_command_palette_overlay() {
+        let mut app = app_with_pods();
+        app.handle_input(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        for c in "logs".chars() {
+            app.handle_input(key(KeyCode::Char(c)));
+        }
+
+        let output = render_to_string(&mut app, 100, 30);
+        assert!(output.contains(" Commands "), "got:\n{}", output);
+        assert!(output.contains("> logs"));
+        assert!(output.contains("View logs"), "got:\n{}", output);
+        assert!(
+            output.contains("Enter:Run"),
+            "Footer shows the palette keys"
+        );
+    }
+
+    #[test]
+    fn test_mouse_clicks_map_to_rendered_rows_and_selectors() {
+        use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+        let mouse = |kind: MouseEventKind, column: u16, row: u16| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let click = |column, row| mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+
+        let mut app = app_with_pods();
+        app.table_state.select(Some(0));
+        let output = render_to_string(&mut app, 100, 24);
+        // Header selectors take rows 0-2, then the list border and header
+        let row = output
+            .lines()
+            .position(|l| l.contains("api-pod-2"))
+            .unwrap() as u16;
+        app.handle_mouse(click(10, row));
+        assert_eq!(app.selected_resource_name().as_deref(), Some("api-pod-2"));
+
+        // Clicking the header row or the border selects nothing new
+        app.handle_mouse(click(10, 4));
+        assert_eq!(app.table_state.selected(), Some(2));
+
+        app.handle_mouse(mouse(MouseEventKind::ScrollUp, 10, row));
+        assert_eq!(app.table_state.selected(), Some(1));
+
+        app.handle_mouse(click(50, 1));
+        assert_eq!(app.focus, Focus::NamespaceSelector);
+        assert!(app.dropdown_visible);
+
+        // The wheel scrolls the detail pane
+        app.focus = Focus::ResourceList;
+        app.dropdown_visible = false;
+        app.view_mode = ViewMode::Detail;
+        app.detail_text = (0..100).map(|i| format!("line {}\n", i)).collect();
+        render_to_string(&mut app, 100, 24);
+        app.handle_mouse(mouse(MouseEventKind::ScrollDown, 80, 10));
+        assert_eq!(app.detail_scroll, 1);
+
+        // Search rows are clickable too
+        let mut app = app_with_search();
+        let output = render_to_string(&mut app, 100, 24);
+        let redis = app
+            .search_filtered
+            .iter()
+            .position(|&i| app.search_results[i].resource.name == "redis-master-0");
+        let row = output
+            .lines()
+            .position(|l| l.contains("redis-master-0"))
+            .unwrap() as u16;
+        app.search_table_state.select(None);
+        app.handle_mouse(click(10, row));
+        assert_eq!(app.search_table_state.selected(), redis);
+    }
+
     // --- Empty State ---
 
     #[test]
     fn test_empty_resource_list_renders_without_panic() {
         let mut app = App::new();
-        app.resources = vec![];
+        app.set_resources(vec![]);
         // Should not panic
         let output = render_to_string(&mut app, 100, 24);
         assert!(output.contains("pods"), "Should still show resource type header");
@@ -599,14 +1815,14 @@ mod tests {
         app.handle_input(key(KeyCode::Down)); // Deployments
         app.handle_input(key(KeyCode::Down)); // StatefulSets
         app.handle_input(key(KeyCode::Enter)); // Confirm StatefulSets
-        app.resources = vec![ResourceItem {
+        app.set_resources(vec![ResourceItem {
             name: "web".to_string(),
             namespace: "default".to_string(),
             status: "Active".to_string(),
-            age: "2d".to_string(),
+            created: ago(172800),
             extra: vec![("ready".to_string(), "2/2".to_string())],
-            raw_yaml: String::new(),
-        }];
+            spec: SpecSummary::default(),
+        }]);
         let ss_output = render_to_string(&mut app, 100, 30);
         assert!(ss_output.contains("READY"));
         assert!(ss_output.contains("statefulsets"));
@@ -615,6 +1831,20 @@ mod tests {
 
     // --- Small Terminal Size ---
 
+    #[test]
+    fn test_narrow_list_drops_node_then_restarts() {
+        let mut app = app_with_pods();
+        let output = render_to_string(&mut app, 80, 24);
+        assert!(!output.contains("NODE"), "got:\n{}", output);
+        assert!(output.contains("RESTARTS"));
+        assert!(output.contains("nginx-pod-0"));
+
+        let output = render_to_string(&mut app, 60, 24);
+        assert!(!output.contains("NODE"), "got:\n{}", output);
+        assert!(!output.contains("RESTARTS"));
+        assert!(output.contains("STATUS"));
+    }
+
     #[test]
     fn test_renders_at_minimum_size_without_panic() {
         let mut app = app_with_pods();
@@ -638,9 +1868,9 @@ mod tests {
                     name: "op-geth-node-0".to_string(),
                     namespace: "ethereum".to_string(),
                     status: "Running".to_string(),
-                    age: "1h".to_string(),
+                    created: ago(3600),
                     extra: vec![],
-                    raw_yaml: String::new(),
+                    spec: SpecSummary::default(),
                 },
                 context: "gke-prod".to_string(),
                 resource_type: ResourceType::Pods,
@@ -650,9 +1880,9 @@ mod tests {
                     name: "op-geth-node-0".to_string(),
                     namespace: "ethereum".to_string(),
                     status: "Running".to_string(),
-                    age: "2h".to_string(),
+                    created: ago(7200),
                     extra: vec![],
-                    raw_yaml: String::new(),
+                    spec: SpecSummary::default(),
                 },
                 context: "gke-staging".to_string(),
                 resource_type: ResourceType::Pods,
@@ -662,9 +1892,9 @@ mod tests {
                     name: "redis-master-0".to_string(),
                     namespace: "cache".to_string(),
                     status: "Running".to_string(),
-                    age: "3d".to_string(),
+                    created: ago(259200),
                     extra: vec![],
-                    raw_yaml: String::new(),
+                    spec: SpecSummary::default(),
                 },
                 context: "gke-prod".to_string(),
                 resource_type: ResourceType::StatefulSets,
@@ -686,6 +1916,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scoped_search_shows_scope_in_titles() {
+        let mut app = app_with_search();
+        app.search_scope = Some(crate::types::SearchScope {
+            context: "gke-prod".to_string(),
+            namespace: "ethereum".to_string(),
+        });
+        let output = render_to_string(&mut app, 100, 24);
+
+        assert!(
+            output.contains("Search gke-prod/ethereum"),
+            "Search input should name the scope, got:\n{}",
+            output
+        );
+        assert!(
+            output.contains("found in gke-prod/ethereum"),
+            "Results title should name the scope, got:\n{}",
+            output
+        );
+    }
+
     #[test]
     fn test_search_view_renders_column_headers() {
         let mut app = app_with_search();
@@ -800,6 +2051,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_search_view_summarizes_and_expands_failed_clusters() {
+        let mut app = app_with_search();
+        app.search_loading = true;
+        app.search_contexts_total = 3;
+        app.search_contexts_done = 2;
+        app.search_failures = vec![
+            ("old-cluster".to_string(), "token expired".to_string()),
+            ("lab".to_string(), "connection refused".to_string()),
+        ];
+
+        let output = render_to_string(&mut app, 120, 24);
+        assert!(output.contains("failed: 2 clusters"), "got:\n{}", output);
+        assert!(!output.contains("token expired"));
+
+        let ctrl_e = KeyEvent {
+            modifiers: KeyModifiers::CONTROL,
+            ..key(KeyCode::Char('e'))
+        };
+        app.handle_input(ctrl_e);
+        let output = render_to_string(&mut app, 120, 24);
+        assert!(
+            output.contains("old-cluster: token expired"),
+            "got:\n{}",
+            output
+        );
+        assert!(output.contains("lab: connection refused"));
+        assert!(
+            app.search_query.is_empty(),
+            "Ctrl+E isn't typed into the query"
+        );
+
+        app.handle_input(ctrl_e);
+        let output = render_to_string(&mut app, 120, 24);
+        assert!(!output.contains("token expired"));
+    }
+
     #[test]
     fn test_search_view_shows_search_query() {
         let mut app = app_with_search();
@@ -906,4 +2194,77 @@ mod tests {
         let output = render_to_string(&mut app, 40, 16);
         assert!(!output.is_empty());
     }
+
+    // --- Theme ---
+
+    #[test]
+    fn test_theme_config_overrides_and_warnings() {
+        use crate::ui::theme::Theme;
+
+        let config = crate::config::parse(
+            "theme:\n  border_focused: blue\n  header: notacolor\n  status:\n    ok: '#00ff00'\n",
+        )
+        .unwrap();
+        let (theme, warnings) = Theme::from_config(&config.theme);
+
+        assert_eq!(theme.border_focused, Color::Blue);
+        assert_eq!(theme.status_ok, Color::Rgb(0, 255, 0));
+        assert_eq!(
+            theme.header,
+            Theme::default().header,
+            "Unknown names keep the default"
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("theme.header"), "got {:?}", warnings);
+
+        let (theme, warnings) = Theme::from_config(&crate::config::parse("").unwrap().theme);
+        assert_eq!(theme, Theme::default());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_search_highlights_matched_characters() {
+        let mut app = app_with_search();
+        app.search_query = "geth".to_string();
+        app.update_search_filter();
+        let buffer = render_to_buffer(&mut app, 120, 24);
+        let matched = app.theme.match_style().fg;
+        let (x, y) = find_text(&buffer, "op-geth-node-0").expect("result row");
+        assert_ne!(
+            buffer[(x, y)].fg,
+            matched.unwrap(),
+            "'o' is not part of the match"
+        );
+        for dx in 3..7 {
+            assert_eq!(
+                buffer[(x + dx, y)].fg,
+                matched.unwrap(),
+                "'geth' is highlighted"
+            );
+        }
+    }
+
+    #[test]
+    fn test_failed_init_containers_are_errors() {
+        let theme = crate::ui::theme::Theme::default();
+        let fg = |status: &str| theme.status_style(status).fg;
+        assert_eq!(fg("Init:0/2"), Some(theme.status_pending));
+        assert_eq!(fg("Init:1/12"), Some(theme.status_pending));
+        for failed in [
+            "Init:CrashLoopBackOff",
+            "Init:Error",
+            "Init:ExitCode:2",
+            "Init:Signal:9",
+        ] {
+            assert_eq!(fg(failed), Some(theme.status_error), "{}", failed);
+        }
+    }
+
+    #[test]
+    fn test_theme_colors_used_when_rendering() {
+        let mut app = app_with_pods();
+        app.theme.status_ok = Color::LightBlue;
+        let buffer = render_to_buffer(&mut app, 100, 24);
+        assert_eq!(fg_of(&buffer, "Running"), Some(Color::LightBlue));
+    }
 }